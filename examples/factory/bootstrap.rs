@@ -1,9 +1,8 @@
 use std::error::Error;
 
-use syrette::ptr::TransientPtr;
 use syrette::DIContainer;
 
-use crate::interfaces::user::{IUser, IUserFactory};
+use crate::interfaces::user::IUserFactory;
 use crate::interfaces::user_manager::IUserManager;
 use crate::user::User;
 use crate::user_manager::UserManager;
@@ -16,14 +15,9 @@ pub fn bootstrap() -> Result<DIContainer, Box<dyn Error>>
         .bind::<dyn IUserManager>()
         .to::<UserManager>()?;
 
-    di_container.bind::<IUserFactory>().to_factory(&|_| {
-        Box::new(move |name, date_of_birth, password| {
-            let user: TransientPtr<dyn IUser> =
-                TransientPtr::new(User::new(name, date_of_birth, password));
-
-            user
-        })
-    })?;
+    di_container
+        .bind::<IUserFactory>()
+        .to_assisted_factory::<User>()?;
 
     Ok(di_container)
 }
@@ -1,4 +1,6 @@
-use crate::interfaces::user::IUser;
+use syrette::injectable;
+
+use crate::interfaces::user::{IUser, IUserFactory};
 
 pub struct User
 {
@@ -7,12 +9,13 @@ pub struct User
     password: &'static str,
 }
 
+#[injectable(IUser, factory = IUserFactory)]
 impl User
 {
     pub fn new(
-        name: &'static str,
-        date_of_birth: &'static str,
-        password: &'static str,
+        #[assisted] name: &'static str,
+        #[assisted] date_of_birth: &'static str,
+        #[assisted] password: &'static str,
     ) -> Self
     {
         Self {
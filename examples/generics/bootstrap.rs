@@ -1,14 +1,18 @@
-use syrette::{di_container_bind, DIContainer};
+use std::error::Error;
+
+use syrette::di_container::GenericParam;
+use syrette::DIContainer;
 
 use crate::interfaces::printer::IPrinter;
 use crate::printer::Printer;
 
-pub fn bootstrap() -> DIContainer
+pub fn bootstrap() -> Result<DIContainer, Box<dyn Error>>
 {
     let mut di_container = DIContainer::new();
 
-    di_container_bind!(IPrinter<String> => Printer, di_container);
-    di_container_bind!(IPrinter<i32> => Printer, di_container);
-
     di_container
+        .bind_generic::<dyn IPrinter<GenericParam>>()
+        .to::<Printer<GenericParam>>()?;
+
+    Ok(di_container)
 }
@@ -9,7 +9,7 @@ use crate::interfaces::printer::IPrinter;
 
 fn main() -> Result<(), Box<dyn Error>>
 {
-    let di_container = bootstrap();
+    let di_container = bootstrap()?;
 
     let string_printer = di_container.get::<dyn IPrinter<String>>()?.transient()?;
 
@@ -1,21 +1,27 @@
 use std::fmt::Display;
+use std::marker::PhantomData;
 
 use syrette::injectable;
 
 use crate::interfaces::printer::IPrinter;
 
-pub struct Printer {}
+pub struct Printer<Printable>
+{
+    _printable: PhantomData<Printable>,
+}
 
-#[injectable]
-impl Printer
+#[injectable(IPrinter<Printable>, instances = [String, i32])]
+impl<Printable: Display + 'static> Printer<Printable>
 {
     pub fn new() -> Self
     {
-        Self {}
+        Self {
+            _printable: PhantomData,
+        }
     }
 }
 
-impl<Printable: Display> IPrinter<Printable> for Printer
+impl<Printable: Display> IPrinter<Printable> for Printer<Printable>
 {
     fn print(&self, out: Printable)
     {
@@ -49,21 +49,21 @@ pub fn generate_caster(
                         .downcast::<#ty>()
                         .map_err(|_| syrette::private::cast::CasterError::CastBoxFailed)?;
 
-                    Ok(concrete as Box<#dst_trait>)
+                    Ok(concrete as syrette::private::alloc::boxed::Box<#dst_trait>)
                 },
                 |from| {
                     let concrete = from
                         .downcast::<#ty>()
                         .map_err(|_| syrette::private::cast::CasterError::CastRcFailed)?;
 
-                    Ok(concrete as std::rc::Rc<#dst_trait>)
+                    Ok(concrete as syrette::private::alloc::rc::Rc<#dst_trait>)
                 },
                 |from| {
                     let concrete = from
                         .downcast::<#ty>()
                         .map_err(|_| syrette::private::cast::CasterError::CastArcFailed)?;
 
-                    Ok(concrete as std::sync::Arc<#dst_trait>)
+                    Ok(concrete as syrette::private::alloc::sync::Arc<#dst_trait>)
                 },
             )
         }
@@ -75,14 +75,14 @@ pub fn generate_caster(
                         .downcast::<#ty>()
                         .map_err(|_| syrette::private::cast::CasterError::CastBoxFailed)?;
 
-                    Ok(concrete as Box<#dst_trait>)
+                    Ok(concrete as syrette::private::alloc::boxed::Box<#dst_trait>)
                 },
                 |from| {
                     let concrete = from
                         .downcast::<#ty>()
                         .map_err(|_| syrette::private::cast::CasterError::CastRcFailed)?;
 
-                    Ok(concrete as std::rc::Rc<#dst_trait>)
+                    Ok(concrete as syrette::private::alloc::rc::Rc<#dst_trait>)
                 },
             )
         }
@@ -91,8 +91,11 @@ pub fn generate_caster(
     quote! {
         #[syrette::private::linkme::distributed_slice(syrette::private::cast::CASTERS)]
         #[linkme(crate = syrette::private::linkme)]
-        fn #fn_ident() -> (::std::any::TypeId, syrette::private::cast::BoxedCaster) {
-            (::std::any::TypeId::of::<#ty>(), Box::new(#new_caster))
+        fn #fn_ident() -> (::core::any::TypeId, syrette::private::cast::BoxedCaster) {
+            (
+                ::core::any::TypeId::of::<#ty>(),
+                syrette::private::alloc::boxed::Box::new(#new_caster)
+            )
         }
     }
 }
@@ -0,0 +1,313 @@
+use proc_macro2::{Ident, Span};
+use quote::{format_ident, quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{Attribute, Type, Visibility};
+
+use crate::container::input::ContainerInput;
+use crate::util::error::diagnostic_error_enum;
+
+pub const CONTAINER_SCOPES: &[&str] = &["singleton", "transient"];
+
+pub struct ContainerImpl
+{
+    attrs: Vec<Attribute>,
+    visibility: Visibility,
+    ident: Ident,
+    bindings: Vec<ContainerBinding>,
+}
+
+impl ContainerImpl
+{
+    pub fn new(input: ContainerInput) -> Result<Self, ContainerImplError>
+    {
+        let mut bindings = Vec::<ContainerBinding>::with_capacity(input.bindings.len());
+
+        for binding_input in input.bindings {
+            let interface_str = binding_input.interface.to_token_stream().to_string();
+
+            if let Some(first_binding) = bindings.iter().find(|binding| {
+                binding.interface.to_token_stream().to_string() == interface_str
+            }) {
+                return Err(ContainerImplError::DuplicateBinding {
+                    interface: interface_str,
+                    interface_span: binding_input.interface.span(),
+                    first_interface_span: first_binding.interface.span(),
+                });
+            }
+
+            let scope = match &binding_input.scope {
+                Some(scope) if scope == "singleton" => BindingScope::Singleton,
+                Some(scope) if scope == "transient" => BindingScope::Transient,
+                Some(scope) => {
+                    return Err(ContainerImplError::UnknownScope {
+                        scope_ident: scope.clone(),
+                    });
+                }
+                None => BindingScope::Transient,
+            };
+
+            bindings.push(ContainerBinding {
+                interface: binding_input.interface,
+                implementation: binding_input.implementation,
+                scope,
+            });
+        }
+
+        Ok(Self {
+            attrs: input.attrs,
+            visibility: input.visibility,
+            ident: input.ident,
+            bindings,
+        })
+    }
+
+    #[cfg(not(tarpaulin_include))]
+    pub fn expand(&self) -> proc_macro2::TokenStream
+    {
+        let Self {
+            attrs,
+            visibility,
+            ident,
+            bindings,
+        } = self;
+
+        let singleton_fields = bindings
+            .iter()
+            .enumerate()
+            .filter(|(_, binding)| binding.scope == BindingScope::Singleton)
+            .map(|(index, binding)| (format_ident!("singleton_{index}"), binding))
+            .collect::<Vec<_>>();
+
+        let singleton_field_idents = singleton_fields
+            .iter()
+            .map(|(field_ident, _)| field_ident)
+            .collect::<Vec<_>>();
+
+        let singleton_field_interfaces = singleton_fields
+            .iter()
+            .map(|(_, binding)| &binding.interface);
+
+        let resolver_impls = bindings.iter().enumerate().map(|(index, binding)| {
+            self.expand_resolver_impl(binding, &format_ident!("singleton_{index}"))
+        });
+
+        quote! {
+            #(#attrs)*
+            #visibility struct #ident
+            {
+                #(
+                    #singleton_field_idents: syrette::private::once_cell::unsync::OnceCell<
+                        syrette::ptr::SingletonPtr<#singleton_field_interfaces>
+                    >,
+                )*
+            }
+
+            impl #ident
+            {
+                /// Returns a new container. Singletons are constructed when they are
+                /// first resolved.
+                #[must_use]
+                pub fn new() -> Self
+                {
+                    Self {
+                        #(
+                            #singleton_field_idents:
+                                syrette::private::once_cell::unsync::OnceCell::new(),
+                        )*
+                    }
+                }
+
+                /// Returns `Interface` as the pointer type of it's binding.
+                pub fn get<Interface>(
+                    &self
+                ) -> <Self as syrette::interfaces::static_resolver::StaticResolver<
+                    Interface
+                >>::Ptr
+                where
+                    Interface: ?Sized,
+                    Self: syrette::interfaces::static_resolver::StaticResolver<Interface>,
+                {
+                    syrette::interfaces::static_resolver::StaticResolver::<
+                        Interface
+                    >::resolve(self)
+                }
+            }
+
+            impl Default for #ident
+            {
+                fn default() -> Self
+                {
+                    Self::new()
+                }
+            }
+
+            #(#resolver_impls)*
+        }
+    }
+}
+
+impl ContainerImpl
+{
+    #[cfg(not(tarpaulin_include))]
+    fn expand_resolver_impl(
+        &self,
+        binding: &ContainerBinding,
+        singleton_field_ident: &Ident,
+    ) -> proc_macro2::TokenStream
+    {
+        let ident = &self.ident;
+
+        let ContainerBinding {
+            interface,
+            implementation,
+            scope,
+        } = binding;
+
+        let construct = quote! {
+            <#implementation as StaticInjectable<Self>>::construct(self)
+        };
+
+        let (ptr_type, resolve_body) = match scope {
+            BindingScope::Singleton => (
+                quote! { syrette::ptr::SingletonPtr<#interface> },
+                quote! {
+                    self.#singleton_field_ident
+                        .get_or_init(|| {
+                            let singleton: syrette::ptr::SingletonPtr<#interface> =
+                                syrette::ptr::SingletonPtr::new(#construct);
+
+                            singleton
+                        })
+                        .clone()
+                },
+            ),
+            BindingScope::Transient => (
+                quote! { syrette::ptr::TransientPtr<#interface> },
+                quote! {
+                    let transient: syrette::ptr::TransientPtr<#interface> =
+                        syrette::ptr::TransientPtr::new(#construct);
+
+                    transient
+                },
+            ),
+        };
+
+        quote! {
+            impl syrette::interfaces::static_resolver::StaticResolver<#interface>
+                for #ident
+            {
+                type Implementation = #implementation;
+                type Ptr = #ptr_type;
+
+                fn resolve(&self) -> Self::Ptr
+                {
+                    use syrette::interfaces::static_injectable::StaticInjectable;
+
+                    #resolve_body
+                }
+            }
+        }
+    }
+}
+
+/// A binding of a interface to a implementation in a container.
+struct ContainerBinding
+{
+    interface: Type,
+    implementation: Type,
+    scope: BindingScope,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BindingScope
+{
+    Singleton,
+    Transient,
+}
+
+diagnostic_error_enum! {
+pub enum ContainerImplError
+{
+    #[error("Unknown scope '{scope_ident}'"), span = scope_ident.span()]
+    #[help("Expected one of: {}", CONTAINER_SCOPES.join(", ")), span = scope_ident.span()]
+    UnknownScope
+    {
+        scope_ident: Ident
+    },
+
+    #[error("Duplicate binding of interface '{interface}'"), span = interface_span]
+    #[note("Previously bound here"), span = first_interface_span]
+    DuplicateBinding
+    {
+        interface: String,
+        interface_span: Span,
+        first_interface_span: Span
+    },
+}
+}
+
+#[cfg(test)]
+mod tests
+{
+    use quote::quote;
+    use syn::parse2;
+
+    use super::*;
+
+    #[test]
+    fn can_create_container_impl()
+    {
+        let container_impl = ContainerImpl::new(
+            parse2::<ContainerInput>(quote! {
+                struct AppContainer;
+
+                dyn IFoo => Foo in singleton,
+                Bar => Bar,
+            })
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            container_impl
+                .bindings
+                .iter()
+                .map(|binding| binding.scope)
+                .collect::<Vec<_>>(),
+            vec![BindingScope::Singleton, BindingScope::Transient]
+        );
+    }
+
+    #[test]
+    fn cannot_create_container_impl_with_unknown_scope()
+    {
+        assert!(matches!(
+            ContainerImpl::new(
+                parse2::<ContainerInput>(quote! {
+                    struct AppContainer;
+
+                    dyn IFoo => Foo in request,
+                })
+                .unwrap(),
+            ),
+            Err(ContainerImplError::UnknownScope { scope_ident }) if scope_ident == "request"
+        ));
+    }
+
+    #[test]
+    fn cannot_create_container_impl_with_duplicate_binding()
+    {
+        assert!(matches!(
+            ContainerImpl::new(
+                parse2::<ContainerInput>(quote! {
+                    struct AppContainer;
+
+                    dyn IFoo => Foo in singleton,
+                    dyn IFoo => OtherFoo,
+                })
+                .unwrap(),
+            ),
+            Err(ContainerImplError::DuplicateBinding { .. })
+        ));
+    }
+}
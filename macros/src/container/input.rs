@@ -0,0 +1,137 @@
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Ident, Token, Type, Visibility};
+
+/// Input of the `container` macro.
+///
+/// Like `pub struct AppContainer; dyn IFoo => Foo in singleton, Bar => Bar`.
+pub struct ContainerInput
+{
+    pub attrs: Vec<Attribute>,
+    pub visibility: Visibility,
+    pub ident: Ident,
+    pub bindings: Punctuated<ContainerBindingInput, Token![,]>,
+}
+
+impl Parse for ContainerInput
+{
+    fn parse(input: ParseStream) -> syn::Result<Self>
+    {
+        let attrs = input.call(Attribute::parse_outer)?;
+
+        let visibility = input.parse::<Visibility>()?;
+
+        input.parse::<Token![struct]>()?;
+
+        let ident = input.parse::<Ident>()?;
+
+        input.parse::<Token![;]>()?;
+
+        let bindings = Punctuated::parse_terminated(input)?;
+
+        Ok(Self {
+            attrs,
+            visibility,
+            ident,
+            bindings,
+        })
+    }
+}
+
+/// A binding in the input of the `container` macro.
+///
+/// Like `dyn IFoo => Foo in singleton`.
+pub struct ContainerBindingInput
+{
+    pub interface: Type,
+    pub implementation: Type,
+    pub scope: Option<Ident>,
+}
+
+impl Parse for ContainerBindingInput
+{
+    fn parse(input: ParseStream) -> syn::Result<Self>
+    {
+        let interface = input.parse::<Type>()?;
+
+        input.parse::<Token![=>]>()?;
+
+        let implementation = input.parse::<Type>()?;
+
+        let scope = if input.peek(Token![in]) {
+            input.parse::<Token![in]>()?;
+
+            Some(input.parse::<Ident>()?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            interface,
+            implementation,
+            scope,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use quote::{format_ident, quote};
+    use syn::{parse2, parse_quote};
+
+    use super::*;
+
+    #[test]
+    fn can_parse_binding()
+    {
+        let binding_input = parse2::<ContainerBindingInput>(quote! {
+            dyn IFoo => Foo in singleton
+        })
+        .unwrap();
+
+        assert_eq!(binding_input.interface, parse_quote! { dyn IFoo });
+        assert_eq!(binding_input.implementation, parse_quote! { Foo });
+        assert_eq!(binding_input.scope, Some(format_ident!("singleton")));
+    }
+
+    #[test]
+    fn can_parse_binding_without_scope()
+    {
+        let binding_input = parse2::<ContainerBindingInput>(quote! {
+            Bar<u8> => Bar<u8>
+        })
+        .unwrap();
+
+        assert_eq!(binding_input.interface, parse_quote! { Bar<u8> });
+        assert_eq!(binding_input.implementation, parse_quote! { Bar<u8> });
+        assert!(binding_input.scope.is_none());
+    }
+
+    #[test]
+    fn can_parse_container()
+    {
+        let container_input = parse2::<ContainerInput>(quote! {
+            /// The container of the app.
+            pub struct AppContainer;
+
+            dyn IFoo => Foo in singleton,
+            Bar => Bar in transient,
+        })
+        .unwrap();
+
+        assert_eq!(container_input.attrs.len(), 1);
+        assert_eq!(container_input.visibility, parse_quote! { pub });
+        assert_eq!(container_input.ident, format_ident!("AppContainer"));
+        assert_eq!(container_input.bindings.len(), 2);
+    }
+
+    #[test]
+    fn cannot_parse_container_without_struct()
+    {
+        assert!(parse2::<ContainerInput>(quote! {
+            dyn IFoo => Foo in singleton
+        })
+        .is_err());
+    }
+}
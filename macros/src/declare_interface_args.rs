@@ -1,6 +1,6 @@
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::{Token, TypePath};
+use syn::{bracketed, Ident, Token, Type, TypePath, WhereClause};
 
 use crate::macro_flag::MacroFlag;
 use crate::util::iterator_ext::IteratorExt;
@@ -10,7 +10,9 @@ pub const DECLARE_INTERFACE_FLAGS: &[&str] = &["threadsafe_sharable"];
 pub struct DeclareInterfaceArgs
 {
     pub implementation: TypePath,
-    pub interface: TypePath,
+    pub interfaces: Punctuated<TypePath, Token![,]>,
+    pub opt_where_clause: Option<WhereClause>,
+    pub opt_instances: Option<DeclareInterfaceInstances>,
     pub flags: Punctuated<MacroFlag, Token![,]>,
 }
 
@@ -22,7 +24,32 @@ impl Parse for DeclareInterfaceArgs
 
         input.parse::<Token![->]>()?;
 
-        let interface: TypePath = input.parse()?;
+        let mut interfaces = Punctuated::<TypePath, Token![,]>::new();
+
+        interfaces.push_value(input.parse()?);
+
+        while input.peek(Token![,]) && !Self::peek_flags(input) {
+            interfaces.push_punct(input.parse()?);
+            interfaces.push_value(input.parse()?);
+        }
+
+        let opt_where_clause = if input.peek(Token![where]) {
+            Some(input.parse::<WhereClause>()?)
+        } else {
+            None
+        };
+
+        let opt_instances = if input.peek(Token![;]) {
+            input.parse::<Token![;]>()?;
+
+            Some(input.parse::<DeclareInterfaceInstances>()?)
+        } else {
+            None
+        };
+
+        if opt_where_clause.is_some() && opt_instances.is_none() {
+            return Err(input.error("Expected a 'for' clause after the where clause"));
+        }
 
         let flags = if input.peek(Token![,]) {
             input.parse::<Token![,]>()?;
@@ -51,12 +78,61 @@ impl Parse for DeclareInterfaceArgs
 
         Ok(Self {
             implementation,
-            interface,
+            interfaces,
+            opt_where_clause,
+            opt_instances,
             flags,
         })
     }
 }
 
+impl DeclareInterfaceArgs
+{
+    /// Returns whether or not the input continues with a comma followed by flags or
+    /// by nothing.
+    fn peek_flags(input: ParseStream) -> bool
+    {
+        let fork = input.fork();
+
+        if fork.parse::<Token![,]>().is_err() {
+            return false;
+        }
+
+        fork.is_empty() || (fork.peek(Ident) && fork.peek2(Token![=]))
+    }
+}
+
+/// The types to declare the interfaces for, given as `for T in [A, B, C]`.
+pub struct DeclareInterfaceInstances
+{
+    pub type_param: Ident,
+    pub types: Punctuated<Type, Token![,]>,
+}
+
+impl Parse for DeclareInterfaceInstances
+{
+    fn parse(input: ParseStream) -> syn::Result<Self>
+    {
+        input.parse::<Token![for]>()?;
+
+        let type_param: Ident = input.parse()?;
+
+        input.parse::<Token![in]>()?;
+
+        let content;
+
+        let bracket = bracketed!(content in input);
+
+        let types = Punctuated::<Type, Token![,]>::parse_terminated(&content)?;
+
+        if types.is_empty() {
+            return Err(syn::Error::new(bracket.span, "Expected at least one type"));
+        }
+
+        Ok(Self { type_param, types })
+    }
+}
+
 #[cfg(test)]
 mod tests
 {
@@ -89,14 +165,14 @@ mod tests
         );
 
         assert_eq!(
-            decl_interface_args.interface,
-            TypePath {
+            decl_interface_args.interfaces,
+            Punctuated::from_iter(vec![TypePath {
                 qself: None,
                 path: test_utils::create_path(&[test_utils::create_path_segment(
                     format_ident!("IFoo"),
                     &[]
                 )])
-            }
+            }])
         );
 
         assert!(decl_interface_args.flags.is_empty());
@@ -123,14 +199,14 @@ mod tests
         );
 
         assert_eq!(
-            decl_interface_args.interface,
-            TypePath {
+            decl_interface_args.interfaces,
+            Punctuated::from_iter(vec![TypePath {
                 qself: None,
                 path: test_utils::create_path(&[test_utils::create_path_segment(
                     format_ident!("IFoobar"),
                     &[]
                 )])
-            }
+            }])
         );
 
         assert_eq!(
@@ -145,6 +221,62 @@ mod tests
         );
     }
 
+    #[test]
+    fn can_parse_with_multiple_interfaces_and_instances()
+    {
+        let input_args = quote! {
+            Foo<T> -> IFoo<T>, IBar where T: Display; for T in [String, u32],
+                threadsafe_sharable = true
+        };
+
+        let decl_interface_args = parse2::<DeclareInterfaceArgs>(input_args).unwrap();
+
+        assert_eq!(
+            decl_interface_args.interfaces,
+            Punctuated::from_iter(vec![
+                parse2::<TypePath>(quote! { IFoo<T> }).unwrap(),
+                parse2::<TypePath>(quote! { IBar }).unwrap(),
+            ])
+        );
+
+        assert_eq!(
+            decl_interface_args.opt_where_clause,
+            Some(parse2::<WhereClause>(quote! { where T: Display }).unwrap())
+        );
+
+        let instances = decl_interface_args.opt_instances.unwrap();
+
+        assert_eq!(instances.type_param, format_ident!("T"));
+
+        assert_eq!(
+            instances.types,
+            Punctuated::from_iter(vec![
+                parse2::<Type>(quote! { String }).unwrap(),
+                parse2::<Type>(quote! { u32 }).unwrap(),
+            ])
+        );
+
+        assert_eq!(decl_interface_args.flags.len(), 1);
+    }
+
+    #[test]
+    fn cannot_parse_with_where_clause_without_instances()
+    {
+        assert!(parse2::<DeclareInterfaceArgs>(quote! {
+            Foo<T> -> IFoo<T> where T: Display
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn cannot_parse_with_empty_instances()
+    {
+        assert!(parse2::<DeclareInterfaceArgs>(quote! {
+            Foo<T> -> IFoo<T>; for T in []
+        })
+        .is_err());
+    }
+
     #[test]
     fn cannot_parse_with_invalid_flag()
     {
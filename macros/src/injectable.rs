@@ -3,3 +3,4 @@ pub mod dummy;
 pub mod implementation;
 pub mod macro_args;
 pub mod named_attr_input;
+pub mod ptr_attr_input;
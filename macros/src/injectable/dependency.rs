@@ -1,11 +1,99 @@
 use proc_macro2::{Ident, Span};
 use syn::spanned::Spanned;
-use syn::{parse2, FnArg, GenericArgument, LitStr, PathArguments, Type};
+use syn::{
+    parse2,
+    Attribute,
+    FnArg,
+    GenericArgument,
+    LitStr,
+    PathArguments,
+    PathSegment,
+    Type,
+};
 
 use crate::injectable::named_attr_input::NamedAttrInput;
+use crate::injectable::ptr_attr_input::PtrAttrInput;
 use crate::util::error::diagnostic_error_enum;
 use crate::util::syn_path::SynPathExt;
 
+/// Smart pointer types that dependencies can be given as.
+const DEPENDENCY_PTRS: &[PtrKind] = &[
+    PtrKind::Transient,
+    PtrKind::Singleton,
+    PtrKind::ThreadsafeSingleton,
+    PtrKind::Factory,
+    PtrKind::ThreadsafeFactory,
+    PtrKind::Some,
+    PtrKind::Injected,
+];
+
+/// DI container reference types that are given the DI container resolving the
+/// dependencies.
+const CONTAINER_REFS: &[PtrKind] = &[PtrKind::ContainerRef, PtrKind::AsyncContainerRef];
+
+/// Standard library smart pointers & the pointer types they are equivalent to.
+const STD_PTRS: &[(&str, PtrKind)] = &[
+    ("Box", PtrKind::Transient),
+    ("Rc", PtrKind::Singleton),
+    ("Arc", PtrKind::ThreadsafeSingleton),
+];
+
+/// Values of the `ptr` argument attribute & the pointer types they declare.
+const PTR_KINDS: &[(&str, PtrKind)] = &[
+    ("transient", PtrKind::Transient),
+    ("singleton", PtrKind::Singleton),
+    ("threadsafe_singleton", PtrKind::ThreadsafeSingleton),
+    ("factory", PtrKind::Factory),
+    ("threadsafe_factory", PtrKind::ThreadsafeFactory),
+    ("some", PtrKind::Some),
+];
+
+/// The kind of pointer type that a dependency is given as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PtrKind
+{
+    Transient,
+    Singleton,
+    ThreadsafeSingleton,
+    Factory,
+    ThreadsafeFactory,
+    Some,
+    Injected,
+    ContainerRef,
+    AsyncContainerRef,
+}
+
+impl PtrKind
+{
+    /// Returns the name of the pointer type in Syrette.
+    pub fn type_name(self) -> &'static str
+    {
+        match self {
+            Self::Transient => "TransientPtr",
+            Self::Singleton => "SingletonPtr",
+            Self::ThreadsafeSingleton => "ThreadsafeSingletonPtr",
+            Self::Factory => "FactoryPtr",
+            Self::ThreadsafeFactory => "ThreadsafeFactoryPtr",
+            Self::Some => "SomePtr",
+            Self::Injected => "InjectedPtr",
+            Self::ContainerRef => "ContainerRef",
+            Self::AsyncContainerRef => "AsyncContainerRef",
+        }
+    }
+
+    /// Returns whether or not the pointer type is a reference to the DI container.
+    pub fn is_container_ref(self) -> bool
+    {
+        CONTAINER_REFS.contains(&self)
+    }
+
+    /// Returns the pointer type with the given name, if it's one of `kinds`.
+    fn find_by_type_name(kinds: &[Self], name: &Ident) -> Option<Self>
+    {
+        kinds.iter().copied().find(|kind| name == kind.type_name())
+    }
+}
+
 /// Representation of a dependency of a injectable type.
 ///
 /// Found as a argument in the constructor method of a `Injectable`.
@@ -14,10 +102,11 @@ pub struct Dependency
 {
     interface: Type,
     ptr: Ident,
+    ptr_kind: PtrKind,
     name: Option<LitStr>,
 }
 
-#[cfg_attr(test, mockall::automock)]
+#[cfg_attr(test, mockall::automock, allow(clippy::struct_field_names))]
 impl Dependency
 {
     /// Build a new `Dependency` from a argument in a constructor method.
@@ -54,31 +143,50 @@ impl Dependency
 
         let ptr_ident = ptr_path_segment.ident.clone();
 
-        let ptr_generic_args = match ptr_path_segment.arguments.clone() {
-            PathArguments::AngleBracketed(generic_args) => Ok(generic_args),
-            _ => Err(DependencyError::DependencyTypeMissingGenerics {
-                ptr_ident_span: ptr_ident.span(),
-            }),
-        }?
-        .args;
+        let arg_attrs = &typed_ctor_method_arg.attrs;
 
-        let interface =
-            if let Some(GenericArgument::Type(interface)) = ptr_generic_args.first() {
-                Ok(interface.clone())
-            } else {
-                Err(DependencyError::DependencyTypeMissingGenerics {
-                    ptr_ident_span: ptr_ident.span(),
+        let opt_ptr_attr = find_arg_attr(arg_attrs, "ptr");
+        let opt_named_attr = find_arg_attr(arg_attrs, "named");
+
+        let opt_container_ref = if opt_ptr_attr.is_none() {
+            PtrKind::find_by_type_name(CONTAINER_REFS, &ptr_ident)
+        } else {
+            None
+        };
+
+        if let Some(container_ref) = opt_container_ref {
+            if let Some(named_attr) = opt_named_attr {
+                return Err(DependencyError::NamedContainerRef {
+                    named_attr_span: named_attr.span(),
+                });
+            }
+
+            return Ok(Self {
+                interface: Type::Path(dependency_type_path.clone()),
+                ptr: ptr_ident,
+                ptr_kind: container_ref,
+                name: None,
+            });
+        }
+
+        let opt_ptr_attr_input = opt_ptr_attr
+            .map(|ptr_attr| {
+                parse2::<PtrAttrInput>(ptr_attr.tokens.clone()).map_err(|err| {
+                    DependencyError::InvalidPtrAttrInput {
+                        arg_span: typed_ctor_method_arg.span(),
+                        err,
+                    }
                 })
-            }?;
+            })
+            .transpose()?;
 
-        let arg_attrs = &typed_ctor_method_arg.attrs;
+        let (ptr_kind, ptr_span) =
+            resolve_ptr_kind(&ptr_ident, opt_ptr_attr_input.as_ref())?;
 
-        let opt_named_attr = arg_attrs.iter().find(|attr| {
-            attr.path.get_ident().map_or_else(
-                || false,
-                |attr_ident| attr_ident.to_string().as_str() == "named",
-            ) || &attr.path.to_string() == "syrette::named"
-        });
+        let interface = match opt_ptr_attr_input.and_then(|input| input.opt_interface) {
+            Some(attr_interface) => attr_interface.interface,
+            None => find_generic_interface(ptr_path_segment, opt_ptr_attr.is_some())?,
+        };
 
         let opt_named_attr_tokens = opt_named_attr.map(|attr| &attr.tokens);
 
@@ -96,7 +204,8 @@ impl Dependency
 
         Ok(Self {
             interface,
-            ptr: ptr_ident,
+            ptr: Ident::new(ptr_kind.type_name(), ptr_span),
+            ptr_kind,
             name: opt_named_attr_input.map(|named_attr_input| named_attr_input.name),
         })
     }
@@ -115,14 +224,98 @@ impl Dependency
         &self.ptr
     }
 
+    /// Returns the kind of the dependency's pointer type.
+    #[allow(dead_code)] // Mock function is never used
+    pub fn get_ptr_kind(&self) -> PtrKind
+    {
+        self.ptr_kind
+    }
+
     /// Returns the dependency's name.
     #[allow(dead_code)] // Mock function is never used
+    #[allow(clippy::ref_option)] // Mockall can't return a Option<&LitStr>
     pub fn get_name(&self) -> &Option<LitStr>
     {
         &self.name
     }
 }
 
+/// Returns the argument attribute with the given name, optionally prefixed with
+/// `syrette::`.
+fn find_arg_attr<'attrs>(
+    attrs: &'attrs [Attribute],
+    name: &str,
+) -> Option<&'attrs Attribute>
+{
+    attrs.iter().find(|attr| {
+        attr.path
+            .get_ident()
+            .map_or_else(|| false, |attr_ident| attr_ident == name)
+            || attr.path.to_string() == format!("syrette::{name}")
+    })
+}
+
+/// Returns the interface given as the first generic argument of a pointer type.
+///
+/// `is_alias` is whether the pointer type is a alias declared with the `ptr` attribute.
+fn find_generic_interface(
+    ptr_path_segment: &PathSegment,
+    is_alias: bool,
+) -> Result<Type, DependencyError>
+{
+    let opt_interface = match &ptr_path_segment.arguments {
+        PathArguments::AngleBracketed(generic_args) => generic_args.args.first(),
+        _ => None,
+    };
+
+    match opt_interface {
+        Some(GenericArgument::Type(interface)) => Ok(interface.clone()),
+        _ if is_alias => Err(DependencyError::PtrAliasMissingInterface {
+            ptr_ident_span: ptr_path_segment.ident.span(),
+        }),
+        _ => Err(DependencyError::DependencyTypeMissingGenerics {
+            ptr_ident_span: ptr_path_segment.ident.span(),
+        }),
+    }
+}
+
+/// Returns the kind of the pointer type of a dependency, declared either with the `ptr`
+/// attribute or by the pointer type itself, together with the span of where it's
+/// declared.
+fn resolve_ptr_kind(
+    ptr_ident: &Ident,
+    opt_ptr_attr_input: Option<&PtrAttrInput>,
+) -> Result<(PtrKind, Span), DependencyError>
+{
+    if let Some(ptr_attr_input) = opt_ptr_attr_input {
+        let ptr_kind =
+            find_ptr_kind(PTR_KINDS, &ptr_attr_input.kind).ok_or_else(|| {
+                DependencyError::UnknownPtrKind {
+                    kind: ptr_attr_input.kind.clone(),
+                }
+            })?;
+
+        return Ok((ptr_kind, ptr_attr_input.kind.span()));
+    }
+
+    let ptr_kind = PtrKind::find_by_type_name(DEPENDENCY_PTRS, ptr_ident)
+        .or_else(|| find_ptr_kind(STD_PTRS, ptr_ident))
+        .ok_or_else(|| DependencyError::UnknownPtr {
+            ptr_ident: ptr_ident.clone(),
+        })?;
+
+    Ok((ptr_kind, ptr_ident.span()))
+}
+
+/// Returns the pointer type that `ptr` is mapped to in the given mappings.
+fn find_ptr_kind(mappings: &[(&str, PtrKind)], ptr: &Ident) -> Option<PtrKind>
+{
+    mappings
+        .iter()
+        .find(|(name, _)| ptr == name)
+        .map(|(_, ptr_kind)| *ptr_kind)
+}
+
 diagnostic_error_enum! {
 pub enum DependencyError
 {
@@ -153,6 +346,60 @@ pub enum DependencyError
         ptr_ident_span: Span
     },
 
+    #[error("Unknown smart pointer type '{}'", ptr_ident), span = ptr_ident.span()]
+    #[help(
+        "Expected one of [ {}, {} ]",
+        DEPENDENCY_PTRS
+            .iter()
+            .map(|ptr_kind| ptr_kind.type_name())
+            .collect::<Vec<_>>()
+            .join(", "),
+        STD_PTRS.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", ")
+    )]
+    #[help("Declare the pointer type of a type alias with the 'ptr' attribute")]
+    UnknownPtr {
+        ptr_ident: Ident
+    },
+
+    #[error("Unknown pointer type '{}'", kind), span = kind.span()]
+    #[help(
+        "Expected one of [ {} ]",
+        PTR_KINDS.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", ")
+    )]
+    UnknownPtrKind {
+        kind: Ident
+    },
+
+    #[
+        error("Expected pointer type alias to either take generic parameters or have a interface"),
+        span = ptr_ident_span
+    ]
+    #[help(
+        "Give the interface with the 'ptr' attribute, for example '{}'",
+        "#[ptr(singleton, interface = dyn IFoo)]"
+    )]
+    PtrAliasMissingInterface {
+        ptr_ident_span: Span
+    },
+
+    #[error("Dependency has a 'ptr' attribute given invalid input"), span = arg_span]
+    #[help(
+        "Expected a pointer type and optionally a interface, for example '{}' or '{}'",
+        "#[ptr = singleton]",
+        "#[ptr(singleton, interface = dyn IFoo)]"
+    )]
+    #[source(err)]
+    InvalidPtrAttrInput {
+        arg_span: Span,
+        err: syn::Error
+    },
+
+    #[error("A DI container reference can't be named"), span = named_attr_span]
+    #[help("Remove the 'named' attribute"), span = named_attr_span]
+    NamedContainerRef {
+        named_attr_span: Span
+    },
+
     #[error("Dependency has a 'named' attribute given invalid input"), span = arg_span]
     #[source(err)]
     InvalidNamedAttrInput {
@@ -206,6 +453,7 @@ mod tests
                     PathSegment::from(format_ident!("Foo"))
                 ])),
                 ptr: format_ident!("TransientPtr"),
+                ptr_kind: PtrKind::Transient,
                 name: None
             }
         ));
@@ -231,6 +479,7 @@ mod tests
                     PathSegment::from(format_ident!("Bar"))
                 ])),
                 ptr: format_ident!("SingletonPtr"),
+                ptr_kind: PtrKind::Singleton,
                 name: None
             }
         ));
@@ -267,6 +516,7 @@ mod tests
                     PathSegment::from(format_ident!("Foo"))
                 ])),
                 ptr: format_ident!("TransientPtr"),
+                ptr_kind: PtrKind::Transient,
                 name: Some(LitStr::new("cool", Span::call_site()))
             }
         ));
@@ -301,6 +551,7 @@ mod tests
                     PathSegment::from(format_ident!("Bar"))
                 ])),
                 ptr: format_ident!("FactoryPtr"),
+                ptr_kind: PtrKind::Factory,
                 name: Some(LitStr::new("awesome", Span::call_site()))
             }
         ));
@@ -353,6 +604,229 @@ mod tests
         .is_err());
     }
 
+    #[test]
+    fn cannot_build_dependency_with_unknown_ptr()
+    {
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(
+                        format_ident!("Cell"),
+                        &[test_utils::create_type(test_utils::create_path(&[
+                            test_utils::create_path_segment(format_ident!("Foo"), &[])
+                        ]))]
+                    ),
+                ])))
+            })),
+            Err(DependencyError::UnknownPtr { ptr_ident }) if ptr_ident == "Cell"
+        ));
+    }
+
+    #[test]
+    fn can_build_dependency_with_std_ptr()
+    {
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(format_ident!("std"), &[]),
+                    test_utils::create_path_segment(format_ident!("rc"), &[]),
+                    test_utils::create_path_segment(
+                        format_ident!("Rc"),
+                        &[test_utils::create_type(test_utils::create_path(&[
+                            test_utils::create_path_segment(format_ident!("Foo"), &[])
+                        ]))]
+                    ),
+                ])))
+            })),
+            Ok(dependency) if dependency == Dependency {
+                interface: test_utils::create_type(test_utils::create_path(&[
+                    PathSegment::from(format_ident!("Foo"))
+                ])),
+                ptr: format_ident!("SingletonPtr"),
+                ptr_kind: PtrKind::Singleton,
+                name: None
+            }
+        ));
+    }
+
+    #[test]
+    fn can_build_dependency_with_ptr_attr()
+    {
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![Attribute {
+                    pound_token: Pound::default(),
+                    style: AttrStyle::Outer,
+                    bracket_token: Bracket::default(),
+                    path: test_utils::create_path(&[test_utils::create_path_segment(
+                        format_ident!("ptr"),
+                        &[]
+                    )]),
+                    tokens: quote! { = threadsafe_singleton }
+                }],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(
+                        format_ident!("Shared"),
+                        &[test_utils::create_type(test_utils::create_path(&[
+                            test_utils::create_path_segment(format_ident!("Foo"), &[])
+                        ]))]
+                    ),
+                ])))
+            })),
+            Ok(dependency) if dependency == Dependency {
+                interface: test_utils::create_type(test_utils::create_path(&[
+                    PathSegment::from(format_ident!("Foo"))
+                ])),
+                ptr: format_ident!("ThreadsafeSingletonPtr"),
+                ptr_kind: PtrKind::ThreadsafeSingleton,
+                name: None
+            }
+        ));
+    }
+
+    #[test]
+    fn can_build_dependency_with_ptr_attr_interface()
+    {
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![Attribute {
+                    pound_token: Pound::default(),
+                    style: AttrStyle::Outer,
+                    bracket_token: Bracket::default(),
+                    path: test_utils::create_path(&[test_utils::create_path_segment(
+                        format_ident!("ptr"),
+                        &[]
+                    )]),
+                    tokens: quote! { (singleton, interface = Foo) }
+                }],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(format_ident!("SharedFoo"), &[]),
+                ])))
+            })),
+            Ok(dependency) if dependency == Dependency {
+                interface: test_utils::create_type(test_utils::create_path(&[
+                    PathSegment::from(format_ident!("Foo"))
+                ])),
+                ptr: format_ident!("SingletonPtr"),
+                ptr_kind: PtrKind::Singleton,
+                name: None
+            }
+        ));
+    }
+
+    #[test]
+    fn cannot_build_dependency_with_ptr_attr_without_interface_or_generics()
+    {
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![Attribute {
+                    pound_token: Pound::default(),
+                    style: AttrStyle::Outer,
+                    bracket_token: Bracket::default(),
+                    path: test_utils::create_path(&[test_utils::create_path_segment(
+                        format_ident!("ptr"),
+                        &[]
+                    )]),
+                    tokens: quote! { = singleton }
+                }],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(format_ident!("SharedFoo"), &[]),
+                ])))
+            })),
+            Err(DependencyError::PtrAliasMissingInterface { .. })
+        ));
+    }
+
+    #[test]
+    fn can_build_container_ref_dependency()
+    {
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(format_ident!("ContainerRef"), &[]),
+                ])))
+            })),
+            Ok(dependency) if dependency == Dependency {
+                interface: test_utils::create_type(test_utils::create_path(&[
+                    PathSegment::from(format_ident!("ContainerRef"))
+                ])),
+                ptr: format_ident!("ContainerRef"),
+                ptr_kind: PtrKind::ContainerRef,
+                name: None
+            }
+        ));
+    }
+
+    #[test]
+    fn cannot_build_named_container_ref_dependency()
+    {
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![Attribute {
+                    pound_token: Pound::default(),
+                    style: AttrStyle::Outer,
+                    bracket_token: Bracket::default(),
+                    path: test_utils::create_path(&[test_utils::create_path_segment(
+                        format_ident!("named"),
+                        &[]
+                    )]),
+                    tokens: quote! { ("cool") }
+                }],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(format_ident!("ContainerRef"), &[]),
+                ])))
+            })),
+            Err(DependencyError::NamedContainerRef { .. })
+        ));
+    }
+
+    #[test]
+    fn cannot_build_dependency_with_unknown_ptr_kind()
+    {
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![Attribute {
+                    pound_token: Pound::default(),
+                    style: AttrStyle::Outer,
+                    bracket_token: Bracket::default(),
+                    path: test_utils::create_path(&[test_utils::create_path_segment(
+                        format_ident!("ptr"),
+                        &[]
+                    )]),
+                    tokens: quote! { = scoped }
+                }],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(
+                        format_ident!("Shared"),
+                        &[test_utils::create_type(test_utils::create_path(&[
+                            test_utils::create_path_segment(format_ident!("Foo"), &[])
+                        ]))]
+                    ),
+                ])))
+            })),
+            Err(DependencyError::UnknownPtrKind { kind }) if kind == "scoped"
+        ));
+    }
+
     #[test]
     fn cannot_build_dependency_without_generics_args()
     {
@@ -2,24 +2,30 @@ use proc_macro2::{Ident, Span};
 use quote::{format_ident, quote, ToTokens};
 use syn::spanned::Spanned;
 use syn::{
+    parse_quote,
+    Attribute,
     Expr,
     ExprCall,
     ExprLit,
     ExprMethodCall,
     ExprPath,
     FnArg,
+    GenericArgument,
     GenericMethodArgument,
+    GenericParam,
+    Generics,
     ImplItemMethod,
     ItemImpl,
+    LitStr,
     MethodTurbofish,
+    PathArguments,
     ReturnType,
     Type,
 };
 
-use crate::injectable::dependency::DependencyError;
+use crate::injectable::dependency::{DependencyError, PtrKind};
 use crate::util::error::diagnostic_error_enum;
 use crate::util::item_impl::find_impl_method_by_name_mut;
-use crate::util::string::camelcase_to_snakecase;
 use crate::util::syn_ext::{
     ExprCallExt,
     ExprLitExt,
@@ -28,19 +34,26 @@ use crate::util::syn_ext::{
     MethodTurbofishExt,
 };
 use crate::util::syn_path::{syn_path, SynPathExt};
+use crate::util::type_param::replace_type_param;
 use crate::util::use_double;
 
 use_double!(crate::injectable::dependency::Dependency);
 
 const DI_CONTAINER_VAR_NAME: &str = "di_container";
 const DEPENDENCY_HISTORY_VAR_NAME: &str = "dependency_history";
+const DI_CONTAINER_TYPE_PARAM_NAME: &str = "DIContainerT";
+
+/// The dependency pointer types that a static container can provide.
+const STATIC_DEPENDENCY_PTRS: &[PtrKind] = &[PtrKind::Transient, PtrKind::Singleton];
 
 pub struct InjectableImpl
 {
     dependencies: Vec<Dependency>,
+    assisted_args: Vec<AssistedArg>,
     original_impl: ItemImpl,
 
     constructor_method: ImplItemMethod,
+    is_constructor_fallible: bool,
 }
 
 impl InjectableImpl
@@ -75,34 +88,99 @@ impl InjectableImpl
                 }
             })?;
 
+        let assisted_args = Self::find_assisted_args(constructor_method);
+
         Self::remove_method_argument_attrs(constructor_method);
 
+        let is_constructor_fallible = Self::is_fallible_constructor(constructor_method);
+
         let constructor_method = constructor_method.clone();
 
         Ok(Self {
             dependencies,
+            assisted_args,
             original_impl: item_impl,
             constructor_method,
+            is_constructor_fallible,
         })
     }
 
-    pub fn validate(&self, is_async: bool) -> Result<(), InjectableImplError>
+    pub fn validate(
+        &self,
+        is_async: bool,
+        is_factory: bool,
+        is_registered: bool,
+    ) -> Result<(), InjectableImplError>
     {
-        if matches!(self.constructor_method.sig.output, ReturnType::Default) {
-            return Err(InjectableImplError::InvalidConstructorMethodReturnType {
+        if is_registered && !self.original_impl.generics.params.is_empty() {
+            return Err(InjectableImplError::RegisteredGeneric {
+                generics_span: self.original_impl.generics.span(),
+            });
+        }
+
+        if !is_factory {
+            if let Some(assisted_arg) = self.assisted_args.first() {
+                return Err(InjectableImplError::AssistedArgWithoutFactoryFlag {
+                    assisted_arg_span: assisted_arg.ty.span(),
+                });
+            }
+        }
+
+        let expected_container_ref = if is_async {
+            PtrKind::AsyncContainerRef
+        } else {
+            PtrKind::ContainerRef
+        };
+
+        if let Some(wrong_container_ref) = self.dependencies.iter().find(|dependency| {
+            Self::is_container_ref(dependency)
+                && dependency.get_ptr_kind() != expected_container_ref
+        }) {
+            return Err(InjectableImplError::WrongContainerRef {
+                ptr_ident_span: wrong_container_ref.get_ptr().span(),
+                expected: expected_container_ref.type_name(),
+            });
+        }
+
+        if is_factory {
+            if let Some(transient_dependency) = self
+                .dependencies
+                .iter()
+                .find(|dependency| dependency.get_ptr_kind() == PtrKind::Transient)
+            {
+                return Err(InjectableImplError::TransientDependencyOfAssisted {
+                    ptr_ident_span: transient_dependency.get_ptr().span(),
+                });
+            }
+
+            if let Some(scope_agnostic_dependency) =
+                self.dependencies.iter().find(|dependency| {
+                    Self::get_ptr_conversion_method(dependency.get_ptr_kind()).is_none()
+                        && !Self::is_container_ref(dependency)
+                })
+            {
+                return Err(InjectableImplError::ScopeAgnosticDependencyOfAssisted {
+                    ptr_ident_span: scope_agnostic_dependency.get_ptr().span(),
+                });
+            }
+        }
+
+        if is_factory && self.is_constructor_fallible {
+            return Err(InjectableImplError::FallibleConstructorOfAssisted {
                 ctor_method_output_span: self.constructor_method.sig.output.span(),
-                expected: "Self".to_string(),
-                found: "()".to_string(),
             });
         }
 
-        if let ReturnType::Type(_, ret_type) = &self.constructor_method.sig.output {
-            if let Type::Path(path_type) = ret_type.as_ref() {
-                if path_type
-                    .path
-                    .get_ident()
-                    .map_or_else(|| true, |ident| *ident != "Self")
-                {
+        match &self.constructor_method.sig.output {
+            ReturnType::Default => {
+                return Err(InjectableImplError::InvalidConstructorMethodReturnType {
+                    ctor_method_output_span: self.constructor_method.sig.output.span(),
+                    expected: "Self".to_string(),
+                    found: "()".to_string(),
+                });
+            }
+            ReturnType::Type(_, ret_type) => {
+                if !self.is_constructor_fallible && !Self::is_self_type(ret_type) {
                     return Err(
                         InjectableImplError::InvalidConstructorMethodReturnType {
                             ctor_method_output_span: self
@@ -115,12 +193,6 @@ impl InjectableImpl
                         },
                     );
                 }
-            } else {
-                return Err(InjectableImplError::InvalidConstructorMethodReturnType {
-                    ctor_method_output_span: self.constructor_method.sig.output.span(),
-                    expected: "Self".to_string(),
-                    found: ret_type.to_token_stream().to_string(),
-                });
             }
         }
 
@@ -148,14 +220,36 @@ impl InjectableImpl
         Ok(())
     }
 
+    /// Validates the implementation for use with the `instances` flag, returning it's
+    /// type parameter.
+    pub fn validate_generic_instances(&self) -> Result<&Ident, InjectableImplError>
+    {
+        let generics = &self.original_impl.generics;
+
+        match generics.params.iter().collect::<Vec<_>>().as_slice() {
+            [GenericParam::Type(type_param)] => Ok(&type_param.ident),
+            _ => Err(InjectableImplError::InstancesWithoutSingleTypeParam {
+                generics_span: if generics.params.is_empty() {
+                    self.original_impl.self_ty.span()
+                } else {
+                    generics.span()
+                },
+            }),
+        }
+    }
+
     pub fn self_type(&self) -> &Type
     {
         &self.original_impl.self_ty
     }
 
     #[cfg(not(tarpaulin_include))]
-    pub fn expand(&self, no_doc_hidden: bool, is_async: bool)
-        -> proc_macro2::TokenStream
+    pub fn expand(
+        &self,
+        no_doc_hidden: bool,
+        is_async: bool,
+        opt_factory: Option<&AssistedFactory>,
+    ) -> proc_macro2::TokenStream
     {
         let di_container_var = format_ident!("{}", DI_CONTAINER_VAR_NAME);
         let dependency_history_var = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
@@ -172,6 +266,12 @@ impl InjectableImpl
             quote! {}
         };
 
+        let handle_dependency_history = quote! {
+            #maybe_prevent_circular_deps
+
+            #dependency_history_var.push::<Self>();
+        };
+
         let get_dep_method_calls = Self::create_get_dep_method_calls(
             &self.dependencies,
             is_async,
@@ -179,12 +279,21 @@ impl InjectableImpl
             &dependency_history_var,
         );
 
-        let injectable_impl = if is_async {
+        let injectable_impl = if let Some(factory) = opt_factory {
+            self.expand_assisted_factory_impl(
+                factory,
+                &maybe_doc_hidden,
+                &di_container_var,
+                &dependency_history_var,
+                &handle_dependency_history,
+                &get_dep_method_calls,
+            )
+        } else if is_async {
             self.expand_async_impl(
                 &maybe_doc_hidden,
                 &di_container_var,
                 &dependency_history_var,
-                &maybe_prevent_circular_deps,
+                &handle_dependency_history,
                 &get_dep_method_calls,
             )
         } else {
@@ -192,17 +301,25 @@ impl InjectableImpl
                 &maybe_doc_hidden,
                 &di_container_var,
                 &dependency_history_var,
-                &maybe_prevent_circular_deps,
+                &handle_dependency_history,
                 &get_dep_method_calls,
             )
         };
 
+        let maybe_static_injectable_impl = if is_async || opt_factory.is_some() {
+            quote! {}
+        } else {
+            self.expand_static_injectable_impl(&maybe_doc_hidden)
+        };
+
         let original_impl = &self.original_impl;
 
         quote! {
             #original_impl
 
             #injectable_impl
+
+            #maybe_static_injectable_impl
         }
     }
 
@@ -219,8 +336,21 @@ impl InjectableImpl
                     dependency_history: #dependency_history_var
                 });
             }
+        }
+    }
 
-            #dependency_history_var.push::<Self>();
+    #[cfg(not(tarpaulin_include))]
+    fn expand_maybe_map_constructor_err(&self) -> proc_macro2::TokenStream
+    {
+        if !self.is_constructor_fallible {
+            return quote! {};
+        }
+
+        quote! {
+            .map_err(|err| InjectableError::ConstructorFailed {
+                source: err.into(),
+                affected: self_type_name,
+            })?
         }
     }
 
@@ -230,11 +360,14 @@ impl InjectableImpl
         maybe_doc_hidden: &proc_macro2::TokenStream,
         di_container_var: &Ident,
         dependency_history_var: &Ident,
-        maybe_prevent_circular_deps: &proc_macro2::TokenStream,
+        handle_dependency_history: &proc_macro2::TokenStream,
         get_dep_method_calls: &Vec<proc_macro2::TokenStream>,
     ) -> proc_macro2::TokenStream
     {
-        let generics = &self.original_impl.generics;
+        let di_container_type_param = format_ident!("{}", DI_CONTAINER_TYPE_PARAM_NAME);
+        let generics = self.create_generics_with_di_container(&quote! {
+            syrette::interfaces::async_dependency_resolver::AsyncDependencyResolver
+        });
         let self_type = &self.original_impl.self_ty;
         let constructor = &self.constructor_method.sig.ident;
 
@@ -248,15 +381,31 @@ impl InjectableImpl
             quote! {}
         };
 
+        let maybe_map_constructor_err = self.expand_maybe_map_constructor_err();
+
+        let dependency_bindings = self
+            .dependencies
+            .iter()
+            .filter(|dependency| !Self::is_container_ref(dependency))
+            .map(|dependency| {
+                let dep_interface = dependency.get_interface();
+                let binding_options = Self::create_binding_options(dependency);
+
+                quote! {
+                    syrette::interfaces::async_injectable::DependencyBinding::new::<
+                        #dep_interface
+                    >(#binding_options)
+                }
+            });
+
         quote! {
             #maybe_doc_hidden
             impl #generics syrette::interfaces::async_injectable::AsyncInjectable<
-                syrette::di_container::asynchronous::AsyncDIContainer,
+                #di_container_type_param
             > for #self_type
             {
                 fn resolve<'di_container, 'fut>(
-                    #di_container_var: &'di_container
-                        syrette::di_container::asynchronous::AsyncDIContainer,
+                    #di_container_var: &'di_container #di_container_type_param,
                     mut #dependency_history_var: syrette::dependency_history::DependencyHistory
                 ) -> syrette::future::BoxFuture<
                     'fut,
@@ -270,13 +419,13 @@ impl InjectableImpl
                     'di_container: 'fut
                 {
                     Box::pin(async move {
-                        use std::any::type_name;
+                        use ::core::any::type_name;
 
                         use syrette::errors::injectable::InjectableError;
 
                         let self_type_name = type_name::<#self_type>();
 
-                        #maybe_prevent_circular_deps
+                        #handle_dependency_history
 
                         // Dependencies can't be passed directly to the constructor
                         // because the Rust compiler becomes sad about SomePtr having
@@ -286,9 +435,18 @@ impl InjectableImpl
 
                         Ok(syrette::ptr::TransientPtr::new(Self::#constructor(
                             #(#dependency_idents),*
-                        )#maybe_await_constructor))
+                        )#maybe_await_constructor #maybe_map_constructor_err))
                     })
                 }
+
+                fn dependencies() -> Vec<
+                    syrette::interfaces::async_injectable::DependencyBinding
+                >
+                where
+                    Self: Sized
+                {
+                    vec![#(#dependency_bindings),*]
+                }
             }
         }
     }
@@ -299,43 +457,324 @@ impl InjectableImpl
         maybe_doc_hidden: &proc_macro2::TokenStream,
         di_container_var: &Ident,
         dependency_history_var: &Ident,
-        maybe_prevent_circular_deps: &proc_macro2::TokenStream,
+        handle_dependency_history: &proc_macro2::TokenStream,
         get_dep_method_calls: &Vec<proc_macro2::TokenStream>,
     ) -> proc_macro2::TokenStream
     {
-        let generics = &self.original_impl.generics;
+        let di_container_type_param = format_ident!("{}", DI_CONTAINER_TYPE_PARAM_NAME);
+        let generics = self.create_generics_with_di_container(&quote! {
+            syrette::interfaces::dependency_resolver::DependencyResolver
+        });
         let self_type = &self.original_impl.self_ty;
         let constructor = &self.constructor_method.sig.ident;
 
+        let maybe_map_constructor_err = self.expand_maybe_map_constructor_err();
+
         quote! {
             #maybe_doc_hidden
             impl #generics syrette::interfaces::injectable::Injectable<
-                ::syrette::di_container::blocking::DIContainer
+                #di_container_type_param
             > for #self_type
             {
                 fn resolve(
-                    #di_container_var: &syrette::di_container::blocking::DIContainer,
+                    #di_container_var: &#di_container_type_param,
                     mut #dependency_history_var: syrette::dependency_history::DependencyHistory
                 ) -> Result<
                     syrette::ptr::TransientPtr<Self>,
                     syrette::errors::injectable::InjectableError>
                 {
-                    use std::any::type_name;
+                    use ::core::any::type_name;
 
                     use syrette::errors::injectable::InjectableError;
 
                     let self_type_name = type_name::<#self_type>();
 
-                    #maybe_prevent_circular_deps
+                    #handle_dependency_history
 
                     return Ok(syrette::ptr::TransientPtr::new(Self::#constructor(
                         #(#get_dep_method_calls),*
-                    )));
+                    )#maybe_map_constructor_err));
                 }
             }
         }
     }
 
+    #[cfg(not(tarpaulin_include))]
+    fn expand_assisted_factory_impl(
+        &self,
+        factory: &AssistedFactory,
+        maybe_doc_hidden: &proc_macro2::TokenStream,
+        di_container_var: &Ident,
+        dependency_history_var: &Ident,
+        handle_dependency_history: &proc_macro2::TokenStream,
+        get_dep_method_calls: &Vec<proc_macro2::TokenStream>,
+    ) -> proc_macro2::TokenStream
+    {
+        let di_container_type_param = format_ident!("{}", DI_CONTAINER_TYPE_PARAM_NAME);
+        let generics = self.create_generics_with_di_container(&quote! {
+            syrette::interfaces::dependency_resolver::DependencyResolver
+        });
+        let self_type = &self.original_impl.self_ty;
+        let constructor = &self.constructor_method.sig.ident;
+
+        let AssistedFactory {
+            interface: factory_interface,
+            output_interface,
+        } = factory;
+
+        let dependency_idents = (0..get_dep_method_calls.len())
+            .map(|index| format_ident!("dependency_{index}"))
+            .collect::<Vec<_>>();
+
+        let assisted_arg_idents = (0..self.assisted_args.len())
+            .map(|index| format_ident!("assisted_arg_{index}"))
+            .collect::<Vec<_>>();
+
+        let assisted_arg_types = self.assisted_args.iter().map(|arg| &arg.ty);
+
+        let mut dependency_idents_iter = dependency_idents.iter();
+        let mut assisted_arg_idents_iter = assisted_arg_idents.iter();
+
+        let constructor_args = (0..dependency_idents.len() + assisted_arg_idents.len())
+            .map(|index| {
+                if self.assisted_args.iter().any(|arg| arg.index == index) {
+                    let assisted_arg_ident = assisted_arg_idents_iter.next();
+
+                    quote! { #assisted_arg_ident }
+                } else {
+                    let dependency_ident = dependency_idents_iter.next();
+
+                    quote! { #dependency_ident.clone() }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        quote! {
+            #maybe_doc_hidden
+            impl #generics syrette::interfaces::assisted_injectable::AssistedInjectable<
+                #di_container_type_param
+            > for #self_type
+            {
+                type Factory = #factory_interface;
+
+                fn resolve_factory(
+                    #di_container_var: &#di_container_type_param,
+                    mut #dependency_history_var: syrette::dependency_history::DependencyHistory
+                ) -> Result<
+                    syrette::ptr::TransientPtr<Self::Factory>,
+                    syrette::errors::injectable::InjectableError>
+                {
+                    use ::core::any::type_name;
+
+                    use syrette::errors::injectable::InjectableError;
+
+                    let self_type_name = type_name::<#self_type>();
+
+                    #handle_dependency_history
+
+                    #(let #dependency_idents = #get_dep_method_calls;)*
+
+                    let factory: syrette::ptr::TransientPtr<Self::Factory> =
+                        syrette::ptr::TransientPtr::new(
+                            move |#(#assisted_arg_idents: #assisted_arg_types),*|
+                                -> syrette::ptr::TransientPtr<#output_interface>
+                            {
+                                syrette::ptr::TransientPtr::new(Self::#constructor(
+                                    #(#constructor_args),*
+                                ))
+                            }
+                        );
+
+                    Ok(factory)
+                }
+            }
+        }
+    }
+
+    /// Expands a implementation of `StaticInjectable`, which requires the container to
+    /// provide every dependency as the pointer type of the dependency.
+    ///
+    /// Nothing is expanded if the injectable can't be constructed by a static container.
+    #[cfg(not(tarpaulin_include))]
+    fn expand_static_injectable_impl(
+        &self,
+        maybe_doc_hidden: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream
+    {
+        if !self.is_static_injectable() {
+            return quote! {};
+        }
+
+        let di_container_type_param = format_ident!("{}", DI_CONTAINER_TYPE_PARAM_NAME);
+        let self_type = &self.original_impl.self_ty;
+        let constructor = &self.constructor_method.sig.ident;
+
+        let mut generics = self.original_impl.generics.clone();
+
+        generics
+            .params
+            .push(parse_quote! { #di_container_type_param });
+
+        let where_clause = generics.make_where_clause();
+
+        for dependency in &self.dependencies {
+            let dep_interface = dependency.get_interface();
+            let dep_ptr = dependency.get_ptr();
+
+            where_clause.predicates.push(parse_quote! {
+                #di_container_type_param:
+                    syrette::interfaces::static_resolver::StaticResolver<
+                        #dep_interface,
+                        Ptr = syrette::ptr::#dep_ptr<#dep_interface>
+                    >
+            });
+
+            where_clause.predicates.push(parse_quote! {
+                <#di_container_type_param as
+                    syrette::interfaces::static_resolver::StaticResolver<#dep_interface>
+                >::Implementation:
+                    syrette::interfaces::static_injectable::StaticInjectable<
+                        #di_container_type_param
+                    >
+            });
+        }
+
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+        let resolve_dep_calls = self.dependencies.iter().map(|dependency| {
+            let dep_interface = dependency.get_interface();
+
+            quote! {
+                syrette::interfaces::static_resolver::StaticResolver::<
+                    #dep_interface
+                >::resolve(container)
+            }
+        });
+
+        quote! {
+            #maybe_doc_hidden
+            impl #impl_generics syrette::interfaces::static_injectable::StaticInjectable<
+                #di_container_type_param
+            > for #self_type #where_clause
+            {
+                fn construct(container: &#di_container_type_param) -> Self
+                {
+                    Self::#constructor(#(#resolve_dep_calls),*)
+                }
+            }
+        }
+    }
+
+    /// Expands a registration of the implementation under the given key for the given
+    /// interface. The interface key is the interface as written by the user.
+    #[cfg(not(tarpaulin_include))]
+    pub fn expand_registration(
+        &self,
+        key: &LitStr,
+        interface: &Type,
+        interface_key: &str,
+    ) -> proc_macro2::TokenStream
+    {
+        let self_type = &self.original_impl.self_ty;
+
+        quote! {
+            const _: () = {
+                #[syrette::private::linkme::distributed_slice(
+                    syrette::private::registry::REGISTERED_IMPLEMENTATIONS
+                )]
+                #[linkme(crate = syrette::private::linkme)]
+                static REGISTERED_IMPLEMENTATION:
+                    syrette::private::registry::RegisteredImplementation =
+                    syrette::private::registry::RegisteredImplementation {
+                        interface: ::core::any::TypeId::of::<#interface>,
+                        interface_key: #interface_key,
+                        key: #key,
+                        implementation: ::core::any::TypeId::of::<#self_type>,
+                        implementation_name: ::core::any::type_name::<#self_type>,
+                        bind: syrette::private::registry::bind_blocking::<
+                            #interface,
+                            #self_type
+                        >,
+                    };
+            };
+        }
+    }
+
+    /// Expands the registration of the instance of the generic implementation that has
+    /// `instance` as it's type parameter.
+    pub fn expand_generic_instance_registration(
+        &self,
+        type_param: &Ident,
+        instance: &Type,
+        interface: &Type,
+    ) -> proc_macro2::TokenStream
+    {
+        let self_type = &self.original_impl.self_ty;
+
+        let generic_param: Type = parse_quote! { syrette::di_container::GenericParam };
+
+        let template_interface =
+            replace_type_param(interface, type_param, &generic_param);
+        let template_self_type =
+            replace_type_param(self_type, type_param, &generic_param);
+
+        let instance_interface = replace_type_param(interface, type_param, instance);
+        let instance_self_type = replace_type_param(self_type, type_param, instance);
+
+        quote! {
+            const _: () = {
+                #[syrette::private::linkme::distributed_slice(
+                    syrette::private::registry::GENERIC_INSTANCES
+                )]
+                #[linkme(crate = syrette::private::linkme)]
+                static GENERIC_INSTANCE:
+                    syrette::private::registry::RegisteredGenericInstance =
+                    syrette::private::registry::RegisteredGenericInstance {
+                        template_interface: ::core::any::TypeId::of::<#template_interface>,
+                        template_implementation:
+                            ::core::any::TypeId::of::<#template_self_type>,
+                        interface_name: ::core::any::type_name::<#instance_interface>,
+                        is_bound: syrette::private::registry::is_bound::<
+                            #instance_interface
+                        >,
+                        bind: syrette::private::registry::bind_blocking::<
+                            #instance_interface,
+                            #instance_self_type
+                        >,
+                    };
+            };
+        }
+    }
+
+    /// Returns whether or not the injectable can be constructed by a static container.
+    fn is_static_injectable(&self) -> bool
+    {
+        !self.is_constructor_fallible
+            && self.assisted_args.is_empty()
+            && self.dependencies.iter().all(|dependency| {
+                dependency.get_name().is_none()
+                    && STATIC_DEPENDENCY_PTRS.contains(&dependency.get_ptr_kind())
+            })
+    }
+
+    /// Returns the generics of the original impl with a added type parameter for the
+    /// DI container that the dependencies are resolved from.
+    fn create_generics_with_di_container(
+        &self,
+        di_container_bound: &proc_macro2::TokenStream,
+    ) -> Generics
+    {
+        let di_container_type_param = format_ident!("{}", DI_CONTAINER_TYPE_PARAM_NAME);
+
+        let mut generics = self.original_impl.generics.clone();
+
+        generics
+            .params
+            .push(parse_quote! { #di_container_type_param: #di_container_bound });
+
+        generics
+    }
+
     fn create_get_dep_method_calls(
         dependencies: &[Dependency],
         is_async: bool,
@@ -382,6 +821,24 @@ impl InjectableImpl
         dependency_history_var: &Ident,
     ) -> proc_macro2::TokenStream
     {
+        let resolve_failed_error = if is_async {
+            quote! { InjectableError::AsyncResolveFailed }
+        } else {
+            quote! { InjectableError::ResolveFailed }
+        };
+
+        if Self::is_container_ref(dependency) {
+            return quote! {
+                #di_container_var
+                    .container_ref()
+                    .map_err(|err| #resolve_failed_error {
+                        reason: syrette::private::alloc::boxed::Box::new(err),
+                        affected: self_type_name,
+                        dependency_history: #dependency_history_var.clone()
+                    })?
+            };
+        }
+
         let dep_interface = dependency.get_interface();
 
         let method_call = ExprMethodCall::new(
@@ -400,10 +857,21 @@ impl InjectableImpl
             dep_interface.clone(),
         )]));
 
-        let ptr_name = dependency.get_ptr().to_string();
+        let dep_interface_str = dep_interface.to_token_stream().to_string();
 
-        let to_ptr =
-            format_ident!("{}", camelcase_to_snakecase(&ptr_name.replace("Ptr", "")));
+        let maybe_to_ptr = Self::get_ptr_conversion_method(dependency.get_ptr_kind())
+            .map_or_else(
+                || quote! {},
+                |to_ptr| {
+                    quote! {
+                        .#to_ptr()
+                        .map_err(|err| InjectableError::PrepareDependencyFailed {
+                            reason: err,
+                            dependency_name: #dep_interface_str
+                        })?
+                    }
+                },
+            );
 
         let do_method_call = if is_async {
             quote! { #method_call.await }
@@ -411,42 +879,151 @@ impl InjectableImpl
             quote! { #method_call }
         };
 
-        let resolve_failed_error = if is_async {
-            quote! { InjectableError::AsyncResolveFailed }
-        } else {
-            quote! { InjectableError::ResolveFailed }
-        };
-
-        let dep_interface_str = dep_interface.to_token_stream().to_string();
+        let binding_options = Self::create_binding_options(dependency);
 
         quote! {
-            #do_method_call
-                .map_err(|err| #resolve_failed_error {
-                    reason: Box::new(err),
-                    affected: self_type_name
-                })?
-                .#to_ptr()
-                .map_err(|err| InjectableError:: PrepareDependencyFailed {
-                    reason: err,
-                    dependency_name: #dep_interface_str
-                })?
+            {
+                let mut #dependency_history_var = #dependency_history_var.clone();
+
+                #dependency_history_var.push_interface::<#dep_interface>(#binding_options);
+
+                #do_method_call
+                    .map_err(|err| #resolve_failed_error {
+                        reason: syrette::private::alloc::boxed::Box::new(err),
+                        affected: self_type_name,
+                        dependency_history: #dependency_history_var
+                    })?
+                    #maybe_to_ptr
+            }
         }
     }
 
+    /// Returns whether or not the dependency is a reference to the DI container.
+    fn is_container_ref(dependency: &Dependency) -> bool
+    {
+        dependency.get_ptr_kind().is_container_ref()
+    }
+
+    /// Returns the method of `SomePtr` that converts into the given smart pointer type.
+    ///
+    /// Returns `None` for the smart pointer types that accept any scope and for DI
+    /// container references.
+    fn get_ptr_conversion_method(ptr_kind: PtrKind) -> Option<Ident>
+    {
+        let method_name = match ptr_kind {
+            PtrKind::Transient => "transient",
+            PtrKind::Singleton => "singleton",
+            PtrKind::ThreadsafeSingleton => "threadsafe_singleton",
+            PtrKind::Factory => "factory",
+            PtrKind::ThreadsafeFactory => "threadsafe_factory",
+            PtrKind::Some
+            | PtrKind::Injected
+            | PtrKind::ContainerRef
+            | PtrKind::AsyncContainerRef => return None,
+        };
+
+        Some(format_ident!("{}", method_name))
+    }
+
     fn build_dependencies(
         ctor_method: &ImplItemMethod,
     ) -> Result<Vec<Dependency>, DependencyError>
     {
         let ctor_method_args = &ctor_method.sig.inputs;
 
-        let dependencies_result: Result<Vec<_>, _> =
-            ctor_method_args.iter().map(Dependency::build).collect();
+        let dependencies_result: Result<Vec<_>, _> = ctor_method_args
+            .iter()
+            .filter(|arg| !Self::is_assisted_arg(arg))
+            .map(Dependency::build)
+            .collect();
 
         let deps = dependencies_result?;
 
         Ok(deps)
     }
 
+    fn is_fallible_constructor(constructor_method: &ImplItemMethod) -> bool
+    {
+        let ret_type = match &constructor_method.sig.output {
+            ReturnType::Type(_, ret_type) => ret_type,
+            ReturnType::Default => return false,
+        };
+
+        let last_segment = match ret_type.as_ref() {
+            Type::Path(path_type) => match path_type.path.segments.last() {
+                Some(last_segment) => last_segment,
+                None => return false,
+            },
+            _ => return false,
+        };
+
+        if last_segment.ident != "Result" {
+            return false;
+        }
+
+        let generic_args = match &last_segment.arguments {
+            PathArguments::AngleBracketed(generic_args) => generic_args,
+            _ => return false,
+        };
+
+        matches!(
+            generic_args.args.first(),
+            Some(GenericArgument::Type(ok_type)) if Self::is_self_type(ok_type)
+        )
+    }
+
+    fn is_self_type(ty: &Type) -> bool
+    {
+        if let Type::Path(path_type) = ty {
+            return path_type
+                .path
+                .get_ident()
+                .map_or_else(|| false, |ident| ident == "Self");
+        }
+
+        false
+    }
+
+    fn find_assisted_args(ctor_method: &ImplItemMethod) -> Vec<AssistedArg>
+    {
+        ctor_method
+            .sig
+            .inputs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, arg)| match arg {
+                FnArg::Typed(typed_arg) if Self::is_assisted_arg(arg) => {
+                    Some(AssistedArg {
+                        index,
+                        ty: typed_arg.ty.as_ref().clone(),
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn is_assisted_arg(arg: &FnArg) -> bool
+    {
+        if let FnArg::Typed(typed_arg) = arg {
+            typed_arg
+                .attrs
+                .iter()
+                .any(|attr| Self::is_arg_attr(attr, "assisted"))
+        } else {
+            false
+        }
+    }
+
+    fn is_arg_attr(attr: &Attribute, name: &str) -> bool
+    {
+        attr.path.to_string() == format!("syrette::{name}")
+            || attr
+                .path
+                .get_ident()
+                .map_or_else(|| false, |attr_ident| attr_ident == name)
+    }
+
     // Removes argument attributes from a method, as they are not actually valid Rust.
     // Not doing this would cause a compilation error.
     fn remove_method_argument_attrs(method: &mut ImplItemMethod)
@@ -460,28 +1037,30 @@ impl InjectableImpl
                 continue;
             };
 
-            let attrs_to_remove: Vec<_> = typed_arg
-                .attrs
-                .iter()
-                .enumerate()
-                .filter_map(|(index, attr)| {
-                    if &attr.path.to_string() == "syrette::named" {
-                        return Some(index);
-                    }
+            typed_arg.attrs.retain(|attr| {
+                !Self::is_arg_attr(attr, "named")
+                    && !Self::is_arg_attr(attr, "assisted")
+                    && !Self::is_arg_attr(attr, "ptr")
+            });
+        }
+    }
+}
 
-                    if attr.path.get_ident()?.to_string().as_str() == "named" {
-                        return Some(index);
-                    }
+/// A factory generated for a injectable with assisted constructor arguments.
+pub struct AssistedFactory
+{
+    /// The interface of the factory.
+    pub interface: Type,
 
-                    None
-                })
-                .collect();
+    /// The interface returned by the factory.
+    pub output_interface: Type,
+}
 
-            for attr_index in attrs_to_remove {
-                typed_arg.attrs.remove(attr_index);
-            }
-        }
-    }
+/// A constructor argument given by the caller of a factory.
+struct AssistedArg
+{
+    index: usize,
+    ty: Type,
 }
 
 diagnostic_error_enum! {
@@ -553,6 +1132,75 @@ pub enum InjectableImplError
         generics_span: Span
     },
 
+    #[
+        error("Assisted arguments require the 'factory' flag to be set"),
+        span = assisted_arg_span
+    ]
+    #[help("Set the 'factory' flag to the interface of the factory")]
+    AssistedArgWithoutFactoryFlag {
+        assisted_arg_span: Span
+    },
+
+    #[
+        error("Transient dependencies are not supported when the 'factory' flag is set"),
+        span = ptr_ident_span
+    ]
+    #[note(concat!(
+        "Dependencies are resolved once when the factory is retrieved and are then ",
+        "shared between all created instances"
+    ))]
+    #[help("Use a singleton or a factory dependency instead")]
+    TransientDependencyOfAssisted {
+        ptr_ident_span: Span
+    },
+
+    #[
+        error("Dependencies of any scope are not supported when the 'factory' flag is set"),
+        span = ptr_ident_span
+    ]
+    #[note(concat!(
+        "Dependencies are resolved once when the factory is retrieved and are then ",
+        "shared between all created instances, which transient dependencies cannot be"
+    ))]
+    #[help("Use a singleton or a factory dependency instead")]
+    ScopeAgnosticDependencyOfAssisted {
+        ptr_ident_span: Span
+    },
+
+    #[error("Unexpected DI container reference type"), span = ptr_ident_span]
+    #[help("Use '{}' instead", expected), span = ptr_ident_span]
+    WrongContainerRef {
+        ptr_ident_span: Span,
+        expected: &'static str
+    },
+
+    #[
+        error("Fallible constructors are not supported when the 'factory' flag is set"),
+        span = ctor_method_output_span
+    ]
+    #[note("The generated factory has no way of returning the error")]
+    FallibleConstructorOfAssisted {
+        ctor_method_output_span: Span
+    },
+
+    #[
+        error("Generic implementations cannot be registered under a key"),
+        span = generics_span
+    ]
+    #[note("Required by the 'key' flag of the 'injectable' attribute macro")]
+    RegisteredGeneric {
+        generics_span: Span
+    },
+
+    #[
+        error("Implementation must have exactly one type parameter"),
+        span = generics_span
+    ]
+    #[note("Required by the 'instances' flag of the 'injectable' attribute macro")]
+    InstancesWithoutSingleTypeParam {
+        generics_span: Span
+    },
+
     #[error("Has a invalid dependency"), span = implementation_span]
     #[source(err)]
     ContainsAInvalidDependency {
@@ -735,6 +1383,133 @@ mod tests
         assert_eq!(dependencies.len(), 2);
     }
 
+    #[test]
+    fn can_build_dependencies_without_assisted_args()
+    {
+        let method = parse2::<ImplItemMethod>(quote! {
+            fn new(
+                #[assisted] name: String,
+                foo: SingletonPtr<dyn Foo>,
+                #[syrette::assisted] age: u8,
+            ) -> Self
+            {
+                Self { name, foo, age }
+            }
+        })
+        .unwrap();
+
+        let _lock = get_lock(&TEST_MUTEX);
+
+        let build_context = MockDependency::build_context();
+
+        build_context
+            .expect()
+            .returning(|_| Ok(MockDependency::new()))
+            .times(1);
+
+        let dependencies =
+            InjectableImpl::build_dependencies(&method).expect("Expected Ok");
+
+        assert_eq!(dependencies.len(), 1);
+    }
+
+    #[test]
+    fn can_find_assisted_args()
+    {
+        let method = parse2::<ImplItemMethod>(quote! {
+            fn new(
+                #[assisted] name: String,
+                foo: SingletonPtr<dyn Foo>,
+                #[syrette::assisted] age: u8,
+            ) -> Self
+            {
+                Self { name, foo, age }
+            }
+        })
+        .unwrap();
+
+        let assisted_args = InjectableImpl::find_assisted_args(&method);
+
+        assert_eq!(
+            assisted_args
+                .iter()
+                .map(|assisted_arg| (
+                    assisted_arg.index,
+                    assisted_arg.ty.to_token_stream().to_string()
+                ))
+                .collect::<Vec<_>>(),
+            vec![(0, "String".to_string()), (2, "u8".to_string())]
+        );
+    }
+
+    #[test]
+    fn can_detect_fallible_constructor()
+    {
+        let fallible_method = parse2::<ImplItemMethod>(quote! {
+            fn new(foo: SingletonPtr<dyn Foo>) -> Result<Self, std::io::Error>
+            {
+                Ok(Self { foo })
+            }
+        })
+        .unwrap();
+
+        let aliased_fallible_method = parse2::<ImplItemMethod>(quote! {
+            fn new(foo: SingletonPtr<dyn Foo>) -> std::io::Result<Self>
+            {
+                Ok(Self { foo })
+            }
+        })
+        .unwrap();
+
+        let infallible_method = parse2::<ImplItemMethod>(quote! {
+            fn new(foo: SingletonPtr<dyn Foo>) -> Self
+            {
+                Self { foo }
+            }
+        })
+        .unwrap();
+
+        let other_result_method = parse2::<ImplItemMethod>(quote! {
+            fn new(foo: SingletonPtr<dyn Foo>) -> Result<Foo, std::io::Error>
+            {
+                Ok(Foo { foo })
+            }
+        })
+        .unwrap();
+
+        assert!(InjectableImpl::is_fallible_constructor(&fallible_method));
+        assert!(InjectableImpl::is_fallible_constructor(
+            &aliased_fallible_method
+        ));
+        assert!(!InjectableImpl::is_fallible_constructor(&infallible_method));
+        assert!(!InjectableImpl::is_fallible_constructor(
+            &other_result_method
+        ));
+    }
+
+    #[test]
+    fn can_remove_assisted_method_argument_attrs()
+    {
+        let mut method = parse2::<ImplItemMethod>(quote! {
+            fn new(
+                #[assisted] name: String,
+                #[named("cool")] foo: SingletonPtr<dyn Foo>,
+                #[syrette::assisted] age: u8,
+            ) -> Self
+            {
+                Self { name, foo, age }
+            }
+        })
+        .unwrap();
+
+        InjectableImpl::remove_method_argument_attrs(&mut method);
+
+        assert!(method.sig.inputs.iter().all(|arg| match arg {
+            FnArg::Typed(typed_arg) => typed_arg.attrs.is_empty(),
+            FnArg::Receiver(_) => false,
+        }));
+    }
+
     #[test]
     fn can_remove_method_argument_attrs()
     {
@@ -845,8 +1620,8 @@ mod tests
         mock_dependency.expect_get_name().return_const(None);
 
         mock_dependency
-            .expect_get_ptr()
-            .return_const(format_ident!("TransientPtr"));
+            .expect_get_ptr_kind()
+            .return_const(PtrKind::Transient);
 
         let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
         let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
@@ -861,19 +1636,116 @@ mod tests
         assert_eq!(
             parse2::<Expr>(output).unwrap(),
             parse2::<Expr>(quote! {
-                #di_container_var_ident
-                    .get_bound::<Foo>(
-                        #dep_history_var_ident.clone(),
+                {
+                    let mut #dep_history_var_ident = #dep_history_var_ident.clone();
+
+                    #dep_history_var_ident.push_interface::<Foo>(
                         syrette::di_container::BindingOptions::new()
-                    )
+                    );
+
+                    #di_container_var_ident
+                        .get_bound::<Foo>(
+                            #dep_history_var_ident.clone(),
+                            syrette::di_container::BindingOptions::new()
+                        )
+                        .map_err(|err| InjectableError::ResolveFailed {
+                            reason: syrette::private::alloc::boxed::Box::new(err),
+                            affected: self_type_name,
+                            dependency_history: #dep_history_var_ident
+                        })?
+                        .transient()
+                        .map_err(|err| InjectableError::PrepareDependencyFailed {
+                            reason: err,
+                            dependency_name: "Foo"
+                        })?
+                }
+            })
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn can_create_single_get_dep_method_call_with_scope_agnostic_ptr()
+    {
+        let mut mock_dependency = MockDependency::new();
+
+        mock_dependency
+            .expect_get_interface()
+            .return_const(create_type(create_path(&[create_path_segment(
+                format_ident!("Foo"),
+                &[],
+            )])));
+
+        mock_dependency.expect_get_name().return_const(None);
+
+        mock_dependency
+            .expect_get_ptr_kind()
+            .return_const(PtrKind::Injected);
+
+        let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
+        let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
+
+        let output = InjectableImpl::create_single_get_dep_method_call(
+            &mock_dependency,
+            false,
+            &format_ident!("{}", DI_CONTAINER_VAR_NAME),
+            &format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME),
+        );
+
+        assert_eq!(
+            parse2::<Expr>(output).unwrap(),
+            parse2::<Expr>(quote! {
+                {
+                    let mut #dep_history_var_ident = #dep_history_var_ident.clone();
+
+                    #dep_history_var_ident.push_interface::<Foo>(
+                        syrette::di_container::BindingOptions::new()
+                    );
+
+                    #di_container_var_ident
+                        .get_bound::<Foo>(
+                            #dep_history_var_ident.clone(),
+                            syrette::di_container::BindingOptions::new()
+                        )
+                        .map_err(|err| InjectableError::ResolveFailed {
+                            reason: syrette::private::alloc::boxed::Box::new(err),
+                            affected: self_type_name,
+                            dependency_history: #dep_history_var_ident
+                        })?
+                }
+            })
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn can_create_single_get_dep_method_call_with_container_ref()
+    {
+        let mut mock_dependency = MockDependency::new();
+
+        mock_dependency
+            .expect_get_ptr_kind()
+            .return_const(PtrKind::ContainerRef);
+
+        let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
+        let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
+
+        let output = InjectableImpl::create_single_get_dep_method_call(
+            &mock_dependency,
+            false,
+            &format_ident!("{}", DI_CONTAINER_VAR_NAME),
+            &format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME),
+        );
+
+        assert_eq!(
+            parse2::<Expr>(output).unwrap(),
+            parse2::<Expr>(quote! {
+                #di_container_var_ident
+                    .container_ref()
                     .map_err(|err| InjectableError::ResolveFailed {
-                        reason: Box::new(err),
-                        affected: self_type_name
-                    })?
-                    .transient()
-                    .map_err(|err| InjectableError::PrepareDependencyFailed {
-                        reason: err,
-                        dependency_name: "Foo"
+                        reason: syrette::private::alloc::boxed::Box::new(err),
+                        affected: self_type_name,
+                        dependency_history: #dep_history_var_ident.clone()
                     })?
             })
             .unwrap()
@@ -897,8 +1769,8 @@ mod tests
             .return_const(Some(LitStr::new("special", Span::call_site())));
 
         mock_dependency
-            .expect_get_ptr()
-            .return_const(format_ident!("TransientPtr"));
+            .expect_get_ptr_kind()
+            .return_const(PtrKind::Transient);
 
         let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
         let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
@@ -913,20 +1785,29 @@ mod tests
         assert_eq!(
             parse2::<Expr>(output).unwrap(),
             parse2::<Expr>(quote! {
-                #di_container_var_ident
-                    .get_bound::<Foo>(
-                        #dep_history_var_ident.clone(),
+                {
+                    let mut #dep_history_var_ident = #dep_history_var_ident.clone();
+
+                    #dep_history_var_ident.push_interface::<Foo>(
                         syrette::di_container::BindingOptions::new().name("special")
-                    )
-                    .map_err(|err| InjectableError::ResolveFailed {
-                        reason: Box::new(err),
-                        affected: self_type_name
-                    })?
-                    .transient()
-                    .map_err(|err| InjectableError::PrepareDependencyFailed {
-                        reason: err,
-                        dependency_name: "Foo"
-                    })?
+                    );
+
+                    #di_container_var_ident
+                        .get_bound::<Foo>(
+                            #dep_history_var_ident.clone(),
+                            syrette::di_container::BindingOptions::new().name("special")
+                        )
+                        .map_err(|err| InjectableError::ResolveFailed {
+                            reason: syrette::private::alloc::boxed::Box::new(err),
+                            affected: self_type_name,
+                            dependency_history: #dep_history_var_ident
+                        })?
+                        .transient()
+                        .map_err(|err| InjectableError::PrepareDependencyFailed {
+                            reason: err,
+                            dependency_name: "Foo"
+                        })?
+                }
             })
             .unwrap()
         );
@@ -947,8 +1828,8 @@ mod tests
         mock_dependency.expect_get_name().return_const(None);
 
         mock_dependency
-            .expect_get_ptr()
-            .return_const(format_ident!("TransientPtr"));
+            .expect_get_ptr_kind()
+            .return_const(PtrKind::Transient);
 
         let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
         let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
@@ -963,21 +1844,30 @@ mod tests
         assert_eq!(
             parse2::<Expr>(output).unwrap(),
             parse2::<Expr>(quote! {
-                #di_container_var_ident
-                    .get_bound::<Foo>(
-                        #dep_history_var_ident.clone(),
+                {
+                    let mut #dep_history_var_ident = #dep_history_var_ident.clone();
+
+                    #dep_history_var_ident.push_interface::<Foo>(
                         syrette::di_container::BindingOptions::new()
-                    )
-                    .await
-                    .map_err(|err| InjectableError::AsyncResolveFailed {
-                        reason: Box::new(err),
-                        affected: self_type_name
-                    })?
-                    .transient()
-                    .map_err(|err| InjectableError::PrepareDependencyFailed {
-                        reason: err,
-                        dependency_name: "Foo"
-                    })?
+                    );
+
+                    #di_container_var_ident
+                        .get_bound::<Foo>(
+                            #dep_history_var_ident.clone(),
+                            syrette::di_container::BindingOptions::new()
+                        )
+                        .await
+                        .map_err(|err| InjectableError::AsyncResolveFailed {
+                            reason: syrette::private::alloc::boxed::Box::new(err),
+                            affected: self_type_name,
+                            dependency_history: #dep_history_var_ident
+                        })?
+                        .transient()
+                        .map_err(|err| InjectableError::PrepareDependencyFailed {
+                            reason: err,
+                            dependency_name: "Foo"
+                        })?
+                }
             })
             .unwrap()
         );
@@ -1000,8 +1890,8 @@ mod tests
             .return_const(Some(LitStr::new("foobar", Span::call_site())));
 
         mock_dependency
-            .expect_get_ptr()
-            .return_const(format_ident!("TransientPtr"));
+            .expect_get_ptr_kind()
+            .return_const(PtrKind::Transient);
 
         let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
         let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
@@ -1016,21 +1906,30 @@ mod tests
         assert_eq!(
             parse2::<Expr>(output).unwrap(),
             parse2::<Expr>(quote! {
-                #di_container_var_ident
-                    .get_bound::<Foo>(
-                        #dep_history_var_ident.clone(),
+                {
+                    let mut #dep_history_var_ident = #dep_history_var_ident.clone();
+
+                    #dep_history_var_ident.push_interface::<Foo>(
                         syrette::di_container::BindingOptions::new().name("foobar")
-                    )
-                    .await
-                    .map_err(|err| InjectableError::AsyncResolveFailed {
-                        reason: Box::new(err),
-                        affected: self_type_name
-                    })?
-                    .transient()
-                    .map_err(|err| InjectableError::PrepareDependencyFailed {
-                        reason: err,
-                        dependency_name: "Foo"
-                    })?
+                    );
+
+                    #di_container_var_ident
+                        .get_bound::<Foo>(
+                            #dep_history_var_ident.clone(),
+                            syrette::di_container::BindingOptions::new().name("foobar")
+                        )
+                        .await
+                        .map_err(|err| InjectableError::AsyncResolveFailed {
+                            reason: syrette::private::alloc::boxed::Box::new(err),
+                            affected: self_type_name,
+                            dependency_history: #dep_history_var_ident
+                        })?
+                        .transient()
+                        .map_err(|err| InjectableError::PrepareDependencyFailed {
+                            reason: err,
+                            dependency_name: "Foo"
+                        })?
+                }
             })
             .unwrap()
         );
@@ -12,6 +12,9 @@ pub const INJECTABLE_MACRO_FLAGS: &[&str] = &[
     "async",
     "no_declare_concrete_interface",
     "constructor",
+    "factory",
+    "key",
+    "instances",
 ];
 
 pub struct InjectableMacroArgs
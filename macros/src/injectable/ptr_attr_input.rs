@@ -0,0 +1,102 @@
+use proc_macro2::Ident;
+use quote::ToTokens;
+use syn::parse::Parse;
+use syn::token::Paren;
+use syn::{parenthesized, Token, Type};
+
+mod kw
+{
+    syn::custom_keyword!(interface);
+}
+
+/// Input of the `ptr` argument attribute. Either `= kind` or `(kind)`, where the
+/// parenthesized form can also be given the interface of the pointer type.
+pub struct PtrAttrInput
+{
+    pub opt_eq_token: Option<Token![=]>,
+    pub opt_paren: Option<Paren>,
+    pub kind: Ident,
+    pub opt_interface: Option<PtrAttrInterface>,
+}
+
+impl Parse for PtrAttrInput
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self>
+    {
+        if input.peek(Token![=]) {
+            return Ok(Self {
+                opt_eq_token: Some(input.parse()?),
+                opt_paren: None,
+                kind: input.parse()?,
+                opt_interface: None,
+            });
+        }
+
+        let content;
+
+        let paren = parenthesized!(content in input);
+
+        Ok(Self {
+            opt_eq_token: None,
+            opt_paren: Some(paren),
+            kind: content.parse()?,
+            opt_interface: if content.is_empty() {
+                None
+            } else {
+                Some(content.parse()?)
+            },
+        })
+    }
+}
+
+impl ToTokens for PtrAttrInput
+{
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream)
+    {
+        if let Some(paren) = &self.opt_paren {
+            paren.surround(tokens, |tokens| {
+                self.kind.to_tokens(tokens);
+                self.opt_interface.to_tokens(tokens);
+            });
+
+            return;
+        }
+
+        self.opt_eq_token.to_tokens(tokens);
+        self.kind.to_tokens(tokens);
+    }
+}
+
+/// The interface of a pointer type alias, for when the alias doesn't take generic
+/// parameters.
+pub struct PtrAttrInterface
+{
+    pub comma: Token![,],
+    pub interface_kw: kw::interface,
+    pub eq_token: Token![=],
+    pub interface: Type,
+}
+
+impl Parse for PtrAttrInterface
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self>
+    {
+        Ok(Self {
+            comma: input.parse()?,
+            interface_kw: input.parse()?,
+            eq_token: input.parse()?,
+            interface: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for PtrAttrInterface
+{
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream)
+    {
+        self.comma.to_tokens(tokens);
+        self.interface_kw.to_tokens(tokens);
+        self.eq_token.to_tokens(tokens);
+        self.interface.to_tokens(tokens);
+    }
+}
@@ -8,27 +8,37 @@
 
 use proc_macro::TokenStream;
 use proc_macro_error::{proc_macro_error, set_dummy, ResultExt};
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
 use syn::punctuated::Punctuated;
 use syn::token::Dyn;
 use syn::{
     parse,
     ItemImpl,
+    Path,
     TraitBound,
     TraitBoundModifier,
     Type,
     TypeParamBound,
+    TypePath,
     TypeTraitObject,
 };
 
 use crate::caster::generate_caster;
+use crate::container::implementation::ContainerImpl;
+use crate::container::input::ContainerInput;
 use crate::declare_interface_args::DeclareInterfaceArgs;
 use crate::injectable::dummy::expand_dummy_blocking_impl;
-use crate::injectable::implementation::{InjectableImpl, InjectableImplError};
+use crate::injectable::implementation::{
+    AssistedFactory,
+    InjectableImpl,
+    InjectableImplError,
+};
 use crate::injectable::macro_args::InjectableMacroArgs;
 use crate::macro_flag::MacroFlag;
+use crate::util::type_param::replace_type_param;
 
 mod caster;
+mod container;
 mod declare_interface_args;
 mod injectable;
 mod macro_flag;
@@ -47,6 +57,10 @@ const PACKAGE_VERSION: &str = env!("CARGO_PKG_VERSION");
 ///
 /// Generates an implementation of [`Injectable`].
 ///
+/// The implementation is generic over the DI container, which can be any type
+/// implementing [`DependencyResolver`] ([`AsyncDependencyResolver`] if the `async` flag
+/// is set).
+///
 /// # Arguments
 /// * (Optional) A interface trait the struct implements.
 /// * (Zero or more) Comma separated flags. Each flag being formatted `name=value`.
@@ -78,6 +92,59 @@ const PACKAGE_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// **Default:** `new`<br>
 /// Constructor method name.
 ///
+/// #### `factory`
+/// <span class="cf">Available on <strong>crate feature <code>factory</code></strong>
+/// only.</span>
+///
+/// **Value:** identifier<br>
+/// Generate an implementation of [`AssistedInjectable`] with the given factory
+/// interface instead of [`Injectable`]. Constructor arguments marked with the
+/// [assisted](#assisted) attribute become parameters of the factory and the rest are
+/// resolved from the DI container.
+///
+/// The factory interface must return the interface trait argument, or the concrete type
+/// if no interface trait argument is given. Dependencies are resolved once when the
+/// factory is retrieved, so they cannot be transient.
+///
+/// Bind the factory with `to_assisted_factory`.
+///
+/// #### `key`
+/// **Value:** string literal<br>
+/// Register the implementation under the given key for the interface trait argument,
+/// or the concrete type if no interface trait argument is given. A binding to the
+/// implementation can then be created with `to_registered` using the key, for example
+/// a key read from a configuration file.
+///
+/// Cannot be used together with the `async` or `factory` flags, or with generic
+/// implementations.
+///
+/// #### `instances`
+/// **Value:** list of types<br>
+/// Register the given instances of a generic implementation with exactly one type
+/// parameter. Bindings to all of the instances can then be created at once with
+/// `bind_generic` by writing the type parameter as `GenericParam`.
+///
+/// The interface is declared for each of the instances instead of for the generic
+/// implementation. The implementation struct must not have bounds on the type
+/// parameter, put them on the impl blocks instead.
+///
+/// Cannot be used together with the `async`, `factory` or `key` flags.
+///
+/// # Dependencies
+/// Constructor arguments are resolved from the DI container. They must be given as one
+/// of the smart pointer types in `syrette::ptr`. `TransientPtr`, `SingletonPtr`,
+/// `ThreadsafeSingletonPtr`, `FactoryPtr` and `ThreadsafeFactoryPtr` require the
+/// binding to have the matching scope. `SomePtr` and `InjectedPtr` accept a binding
+/// in any scope.
+///
+/// `Box`, `Rc` and `Arc` are treated as `TransientPtr`, `SingletonPtr` and
+/// `ThreadsafeSingletonPtr` respectively. The pointer type of any other type alias must
+/// be declared with the [`ptr`](#ptr) attribute.
+///
+/// A `ContainerRef` argument is given a reference to the resolving DI container, or a
+/// `AsyncContainerRef` if the `async` flag is set. Resolving it fails unless the DI
+/// container has been moved into a `Rc` or `Arc` with `into_shared`.
+///
 /// # Important
 /// When no interface trait argument is given, you have three options
 /// - Manually declare the interface with the [`declare_interface!`] macro.
@@ -123,6 +190,29 @@ const PACKAGE_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// }
 /// # }
 /// ```
+/// <br>
+///
+/// The constructor can also return a `Result` with `Self` as the success type. A
+/// returned error is passed on as the source of a `InjectableError::ConstructorFailed`
+/// when the type is resolved.
+/// ```
+/// # use std::fs::read_to_string;
+/// #
+/// # use syrette::injectable;
+/// #
+/// # struct Config { content: String }
+/// #
+/// #[injectable]
+/// impl Config
+/// {
+///     pub fn new() -> Result<Self, std::io::Error>
+///     {
+///         let content = read_to_string("config.toml")?;
+///
+///         Ok(Self { content })
+///     }
+/// }
+/// ```
 ///
 /// # Attributes
 /// Attributes specific to impls with this attribute macro.
@@ -167,10 +257,122 @@ const PACKAGE_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// # impl IKnight for Knight {}
 /// ```
 ///
+/// ### Assisted
+/// <span class="cf">Available on <strong>crate feature <code>factory</code></strong>
+/// only.</span>
+///
+/// Used inside the of constructor method before a argument that should be given by the
+/// caller of the factory. Requires the `factory` flag to be set.
+///
+/// The `assisted` ghost attribute macro can be used for intellisense and autocompletion
+/// for this attribute.
+///
+/// For example:
+/// ```
+/// # #[cfg(feature = "factory")]
+/// # mod example {
+/// # use syrette::ptr::{SingletonPtr, TransientPtr};
+/// # use syrette::injectable;
+/// #
+/// # trait IArmor {}
+/// #
+/// # trait IKnight {}
+/// #
+/// # struct Knight
+/// # {
+/// #     name: String,
+/// #     armor: SingletonPtr<dyn IArmor>,
+/// # }
+/// #
+/// type IKnightFactory = dyn Fn(String) -> TransientPtr<dyn IKnight>;
+///
+/// #[injectable(IKnight, factory = IKnightFactory)]
+/// impl Knight
+/// {
+///     pub fn new(#[assisted] name: String, armor: SingletonPtr<dyn IArmor>) -> Self
+///     {
+///         Self { name, armor }
+///     }
+/// }
+/// #
+/// # impl IKnight for Knight {}
+/// # }
+/// ```
+///
+/// ### Ptr
+/// Used inside the of constructor method before a dependency argument. Declares the
+/// pointer type of a dependency given as a type alias. Should be given one of
+/// `transient`, `singleton`, `threadsafe_singleton`, `factory`, `threadsafe_factory` or
+/// `some` after a equals sign.
+///
+/// The interface is taken from the first generic parameter of the type alias. A type
+/// alias that doesn't take generic parameters is instead given it's interface in
+/// parentheses together with the pointer type, like
+/// `#[ptr(singleton, interface = dyn IArmor)]`.
+///
+/// For example:
+/// ```
+/// # use syrette::ptr::SingletonPtr;
+/// # use syrette::injectable;
+/// #
+/// # trait IArmor {}
+/// #
+/// # trait IKnight {}
+/// #
+/// # struct Knight
+/// # {
+/// #     armor: Shared<dyn IArmor>,
+/// # }
+/// #
+/// type Shared<T> = SingletonPtr<T>;
+///
+/// #[injectable(IKnight)]
+/// impl Knight
+/// {
+///     pub fn new(#[ptr = singleton] armor: Shared<dyn IArmor>) -> Self
+///     {
+///         Self { armor }
+///     }
+/// }
+/// #
+/// # impl IKnight for Knight {}
+/// ```
+///
+/// Or with a type alias that doesn't take generic parameters:
+/// ```
+/// # use syrette::ptr::SingletonPtr;
+/// # use syrette::injectable;
+/// #
+/// # trait IArmor {}
+/// #
+/// # trait IKnight {}
+/// #
+/// # struct Knight
+/// # {
+/// #     armor: SharedArmor,
+/// # }
+/// #
+/// type SharedArmor = SingletonPtr<dyn IArmor>;
+///
+/// #[injectable(IKnight)]
+/// impl Knight
+/// {
+///     pub fn new(#[ptr(singleton, interface = dyn IArmor)] armor: SharedArmor) -> Self
+///     {
+///         Self { armor }
+///     }
+/// }
+/// #
+/// # impl IKnight for Knight {}
+/// ```
+///
 /// [`DIContainer`]: ../syrette/di_container/blocking/struct.DIContainer.html
 /// [`AsyncDIContainer`]: ../syrette/di_container/asynchronous/struct.AsyncDIContainer.html
 /// [`Injectable`]: ../syrette/interfaces/injectable/trait.Injectable.html
 /// [`AsyncInjectable`]: ../syrette/interfaces/async_injectable/trait.AsyncInjectable.html
+/// [`AssistedInjectable`]: ../syrette/interfaces/assisted_injectable/trait.AssistedInjectable.html
+/// [`DependencyResolver`]: ../syrette/interfaces/dependency_resolver/trait.DependencyResolver.html
+/// [`AsyncDependencyResolver`]: ../syrette/interfaces/async_dependency_resolver/trait.AsyncDependencyResolver.html
 /// [`di_container_bind`]: ../syrette/macro.di_container_bind.html
 /// [`async`]: https://doc.rust-lang.org/std/keyword.async.html
 #[cfg(not(tarpaulin_include))]
@@ -254,14 +456,166 @@ pub fn injectable(args_stream: TokenStream, input_stream: TokenStream) -> TokenS
         );
     }
 
+    let opt_factory_flag = args.flags.iter().find(|flag| flag.name() == "factory");
+
+    let opt_factory_interface = opt_factory_flag
+        .map(MacroFlag::get_ident)
+        .transpose()
+        .unwrap_or_abort();
+
+    #[cfg(not(feature = "factory"))]
+    if let Some(factory_flag) = opt_factory_flag {
+        use proc_macro_error::abort;
+
+        abort!(
+            factory_flag.name().span(),
+            "The 'factory' crate feature must be enabled to use this flag";
+            suggestion = concat!(
+                "In your Cargo.toml: syrette = {{ version = \"{}\", features = ",
+                "[\"factory\"] }}"
+            ),
+            PACKAGE_VERSION
+        );
+    }
+
+    if let (true, Some(factory_flag)) = (is_async, opt_factory_flag) {
+        use proc_macro_error::abort;
+
+        abort!(
+            factory_flag.name().span(),
+            "The 'factory' flag cannot be used together with the 'async' flag"
+        );
+    }
+
+    let opt_key_flag = args.flags.iter().find(|flag| flag.name() == "key");
+
+    let opt_key = opt_key_flag
+        .map(MacroFlag::get_str)
+        .transpose()
+        .unwrap_or_abort();
+
+    if let Some(key_flag) = opt_key_flag {
+        use proc_macro_error::abort;
+
+        if is_async {
+            abort!(
+                key_flag.name().span(),
+                "The 'key' flag cannot be used together with the 'async' flag"
+            );
+        }
+
+        if opt_factory_flag.is_some() {
+            abort!(
+                key_flag.name().span(),
+                "The 'key' flag cannot be used together with the 'factory' flag"
+            );
+        }
+    }
+
+    let opt_instances_flag = args.flags.iter().find(|flag| flag.name() == "instances");
+
+    let opt_instances = opt_instances_flag
+        .map(MacroFlag::get_types)
+        .transpose()
+        .unwrap_or_abort();
+
+    if let Some(instances_flag) = opt_instances_flag {
+        use proc_macro_error::abort;
+
+        if is_async || opt_factory_flag.is_some() || opt_key_flag.is_some() {
+            abort!(
+                instances_flag.name().span(),
+                concat!(
+                    "The 'instances' flag cannot be used together with the 'async', ",
+                    "'factory' or 'key' flags"
+                )
+            );
+        }
+    }
+
     let injectable_impl = InjectableImpl::new(item_impl, &constructor).unwrap_or_abort();
 
-    injectable_impl.validate(is_async).unwrap_or_abort();
+    injectable_impl
+        .validate(is_async, opt_factory_interface.is_some(), opt_key.is_some())
+        .unwrap_or_abort();
 
-    let expanded_injectable_impl = injectable_impl.expand(no_doc_hidden, is_async);
+    let opt_generic_type_param = opt_instances
+        .as_ref()
+        .map(|_| injectable_impl.validate_generic_instances())
+        .transpose()
+        .unwrap_or_abort();
+
+    let opt_assisted_factory = opt_factory_interface.map(|factory_interface| {
+        let output_interface = args.interface.as_ref().map_or_else(
+            || injectable_impl.self_type().clone(),
+            |interface| Type::TraitObject(create_trait_object(interface.path.clone())),
+        );
+
+        AssistedFactory {
+            interface: Type::Path(TypePath {
+                qself: None,
+                path: factory_interface.into(),
+            }),
+            output_interface,
+        }
+    });
+
+    let expanded_injectable_impl =
+        injectable_impl.expand(no_doc_hidden, is_async, opt_assisted_factory.as_ref());
+
+    let maybe_registration = opt_key.map_or_else(
+        || quote! {},
+        |key| {
+            let (registered_interface, interface_key) =
+                args.interface.as_ref().map_or_else(
+                    || {
+                        let self_type = injectable_impl.self_type();
+
+                        (self_type.clone(), self_type.to_token_stream().to_string())
+                    },
+                    |interface| {
+                        (
+                            Type::TraitObject(create_trait_object(
+                                interface.path.clone(),
+                            )),
+                            interface.to_token_stream().to_string(),
+                        )
+                    },
+                );
+
+            injectable_impl.expand_registration(
+                &key,
+                &registered_interface,
+                &interface_key.replace(' ', ""),
+            )
+        },
+    );
 
     let self_type = injectable_impl.self_type();
 
+    let maybe_instance_registrations = match (&opt_instances, opt_generic_type_param) {
+        (Some(instances), Some(type_param)) => {
+            let registered_interface = args.interface.as_ref().map_or_else(
+                || self_type.clone(),
+                |interface| {
+                    Type::TraitObject(create_trait_object(interface.path.clone()))
+                },
+            );
+
+            instances
+                .iter()
+                .map(|instance| {
+                    injectable_impl.expand_generic_instance_registration(
+                        type_param,
+                        instance,
+                        &registered_interface,
+                    )
+                })
+                .collect()
+        }
+        _ => Vec::new(),
+    };
+
     let opt_interface = args.interface.map(Type::Path).or_else(|| {
         if no_declare_concrete_interface {
             None
@@ -277,9 +631,16 @@ pub fn injectable(args_stream: TokenStream, input_stream: TokenStream) -> TokenS
             quote! {}
         };
 
+        let maybe_for_clause = match (&opt_instances, opt_generic_type_param) {
+            (Some(instances), Some(type_param)) => quote! {
+                ; for #type_param in [#(#instances),*]
+            },
+            _ => quote! {},
+        };
+
         quote! {
             syrette::declare_interface!(
-                #self_type -> #interface #threadsafe_sharable_flag
+                #self_type -> #interface #maybe_for_clause #threadsafe_sharable_flag
             );
         }
     } else {
@@ -290,14 +651,24 @@ pub fn injectable(args_stream: TokenStream, input_stream: TokenStream) -> TokenS
         #expanded_injectable_impl
 
         #maybe_decl_interface
+
+        #maybe_registration
+
+        #(#maybe_instance_registrations)*
     }
     .into()
 }
 
-/// Declares the interface trait of a implementation.
+/// Declares the interface traits of a implementation.
 ///
 /// # Arguments
-/// {Implementation} -> {Interface}
+/// {Implementation} -> {Interface}, {Interface}... where {Predicates}; for {Type param}
+/// in [{Type}, {Type}...]
+/// * One or more comma separated interfaces.
+/// * (Optional) A where clause. Only allowed together with a `for` clause.
+/// * (Optional) A `for` clause. The interfaces are declared for the implementation with
+///   the type parameter replaced by each of the given types. The where clause is checked
+///   for each of the types.
 /// * (Zero or more) Flags. Like `a = true, b = false`
 ///
 /// # Flags
@@ -316,6 +687,32 @@ pub fn injectable(args_stream: TokenStream, input_stream: TokenStream) -> TokenS
 /// #
 /// declare_interface!(Ninja -> INinja);
 /// ```
+///
+/// ```
+/// # use std::fmt::Display;
+/// # use std::marker::PhantomData;
+/// #
+/// # use syrette::declare_interface;
+/// #
+/// # trait IPrinter<Printable> {}
+/// #
+/// # trait IDevice {}
+/// #
+/// # struct Printer<Printable>
+/// # {
+/// #     _printable: PhantomData<Printable>,
+/// # }
+/// #
+/// # impl<Printable: Display> IPrinter<Printable> for Printer<Printable> {}
+/// #
+/// # impl<Printable> IDevice for Printer<Printable> {}
+/// #
+/// declare_interface!(
+///     Printer<Printable> -> IPrinter<Printable>, IDevice
+///     where Printable: Display;
+///     for Printable in [String, i32]
+/// );
+/// ```
 #[cfg(not(tarpaulin_include))]
 #[proc_macro_error]
 #[proc_macro]
@@ -323,7 +720,9 @@ pub fn declare_interface(input: TokenStream) -> TokenStream
 {
     let DeclareInterfaceArgs {
         implementation,
-        interface,
+        interfaces,
+        opt_where_clause,
+        opt_instances,
         flags,
     } = parse(input).unwrap_or_abort();
 
@@ -338,21 +737,204 @@ pub fn declare_interface(input: TokenStream) -> TokenStream
     #[cfg(syrette_macros_logging)]
     init_logging();
 
-    let interface_type = if interface == implementation {
-        Type::Path(interface)
-    } else {
-        Type::TraitObject(TypeTraitObject {
-            dyn_token: Some(Dyn::default()),
-            bounds: Punctuated::from_iter(vec![TypeParamBound::Trait(TraitBound {
-                paren_token: None,
-                modifier: TraitBoundModifier::None,
-                lifetimes: None,
-                path: interface.path,
-            })]),
+    let implementation = Type::Path(implementation);
+    let interfaces = interfaces.into_iter().map(Type::Path).collect::<Vec<_>>();
+
+    let declared_types: Vec<(Type, Vec<Type>)> = opt_instances.as_ref().map_or_else(
+        || vec![(implementation.clone(), interfaces.clone())],
+        |instances| {
+            instances
+                .types
+                .iter()
+                .map(|instance| {
+                    (
+                        replace_type_param(
+                            &implementation,
+                            &instances.type_param,
+                            instance,
+                        ),
+                        interfaces
+                            .iter()
+                            .map(|interface| {
+                                replace_type_param(
+                                    interface,
+                                    &instances.type_param,
+                                    instance,
+                                )
+                            })
+                            .collect(),
+                    )
+                })
+                .collect()
+        },
+    );
+
+    let casters = declared_types
+        .iter()
+        .flat_map(|(implementation, interfaces)| {
+            interfaces.iter().map(move |interface| {
+                let interface_type = match interface {
+                    Type::Path(interface_path) if interface != implementation => {
+                        Type::TraitObject(create_trait_object(
+                            interface_path.path.clone(),
+                        ))
+                    }
+                    _ => interface.clone(),
+                };
+
+                generate_caster(implementation, &interface_type, is_async)
+            })
         })
+        .collect::<Vec<_>>();
+
+    let maybe_bounds_assertion = match (opt_where_clause, opt_instances) {
+        (Some(where_clause), Some(instances)) => {
+            let type_param = instances.type_param;
+            let types = instances.types.iter();
+
+            quote! {
+                const _: () = {
+                    fn assert_bounds<#type_param>() #where_clause {}
+
+                    #(let _ = assert_bounds::<#types>;)*
+                };
+            }
+        }
+        _ => quote! {},
     };
 
-    generate_caster(&implementation, &interface_type, is_async).into()
+    quote! {
+        #maybe_bounds_assertion
+
+        #(#casters)*
+    }
+    .into()
+}
+
+/// Creates a static container where the bindings are verified at compile time.
+///
+/// Generates a struct with a `get` method for every bound interface. The dependencies
+/// of the bound implementations are resolved using the [`StaticInjectable`]
+/// implementations generated by [`macro@injectable`], so a missing binding or a
+/// dependency cycle is a compile error instead of a error at runtime.
+///
+/// A implementation can be bound if it's constructor is infallible and it has no named
+/// dependencies. Only the `TransientPtr` and `SingletonPtr` pointer types are supported.
+///
+/// # Arguments
+/// * A struct declaration. Like `pub struct AppContainer;`
+/// * (Zero or more) Bindings. Like `{Interface} => {Implementation} in {scope}`
+///
+/// # Scopes
+/// - `transient` - A new instance is created every time the interface is resolved. Is the
+///   default if no scope is given.
+/// - `singleton` - A single instance is created the first time the interface is resolved.
+///
+/// # Examples
+/// ```
+/// # use syrette::ptr::{SingletonPtr, TransientPtr};
+/// # use syrette::{container, injectable};
+/// #
+/// trait IConfig
+/// {
+///     fn port(&self) -> u16;
+/// }
+///
+/// struct Config {}
+///
+/// #[injectable]
+/// impl Config
+/// {
+///     fn new() -> Self
+///     {
+///         Self {}
+///     }
+/// }
+///
+/// impl IConfig for Config
+/// {
+///     fn port(&self) -> u16
+///     {
+///         8080
+///     }
+/// }
+///
+/// struct Server
+/// {
+///     config: SingletonPtr<dyn IConfig>,
+/// }
+///
+/// #[injectable]
+/// impl Server
+/// {
+///     fn new(config: SingletonPtr<dyn IConfig>) -> Self
+///     {
+///         Self { config }
+///     }
+/// }
+///
+/// container! {
+///     struct AppContainer;
+///
+///     dyn IConfig => Config in singleton,
+///     Server => Server in transient,
+/// }
+///
+/// let app_container = AppContainer::new();
+///
+/// let server: TransientPtr<Server> = app_container.get::<Server>();
+///
+/// assert_eq!(server.config.port(), 8080);
+/// ```
+///
+/// Leaving out a binding of a dependency fails to compile.
+/// ```compile_fail
+/// # use syrette::ptr::SingletonPtr;
+/// # use syrette::{container, injectable};
+/// #
+/// # struct Config {}
+/// #
+/// # #[injectable]
+/// # impl Config
+/// # {
+/// #     fn new() -> Self
+/// #     {
+/// #         Self {}
+/// #     }
+/// # }
+/// #
+/// # struct Server
+/// # {
+/// #     config: SingletonPtr<Config>,
+/// # }
+/// #
+/// # #[injectable]
+/// # impl Server
+/// # {
+/// #     fn new(config: SingletonPtr<Config>) -> Self
+/// #     {
+/// #         Self { config }
+/// #     }
+/// # }
+/// #
+/// container! {
+///     struct AppContainer;
+///
+///     Server => Server in transient,
+/// }
+/// ```
+///
+/// [`StaticInjectable`]: ../syrette/interfaces/static_injectable/trait.StaticInjectable.html
+#[cfg(not(tarpaulin_include))]
+#[proc_macro_error]
+#[proc_macro]
+pub fn container(input: TokenStream) -> TokenStream
+{
+    let container_input = parse::<ContainerInput>(input).unwrap_or_abort();
+
+    let container_impl = ContainerImpl::new(container_input).unwrap_or_abort();
+
+    container_impl.expand().into()
 }
 
 /// Used to declare the name of a dependency in the constructor of a impl block decorated
@@ -399,6 +981,64 @@ pub fn named(_: TokenStream, _: TokenStream) -> TokenStream
     TokenStream::new()
 }
 
+/// Used to declare a constructor argument as given by the caller of the factory of a
+/// impl block decorated with [`macro@injectable`] with the `factory` flag set.
+///
+/// **This macro attribute doesn't actually do anything**. It only exists for the
+/// convenience of having intellisense, autocompletion and documentation.
+///
+/// # Examples
+/// ```
+/// # use syrette::ptr::{SingletonPtr, TransientPtr};
+/// # use syrette::injectable;
+/// #
+/// # trait INinja {}
+/// # trait IWeapon {}
+/// #
+/// # struct Ninja
+/// # {
+/// #   name: String,
+/// #   weapon: SingletonPtr<dyn IWeapon>,
+/// # }
+/// #
+/// type INinjaFactory = dyn Fn(String) -> TransientPtr<dyn INinja>;
+///
+/// #[injectable(INinja, factory = INinjaFactory)]
+/// impl Ninja
+/// {
+///     pub fn new(
+///         #[syrette::assisted] name: String,
+///         weapon: SingletonPtr<dyn IWeapon>,
+///     ) -> Self
+///     {
+///         Self { name, weapon }
+///     }
+/// }
+/// #
+/// # impl INinja for Ninja {}
+/// ```
+#[cfg(feature = "factory")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
+#[cfg(not(tarpaulin_include))]
+#[proc_macro_attribute]
+pub fn assisted(_: TokenStream, _: TokenStream) -> TokenStream
+{
+    TokenStream::new()
+}
+
+fn create_trait_object(trait_path: Path) -> TypeTraitObject
+{
+    TypeTraitObject {
+        dyn_token: Some(Dyn::default()),
+        bounds: Punctuated::from_iter(vec![TypeParamBound::Trait(TraitBound {
+            paren_token: None,
+            modifier: TraitBoundModifier::None,
+            lifetimes: None,
+            path: trait_path,
+        })]),
+    }
+}
+
 #[cfg(syrette_macros_logging)]
 fn init_logging()
 {
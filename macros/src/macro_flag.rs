@@ -2,7 +2,9 @@ use std::hash::Hash;
 
 use proc_macro2::Span;
 use syn::parse::{Parse, ParseStream};
-use syn::{Ident, Lit, LitBool, Token};
+use syn::punctuated::Punctuated;
+use syn::token::Bracket;
+use syn::{bracketed, Ident, Lit, LitBool, LitStr, Token, Type};
 
 use crate::util::error::diagnostic_error_enum;
 
@@ -54,6 +56,30 @@ impl MacroFlag
             value_span: self.value.span(),
         })
     }
+
+    pub fn get_types(&self) -> Result<Vec<Type>, MacroFlagError>
+    {
+        if let MacroFlagValue::TypeList(_, types) = &self.value {
+            return Ok(types.iter().cloned().collect());
+        }
+
+        Err(MacroFlagError::UnexpectedValueKind {
+            expected: "list of types",
+            value_span: self.value.span(),
+        })
+    }
+
+    pub fn get_str(&self) -> Result<LitStr, MacroFlagError>
+    {
+        if let MacroFlagValue::Literal(Lit::Str(lit_str)) = &self.value {
+            return Ok(lit_str.clone());
+        }
+
+        Err(MacroFlagError::UnexpectedValueKind {
+            expected: "string literal",
+            value_span: self.value.span(),
+        })
+    }
 }
 
 impl Parse for MacroFlag
@@ -103,6 +129,7 @@ pub enum MacroFlagValue
 {
     Literal(Lit),
     Identifier(Ident),
+    TypeList(Bracket, Punctuated<Type, Token![,]>),
 }
 
 impl MacroFlagValue
@@ -112,6 +139,7 @@ impl MacroFlagValue
         match self {
             Self::Literal(lit) => lit.span(),
             Self::Identifier(ident) => ident.span(),
+            Self::TypeList(bracket, _) => bracket.span,
         }
     }
 }
@@ -120,9 +148,20 @@ impl Parse for MacroFlagValue
 {
     fn parse(input: ParseStream) -> syn::Result<Self>
     {
+        if input.peek(Bracket) {
+            let content;
+
+            let bracket = bracketed!(content in input);
+
+            return Ok(Self::TypeList(
+                bracket,
+                Punctuated::parse_terminated(&content)?,
+            ));
+        }
+
         if let Ok(lit) = input.parse::<Lit>() {
             return Ok(Self::Literal(lit));
-        };
+        }
 
         input.parse::<Ident>().map(Self::Identifier).map_err(|err| {
             syn::Error::new(err.span(), "Expected a literal or a identifier")
@@ -246,6 +285,55 @@ mod tests
         );
     }
 
+    #[test]
+    fn parse_macro_flag_type_list_works()
+    {
+        let macro_flag = parse2::<MacroFlag>(quote! {
+            instances = [String, Vec<u8>]
+        })
+        .expect("Expected Ok");
+
+        assert_eq!(macro_flag.name, format_ident!("instances"));
+
+        let types = macro_flag.get_types().expect("Expected Ok");
+
+        assert_eq!(types.len(), 2);
+        assert_eq!(
+            types[1],
+            parse2::<Type>(quote! { Vec<u8> }).expect("Expected Ok")
+        );
+
+        assert!(macro_flag.get_bool().is_err());
+    }
+
+    #[test]
+    fn get_str_works()
+    {
+        assert_eq!(
+            MacroFlag {
+                name: format_ident!("guitarist"),
+                value: MacroFlagValue::Literal(Lit::Str(LitStr::new(
+                    "John Norum",
+                    Span::call_site()
+                )))
+            }
+            .get_str()
+            .expect("Expected Ok")
+            .value(),
+            "John Norum"
+        );
+
+        assert!(
+            // Formatting is weird without this comment
+            MacroFlag {
+                name: format_ident!("members"),
+                value: MacroFlagValue::Identifier(format_ident!("five"))
+            }
+            .get_str()
+            .is_err()
+        );
+    }
+
     #[test]
     fn parse_with_invalid_name_fails()
     {
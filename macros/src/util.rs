@@ -1,9 +1,11 @@
 pub mod error;
 pub mod item_impl;
 pub mod iterator_ext;
-pub mod string;
 pub mod syn_ext;
 pub mod syn_path;
+pub mod type_param;
+
+#[cfg(syrette_macros_logging)]
 pub mod tokens;
 
 macro_rules! to_option {
@@ -30,7 +30,6 @@ macro_rules! diagnostic_error_enum {
 
         impl From<$name> for ::proc_macro_error::Diagnostic
         {
-            #[must_use]
             fn from(err: $name) -> Self
             {
                 use $crate::util::error::DiagnosticErrorVariantInfo;
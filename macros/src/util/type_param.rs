@@ -0,0 +1,65 @@
+use proc_macro2::Ident;
+use syn::visit_mut::{self, VisitMut};
+use syn::Type;
+
+/// Returns the given type with all occurrences of the type parameter `param` replaced
+/// with `replacement`.
+pub fn replace_type_param(ty: &Type, param: &Ident, replacement: &Type) -> Type
+{
+    let mut ty = ty.clone();
+
+    TypeParamReplacer { param, replacement }.visit_type_mut(&mut ty);
+
+    ty
+}
+
+struct TypeParamReplacer<'a>
+{
+    param: &'a Ident,
+    replacement: &'a Type,
+}
+
+impl VisitMut for TypeParamReplacer<'_>
+{
+    fn visit_type_mut(&mut self, ty: &mut Type)
+    {
+        if let Type::Path(type_path) = ty {
+            if type_path.qself.is_none() && type_path.path.is_ident(self.param) {
+                *ty = self.replacement.clone();
+
+                return;
+            }
+        }
+
+        visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use pretty_assertions::assert_eq;
+    use quote::{format_ident, quote};
+    use syn::parse2;
+
+    use super::*;
+
+    #[test]
+    fn replace_type_param_works()
+    {
+        let ty = parse2::<Type>(quote! {
+            Printer<T, Vec<T>, Option<TT>>
+        })
+        .expect("Expected Ok");
+
+        let replacement = parse2::<Type>(quote! { String }).expect("Expected Ok");
+
+        assert_eq!(
+            replace_type_param(&ty, &format_ident!("T"), &replacement),
+            parse2::<Type>(quote! {
+                Printer<String, Vec<String>, Option<TT>>
+            })
+            .expect("Expected Ok")
+        );
+    }
+}
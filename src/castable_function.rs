@@ -1,8 +1,10 @@
-use std::any::{type_name, Any};
-use std::fmt::Debug;
+use core::any::{type_name, Any};
+use core::fmt::Debug;
 
 use crate::ptr::TransientPtr;
 
+pub mod fallible;
+
 #[cfg(feature = "async")]
 pub mod threadsafe;
 
@@ -55,7 +57,7 @@ where
     ReturnInterface: 'static + ?Sized,
 {
     #[cfg(not(tarpaulin_include))]
-    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
     {
         let ret = type_name::<TransientPtr<ReturnInterface>>();
 
@@ -0,0 +1,119 @@
+use alloc::boxed::Box;
+use core::any::{type_name, Any};
+use core::fmt::Debug;
+
+use crate::castable_function::AnyCastableFunction;
+use crate::ptr::TransientPtr;
+use crate::util::Error;
+
+pub type BoxedFunctionError = Box<dyn Error + Send + Sync>;
+
+type FallibleFunc<ReturnInterface, DIContainerT> =
+    dyn Fn(&DIContainerT) -> Result<TransientPtr<ReturnInterface>, BoxedFunctionError>;
+
+pub struct FallibleCastableFunction<ReturnInterface, DIContainerT>
+where
+    ReturnInterface: 'static + ?Sized,
+    DIContainerT: 'static,
+{
+    func: Box<FallibleFunc<ReturnInterface, DIContainerT>>,
+}
+
+impl<ReturnInterface, DIContainerT>
+    FallibleCastableFunction<ReturnInterface, DIContainerT>
+where
+    ReturnInterface: 'static + ?Sized,
+{
+    pub fn new<Func, FuncError>(func: &'static Func) -> Self
+    where
+        Func: Fn(&DIContainerT) -> Result<TransientPtr<ReturnInterface>, FuncError>,
+        FuncError: Error + Send + Sync + 'static,
+    {
+        Self {
+            func: Box::new(|di_container| {
+                func(di_container).map_err(|err| Box::new(err) as BoxedFunctionError)
+            }),
+        }
+    }
+
+    pub fn call(
+        &self,
+        di_container: &DIContainerT,
+    ) -> Result<TransientPtr<ReturnInterface>, BoxedFunctionError>
+    {
+        (self.func)(di_container)
+    }
+}
+
+impl<ReturnInterface, DIContainerT> AnyCastableFunction
+    for FallibleCastableFunction<ReturnInterface, DIContainerT>
+where
+    ReturnInterface: 'static + ?Sized,
+    DIContainerT: 'static,
+{
+    fn as_any(&self) -> &dyn Any
+    {
+        self
+    }
+}
+
+impl<ReturnInterface, DIContainerT> Debug
+    for FallibleCastableFunction<ReturnInterface, DIContainerT>
+where
+    ReturnInterface: 'static + ?Sized,
+{
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        let ret = type_name::<TransientPtr<ReturnInterface>>();
+
+        formatter.write_fmt(format_args!(
+            "FallibleCastableFunction (&DIContainer) -> Result<{ret}, _> {{ ... }}"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::di_container::blocking::MockDIContainer;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Bacon
+    {
+        heal_amount: u32,
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("The bacon is burnt")]
+    struct BurntBaconError;
+
+    #[test]
+    fn can_call()
+    {
+        let castable_func = FallibleCastableFunction::new(&|_: &MockDIContainer| {
+            Ok::<_, BurntBaconError>(TransientPtr::new(Bacon { heal_amount: 27 }))
+        });
+
+        let mock_di_container = MockDIContainer::new();
+
+        let output = castable_func.call(&mock_di_container).unwrap();
+
+        assert_eq!(output, TransientPtr::new(Bacon { heal_amount: 27 }));
+    }
+
+    #[test]
+    fn can_call_failing()
+    {
+        let castable_func = FallibleCastableFunction::new(&|_: &MockDIContainer| {
+            Err::<TransientPtr<Bacon>, _>(BurntBaconError)
+        });
+
+        let mock_di_container = MockDIContainer::new();
+
+        let err = castable_func.call(&mock_di_container).unwrap_err();
+
+        assert!(err.is::<BurntBaconError>());
+    }
+}
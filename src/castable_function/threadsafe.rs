@@ -4,6 +4,8 @@ use std::fmt::Debug;
 use crate::castable_function::AnyCastableFunction;
 use crate::ptr::TransientPtr;
 
+pub mod fallible;
+
 /// Interface for any threadsafe castable function.
 pub trait AnyThreadsafeCastableFunction:
     AnyCastableFunction + Send + Sync + Debug
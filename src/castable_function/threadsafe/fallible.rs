@@ -0,0 +1,137 @@
+use std::any::{type_name, Any};
+use std::error::Error;
+use std::fmt::Debug;
+
+use crate::castable_function::fallible::BoxedFunctionError;
+use crate::castable_function::threadsafe::AnyThreadsafeCastableFunction;
+use crate::castable_function::AnyCastableFunction;
+use crate::ptr::TransientPtr;
+
+type ThreadsafeFallibleFunc<ReturnInterface, DIContainerT> = dyn Fn(&DIContainerT) -> Result<TransientPtr<ReturnInterface>, BoxedFunctionError>
+    + Send
+    + Sync;
+
+pub struct ThreadsafeFallibleCastableFunction<ReturnInterface, DIContainerT>
+where
+    DIContainerT: 'static,
+    ReturnInterface: 'static + ?Sized,
+{
+    func: Box<ThreadsafeFallibleFunc<ReturnInterface, DIContainerT>>,
+}
+
+impl<ReturnInterface, DIContainerT>
+    ThreadsafeFallibleCastableFunction<ReturnInterface, DIContainerT>
+where
+    DIContainerT: 'static,
+    ReturnInterface: 'static + ?Sized,
+{
+    pub fn new<Func, FuncError>(func: &'static Func) -> Self
+    where
+        Func: Fn(&DIContainerT) -> Result<TransientPtr<ReturnInterface>, FuncError>
+            + Send
+            + Sync,
+        FuncError: Error + Send + Sync + 'static,
+    {
+        Self {
+            func: Box::new(|di_container| {
+                func(di_container).map_err(|err| Box::new(err) as BoxedFunctionError)
+            }),
+        }
+    }
+
+    pub fn call(
+        &self,
+        di_container: &DIContainerT,
+    ) -> Result<TransientPtr<ReturnInterface>, BoxedFunctionError>
+    {
+        (self.func)(di_container)
+    }
+}
+
+impl<ReturnInterface, DIContainerT> AnyCastableFunction
+    for ThreadsafeFallibleCastableFunction<ReturnInterface, DIContainerT>
+where
+    DIContainerT: 'static,
+    ReturnInterface: 'static + ?Sized,
+{
+    fn as_any(&self) -> &dyn Any
+    {
+        self
+    }
+}
+
+impl<ReturnInterface, DIContainerT> AnyThreadsafeCastableFunction
+    for ThreadsafeFallibleCastableFunction<ReturnInterface, DIContainerT>
+where
+    DIContainerT: 'static,
+    ReturnInterface: 'static + ?Sized,
+{
+}
+
+impl<ReturnInterface, DIContainerT> Debug
+    for ThreadsafeFallibleCastableFunction<ReturnInterface, DIContainerT>
+where
+    DIContainerT: 'static,
+    ReturnInterface: 'static + ?Sized,
+{
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let ret = type_name::<TransientPtr<ReturnInterface>>();
+
+        formatter.write_fmt(format_args!(
+            concat!(
+                "ThreadsafeFallibleCastableFunction(&AsyncDIContainer) -> ",
+                "Result<{}, _> {{ ... }}"
+            ),
+            ret
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::di_container::asynchronous::MockAsyncDIContainer;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Bacon
+    {
+        heal_amount: u32,
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("The bacon is burnt")]
+    struct BurntBaconError;
+
+    #[test]
+    fn can_call()
+    {
+        let castable_function =
+            ThreadsafeFallibleCastableFunction::new(&|_: &MockAsyncDIContainer| {
+                Ok::<_, BurntBaconError>(TransientPtr::new(Bacon { heal_amount: 27 }))
+            });
+
+        let mock_di_container = MockAsyncDIContainer::new();
+
+        let output = castable_function.call(&mock_di_container).unwrap();
+
+        assert_eq!(output, TransientPtr::new(Bacon { heal_amount: 27 }));
+    }
+
+    #[test]
+    fn can_call_failing()
+    {
+        let castable_function =
+            ThreadsafeFallibleCastableFunction::new(&|_: &MockAsyncDIContainer| {
+                Err::<TransientPtr<Bacon>, _>(BurntBaconError)
+            });
+
+        let mock_di_container = MockAsyncDIContainer::new();
+
+        let err = castable_function.call(&mock_di_container).unwrap_err();
+
+        assert!(err.is::<BurntBaconError>());
+    }
+}
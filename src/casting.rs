@@ -0,0 +1,336 @@
+//! Querying & casting between the interfaces declared for concrete types.
+//!
+//! Interfaces are declared for concrete types with the [`declare_interface!`] and
+//! [`injectable`] macros. To cast a [`SomePtr`] to another interface, the interface trait
+//! of the pointer must extend [`CastFrom`]. Casting threadsafe pointers additionally
+//! requires it to extend [`CastFromArc`].
+//!
+//! # Examples
+//! ```
+//! use std::error::Error;
+//!
+//! use syrette::casting::{self, CastFrom};
+//! use syrette::declare_interface;
+//! use syrette::ptr::SomePtr;
+//!
+//! trait IReader: CastFrom {}
+//!
+//! trait IWriter: CastFrom
+//! {
+//!     fn write(&self, data: &str) -> usize;
+//! }
+//!
+//! struct File {}
+//!
+//! impl IReader for File {}
+//!
+//! impl IWriter for File
+//! {
+//!     fn write(&self, data: &str) -> usize
+//!     {
+//!         data.len()
+//!     }
+//! }
+//!
+//! declare_interface!(File -> IReader, IWriter);
+//!
+//! fn main() -> Result<(), Box<dyn Error>>
+//! {
+//!     let reader: SomePtr<dyn IReader> = SomePtr::Transient(Box::new(File {}));
+//!
+//!     assert!(casting::can_cast::<dyn IWriter, _>(&reader));
+//!
+//!     let writer = casting::cast::<dyn IWriter, _>(reader)?.transient()?;
+//!
+//!     assert_eq!(writer.write("hello"), 5);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`declare_interface!`]: crate::declare_interface
+//! [`injectable`]: crate::injectable
+use alloc::vec::Vec;
+use core::any::{type_name, Any, TypeId};
+
+use crate::errors::casting::CastingError;
+use crate::private::cast::arc::CastArc;
+use crate::private::cast::boxed::CastBox;
+use crate::private::cast::error::CastError;
+use crate::private::cast::rc::CastRc;
+use crate::private::cast::{get_caster, get_casters_from};
+pub use crate::private::cast::{CastFrom, CastFromArc};
+use crate::ptr::SomePtr;
+
+/// A interface declared for a concrete type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeclaredInterface
+{
+    type_id: TypeId,
+    name: &'static str,
+    is_threadsafe_sharable: bool,
+}
+
+impl DeclaredInterface
+{
+    /// Returns the `TypeId` of the interface.
+    #[must_use]
+    pub fn type_id(&self) -> TypeId
+    {
+        self.type_id
+    }
+
+    /// Returns the type name of the interface.
+    #[must_use]
+    pub fn name(&self) -> &'static str
+    {
+        self.name
+    }
+
+    /// Returns whether or not the interface is declared as threadsafe sharable.
+    #[must_use]
+    pub fn is_threadsafe_sharable(&self) -> bool
+    {
+        self.is_threadsafe_sharable
+    }
+}
+
+/// Returns the interfaces declared for the concrete type `Concrete`, sorted by name.
+///
+/// # Examples
+/// ```
+/// # use syrette::casting::declared_interfaces;
+/// # use syrette::declare_interface;
+/// #
+/// trait IShape {}
+///
+/// struct Circle {}
+///
+/// impl IShape for Circle {}
+///
+/// declare_interface!(Circle -> IShape);
+///
+/// assert!(declared_interfaces::<Circle>()
+///     .iter()
+///     .any(|interface| interface.name().ends_with("IShape")));
+/// ```
+#[must_use]
+pub fn declared_interfaces<Concrete>() -> Vec<DeclaredInterface>
+where
+    Concrete: 'static,
+{
+    let mut declared_interfaces: Vec<_> = get_casters_from(TypeId::of::<Concrete>())
+        .map(|caster| DeclaredInterface {
+            type_id: caster.dest_type_id(),
+            name: caster.dest_type_name(),
+            is_threadsafe_sharable: caster.is_arc_castable(),
+        })
+        .collect();
+
+    declared_interfaces
+        .sort_unstable_by_key(|declared_interface| declared_interface.name);
+
+    declared_interfaces
+}
+
+/// Returns whether or not the given pointer can be cast to `Dest`.
+///
+/// Threadsafe pointers can only be cast to interfaces declared as threadsafe sharable.
+#[must_use]
+pub fn can_cast<Dest, Source>(ptr: &SomePtr<Source>) -> bool
+where
+    Dest: 'static + ?Sized,
+    Source: 'static + ?Sized + CastFrom,
+{
+    let (source, is_threadsafe): (&Source, bool) = match ptr {
+        SomePtr::Transient(transient_ptr) => (transient_ptr, false),
+        SomePtr::Singleton(singleton_ptr) => (singleton_ptr, false),
+        #[cfg(feature = "factory")]
+        SomePtr::Factory(factory_ptr) => (factory_ptr, false),
+        SomePtr::ThreadsafeSingleton(threadsafe_singleton_ptr) => {
+            (threadsafe_singleton_ptr, true)
+        }
+        #[cfg(feature = "factory")]
+        SomePtr::ThreadsafeFactory(threadsafe_factory_ptr) => {
+            (threadsafe_factory_ptr, true)
+        }
+    };
+
+    get_caster::<Dest>(Any::type_id(source)).map_or(false, |caster| {
+        !is_threadsafe || caster.opt_cast_arc.is_some()
+    })
+}
+
+/// Casts the given pointer to a pointer of `Dest`, keeping the kind of the pointer.
+///
+/// The pointer is dropped if the cast fails. Use [`can_cast`] to check beforehand.
+///
+/// # Errors
+/// Will return Err if:
+/// - `Dest` is not declared for the concrete type behind the pointer.
+/// - The pointer is threadsafe. Use [`cast_threadsafe`] for threadsafe pointers.
+pub fn cast<Dest, Source>(ptr: SomePtr<Source>) -> Result<SomePtr<Dest>, CastingError>
+where
+    Dest: 'static + ?Sized,
+    Source: 'static + ?Sized + CastFrom,
+{
+    match ptr {
+        SomePtr::Transient(transient_ptr) => transient_ptr
+            .cast::<Dest>()
+            .map(SomePtr::Transient)
+            .map_err(|err| create_casting_error::<Dest, Source>(&err)),
+        SomePtr::Singleton(singleton_ptr) => singleton_ptr
+            .cast::<Dest>()
+            .map(SomePtr::Singleton)
+            .map_err(|err| create_casting_error::<Dest, Source>(&err)),
+        #[cfg(feature = "factory")]
+        SomePtr::Factory(factory_ptr) => factory_ptr
+            .cast::<Dest>()
+            .map(SomePtr::Factory)
+            .map_err(|err| create_casting_error::<Dest, Source>(&err)),
+        SomePtr::ThreadsafeSingleton(_) => {
+            Err(CastingError::ThreadsafePtr(type_name::<Source>()))
+        }
+        #[cfg(feature = "factory")]
+        SomePtr::ThreadsafeFactory(_) => {
+            Err(CastingError::ThreadsafePtr(type_name::<Source>()))
+        }
+    }
+}
+
+/// Casts the given pointer to a pointer of `Dest`, keeping the kind of the pointer.
+///
+/// Unlike [`cast`], threadsafe pointers can be cast as well.
+///
+/// The pointer is dropped if the cast fails. Use [`can_cast`] to check beforehand.
+///
+/// # Errors
+/// Will return Err if:
+/// - `Dest` is not declared for the concrete type behind the pointer.
+/// - The pointer is threadsafe and `Dest` is not declared as threadsafe sharable.
+pub fn cast_threadsafe<Dest, Source>(
+    ptr: SomePtr<Source>,
+) -> Result<SomePtr<Dest>, CastingError>
+where
+    Dest: 'static + ?Sized,
+    Source: 'static + ?Sized + CastFromArc,
+{
+    match ptr {
+        SomePtr::ThreadsafeSingleton(threadsafe_singleton_ptr) => {
+            CastArc::cast::<Dest>(threadsafe_singleton_ptr)
+                .map(SomePtr::ThreadsafeSingleton)
+                .map_err(|err| create_casting_error::<Dest, Source>(&err))
+        }
+        #[cfg(feature = "factory")]
+        SomePtr::ThreadsafeFactory(threadsafe_factory_ptr) => {
+            CastArc::cast::<Dest>(threadsafe_factory_ptr)
+                .map(SomePtr::ThreadsafeFactory)
+                .map_err(|err| create_casting_error::<Dest, Source>(&err))
+        }
+        ptr => cast(ptr),
+    }
+}
+
+fn create_casting_error<Dest, Source>(err: &CastError) -> CastingError
+where
+    Dest: 'static + ?Sized,
+    Source: 'static + ?Sized,
+{
+    match err {
+        CastError::NotArcCastable(_) => CastingError::NotThreadsafeSharable {
+            from: type_name::<Source>(),
+            to: type_name::<Dest>(),
+        },
+        CastError::GetCasterFailed(_) | CastError::CastFailed { .. } => {
+            CastingError::NotDeclared {
+                from: type_name::<Source>(),
+                to: type_name::<Dest>(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use alloc::sync::Arc;
+    use core::fmt::{Debug, Display};
+
+    use super::*;
+    use crate::test_utils::subjects;
+
+    #[test]
+    fn declared_interfaces_works()
+    {
+        let ninja_interfaces = declared_interfaces::<subjects::Ninja>();
+
+        assert!(ninja_interfaces.iter().any(|declared_interface| {
+            declared_interface.type_id() == TypeId::of::<dyn Debug>()
+                && declared_interface.is_threadsafe_sharable()
+        }));
+
+        assert!(declared_interfaces::<u128>().is_empty());
+    }
+
+    #[test]
+    fn can_cast_works()
+    {
+        let ninja: SomePtr<dyn subjects::INinja> =
+            SomePtr::Transient(Box::new(subjects::Ninja));
+
+        assert!(can_cast::<dyn Debug, _>(&ninja));
+        assert!(!can_cast::<dyn Display, _>(&ninja));
+    }
+
+    #[test]
+    fn can_cast_singleton()
+    {
+        let ninja: SomePtr<dyn subjects::INinja> =
+            SomePtr::Singleton(Rc::new(subjects::Ninja));
+
+        assert!(matches!(
+            cast::<dyn Debug, _>(ninja),
+            Ok(SomePtr::Singleton(_))
+        ));
+    }
+
+    #[test]
+    fn cannot_cast_to_undeclared_interface()
+    {
+        let ninja: SomePtr<dyn subjects::INinja> =
+            SomePtr::Transient(Box::new(subjects::Ninja));
+
+        assert!(matches!(
+            cast::<dyn Display, _>(ninja),
+            Err(CastingError::NotDeclared { .. })
+        ));
+    }
+
+    #[test]
+    fn cast_of_threadsafe_ptr_fails()
+    {
+        let ninja: SomePtr<dyn subjects::INinja> =
+            SomePtr::ThreadsafeSingleton(Arc::new(subjects::Ninja));
+
+        assert!(matches!(
+            cast::<dyn Debug, _>(ninja),
+            Err(CastingError::ThreadsafePtr(_))
+        ));
+    }
+
+    #[test]
+    fn can_cast_threadsafe()
+    {
+        let ninja: SomePtr<subjects::Ninja> =
+            SomePtr::ThreadsafeSingleton(Arc::new(subjects::Ninja));
+
+        assert!(can_cast::<dyn Debug, _>(&ninja));
+
+        assert!(matches!(
+            cast_threadsafe::<dyn Debug, _>(ninja),
+            Ok(SomePtr::ThreadsafeSingleton(_))
+        ));
+    }
+}
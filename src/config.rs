@@ -0,0 +1,502 @@
+//! Declarative configuration of the bindings of a [`DIContainer`].
+//!
+//! A configuration describes bindings of interfaces to implementations registered under
+//! a key using the `key` flag of the [`injectable`] macro. It can be written in either
+//! TOML or JSON.
+//!
+//! Each binding consists of
+//! - `interface`: The interface as written in the [`injectable`] macro.
+//! - `implementation`: The key the implementation is registered under.
+//! - `scope` (Optional): `transient` or `singleton`. Defaults to `transient`.
+//! - `name` (Optional): The name of the binding.
+//!
+//! # Examples
+//! ```toml
+//! [[bindings]]
+//! interface = "IStorage"
+//! implementation = "s3"
+//! scope = "singleton"
+//!
+//! [[bindings]]
+//! interface = "IStorage"
+//! implementation = "local"
+//! name = "backup"
+//! ```
+//!
+//! ```json
+//! {
+//!   "bindings": [
+//!     { "interface": "IStorage", "implementation": "s3", "scope": "singleton" },
+//!     { "interface": "IStorage", "implementation": "local", "name": "backup" }
+//!   ]
+//! }
+//! ```
+//!
+//! [`DIContainer`]: crate::di_container::blocking::DIContainer
+//! [`injectable`]: crate::injectable
+use std::path::Path;
+use std::sync::{Mutex, PoisonError};
+
+use hashbrown::HashSet;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::config::ConfigError;
+use crate::private::registry::{
+    registered_implementations,
+    RegisteredImplementation,
+    RegisteredScope,
+};
+
+/// Configuration of the bindings of a [`DIContainer`].
+///
+/// [`DIContainer`]: crate::di_container::blocking::DIContainer
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContainerConfig
+{
+    /// The bindings.
+    #[serde(default)]
+    pub bindings: Vec<BindingConfig>,
+}
+
+impl ContainerConfig
+{
+    /// Parses a configuration in the given format.
+    ///
+    /// # Errors
+    /// Will return Err if the source is not a valid configuration.
+    pub fn parse(source: &str, format: ConfigFormat) -> Result<Self, ConfigError>
+    {
+        match format {
+            ConfigFormat::Toml => {
+                toml::from_str(source).map_err(|err| ConfigError::ParseFailed {
+                    line: err.line_col().map(|(line, _)| line + 1),
+                    message: err.to_string(),
+                })
+            }
+            ConfigFormat::Json => {
+                serde_json::from_str(source).map_err(|err| ConfigError::ParseFailed {
+                    line: Some(err.line()),
+                    message: err.to_string(),
+                })
+            }
+        }
+    }
+
+    /// Serializes the configuration to the given format.
+    ///
+    /// # Errors
+    /// Will return Err if serializing the configuration fails.
+    pub fn serialize(&self, format: ConfigFormat) -> Result<String, ConfigError>
+    {
+        match format {
+            ConfigFormat::Toml => {
+                toml::to_string(self).map_err(|err| ConfigError::SerializeFailed {
+                    message: err.to_string(),
+                })
+            }
+            ConfigFormat::Json => serde_json::to_string_pretty(self).map_err(|err| {
+                ConfigError::SerializeFailed {
+                    message: err.to_string(),
+                }
+            }),
+        }
+    }
+}
+
+/// Configuration of a single binding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BindingConfig
+{
+    /// The interface as written in the [`injectable`] macro.
+    ///
+    /// [`injectable`]: crate::injectable
+    pub interface: String,
+
+    /// The key the implementation is registered under.
+    pub implementation: String,
+
+    /// The scope of the binding.
+    #[serde(default)]
+    pub scope: BindingScope,
+
+    /// The name of the binding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The scope of a binding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BindingScope
+{
+    /// A new instance is created every time the binding is resolved.
+    #[default]
+    Transient,
+
+    /// A single instance is shared.
+    Singleton,
+}
+
+impl From<BindingScope> for RegisteredScope
+{
+    fn from(scope: BindingScope) -> Self
+    {
+        match scope {
+            BindingScope::Transient => Self::Transient,
+            BindingScope::Singleton => Self::Singleton,
+        }
+    }
+}
+
+impl From<RegisteredScope> for BindingScope
+{
+    fn from(scope: RegisteredScope) -> Self
+    {
+        match scope {
+            RegisteredScope::Transient => Self::Transient,
+            RegisteredScope::Singleton => Self::Singleton,
+        }
+    }
+}
+
+/// The format of a configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat
+{
+    /// TOML.
+    Toml,
+
+    /// JSON.
+    Json,
+}
+
+impl ConfigFormat
+{
+    /// Returns the format of a configuration file from it's file extension.
+    ///
+    /// # Errors
+    /// Will return Err if the file extension is not `toml` or `json`.
+    pub fn from_path(path: &Path) -> Result<Self, ConfigError>
+    {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Ok(Self::Toml),
+            Some("json") => Ok(Self::Json),
+            _ => Err(ConfigError::UnknownFormat {
+                path: path.to_path_buf(),
+            }),
+        }
+    }
+}
+
+/// A configured binding with it's registered implementation.
+pub(crate) struct ResolvedBindingConfig
+{
+    pub registered: &'static RegisteredImplementation,
+    pub scope: RegisteredScope,
+    pub name: Option<String>,
+    pub line: Option<usize>,
+}
+
+/// Finds the registered implementations of all of the bindings of a configuration.
+///
+/// The source of the configuration is used to find the lines of offending bindings.
+pub(crate) fn resolve_binding_configs(
+    config: ContainerConfig,
+    source: &str,
+) -> Result<Vec<ResolvedBindingConfig>, ConfigError>
+{
+    config
+        .bindings
+        .into_iter()
+        .enumerate()
+        .map(|(index, binding_config)| {
+            let registered = find_registered_implementation(&binding_config, |field| {
+                find_field_line(source, field, index)
+            })?;
+
+            Ok(ResolvedBindingConfig {
+                registered,
+                scope: binding_config.scope.into(),
+                name: binding_config.name,
+                line: find_field_line(source, "interface", index),
+            })
+        })
+        .collect()
+}
+
+/// Returns the configuration of a binding of a interface to a implementation if the
+/// implementation is registered under a key.
+pub(crate) fn find_binding_config(
+    interface: core::any::TypeId,
+    implementation: core::any::TypeId,
+    scope: RegisteredScope,
+    opt_name: Option<&str>,
+) -> Option<BindingConfig>
+{
+    registered_implementations()
+        .iter()
+        .find(|registered| {
+            (registered.interface)() == interface
+                && (registered.implementation)() == implementation
+        })
+        .map(|registered| BindingConfig {
+            interface: registered.interface_key.to_string(),
+            implementation: registered.key.to_string(),
+            scope: scope.into(),
+            name: opt_name.map(ToString::to_string),
+        })
+}
+
+fn find_registered_implementation(
+    binding_config: &BindingConfig,
+    find_line: impl Fn(&str) -> Option<usize>,
+) -> Result<&'static RegisteredImplementation, ConfigError>
+{
+    let interface_registered: Vec<_> = registered_implementations()
+        .iter()
+        .filter(|registered| registered.interface_key == binding_config.interface)
+        .collect();
+
+    if interface_registered.is_empty() {
+        return Err(ConfigError::UnknownInterface {
+            interface: binding_config.interface.clone(),
+            line: find_line("interface"),
+        });
+    }
+
+    let matching: Vec<_> = interface_registered
+        .iter()
+        .filter(|registered| registered.key == binding_config.implementation)
+        .collect();
+
+    match matching.as_slice() {
+        [registered] => Ok(registered),
+        [] => {
+            let mut available_keys: Vec<_> = interface_registered
+                .iter()
+                .map(|registered| registered.key)
+                .collect();
+
+            available_keys.sort_unstable();
+            available_keys.dedup();
+
+            Err(ConfigError::UnregisteredKey {
+                interface: binding_config.interface.clone(),
+                key: binding_config.implementation.clone(),
+                available_keys,
+                line: find_line("implementation"),
+            })
+        }
+        _ => Err(ConfigError::AmbiguousRegisteredKey {
+            interface: binding_config.interface.clone(),
+            key: binding_config.implementation.clone(),
+            implementations: matching
+                .iter()
+                .map(|registered| (registered.implementation_name)())
+                .collect(),
+            line: find_line("implementation"),
+        }),
+    }
+}
+
+/// Returns a copy of the given binding name that lives for the rest of the program.
+///
+/// Names of bindings must be `'static` since they can outlive the DI container, for
+/// example in errors. Every distinct name is only allocated once so that loading
+/// configurations repeatedly doesn't keep allocating memory that is never freed.
+pub(crate) fn intern_binding_name(name: &str) -> &'static str
+{
+    static INTERNED_NAMES: Lazy<Mutex<HashSet<&'static str>>> =
+        Lazy::new(|| Mutex::new(HashSet::new()));
+
+    let mut interned_names = INTERNED_NAMES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    if let Some(interned_name) = interned_names.get(name) {
+        return interned_name;
+    }
+
+    let interned_name: &'static str = Box::leak(name.into());
+
+    interned_names.insert(interned_name);
+
+    interned_name
+}
+
+/// Returns the line number of the field with the given name in the binding at the given
+/// index.
+fn find_field_line(source: &str, field: &str, index: usize) -> Option<usize>
+{
+    source
+        .lines()
+        .enumerate()
+        .flat_map(|(line_index, line)| {
+            line.match_indices(field)
+                .filter(move |(offset, _)| is_field_key(line, *offset, field.len()))
+                .map(move |_| line_index + 1)
+        })
+        .nth(index)
+}
+
+/// Returns whether the text at the given offset of a line is the key of a field in
+/// either TOML or JSON.
+fn is_field_key(line: &str, offset: usize, len: usize) -> bool
+{
+    let before = line[..offset].trim_end_matches('"').trim_end();
+    let after = line[offset + len..].trim_start_matches('"').trim_start();
+
+    (before.is_empty() || before.ends_with(['{', ',']))
+        && (after.starts_with('=') || after.starts_with(':'))
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn parse_toml_works()
+    {
+        let config = ContainerConfig::parse(
+            concat!(
+                "[[bindings]]\n",
+                "interface = \"IStorage\"\n",
+                "implementation = \"s3\"\n",
+                "scope = \"singleton\"\n",
+                "\n",
+                "[[bindings]]\n",
+                "interface = \"IStorage\"\n",
+                "implementation = \"local\"\n",
+                "name = \"backup\"\n",
+            ),
+            ConfigFormat::Toml,
+        )
+        .expect("Expected Ok");
+
+        assert_eq!(
+            config.bindings,
+            vec![
+                BindingConfig {
+                    interface: "IStorage".to_string(),
+                    implementation: "s3".to_string(),
+                    scope: BindingScope::Singleton,
+                    name: None
+                },
+                BindingConfig {
+                    interface: "IStorage".to_string(),
+                    implementation: "local".to_string(),
+                    scope: BindingScope::Transient,
+                    name: Some("backup".to_string())
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_json_works()
+    {
+        let config = ContainerConfig::parse(
+            r#"{ "bindings": [{ "interface": "IStorage", "implementation": "s3" }] }"#,
+            ConfigFormat::Json,
+        )
+        .expect("Expected Ok");
+
+        assert_eq!(
+            config.bindings,
+            vec![BindingConfig {
+                interface: "IStorage".to_string(),
+                implementation: "s3".to_string(),
+                scope: BindingScope::Transient,
+                name: None
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_with_unknown_scope_fails()
+    {
+        assert!(matches!(
+            ContainerConfig::parse(
+                concat!(
+                    "{\n",
+                    "  \"bindings\": [\n",
+                    "    { \"interface\": \"IStorage\", \"implementation\": \"s3\", ",
+                    "\"scope\": \"forever\" }\n",
+                    "  ]\n",
+                    "}"
+                ),
+                ConfigFormat::Json,
+            ),
+            Err(ConfigError::ParseFailed { line: Some(3), .. })
+        ));
+    }
+
+    #[test]
+    fn serialize_roundtrip_works()
+    {
+        let config = ContainerConfig {
+            bindings: vec![BindingConfig {
+                interface: "IStorage".to_string(),
+                implementation: "s3".to_string(),
+                scope: BindingScope::Singleton,
+                name: Some("primary".to_string()),
+            }],
+        };
+
+        for format in [ConfigFormat::Toml, ConfigFormat::Json] {
+            let source = config.serialize(format).expect("Expected Ok");
+
+            assert_eq!(
+                ContainerConfig::parse(&source, format).expect("Expected Ok"),
+                config
+            );
+        }
+    }
+
+    #[test]
+    fn format_from_path_works()
+    {
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("container.toml")).expect("Expected Ok"),
+            ConfigFormat::Toml
+        );
+
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("container.json")).expect("Expected Ok"),
+            ConfigFormat::Json
+        );
+
+        assert!(ConfigFormat::from_path(Path::new("container.yaml")).is_err());
+    }
+
+    #[test]
+    fn find_field_line_works()
+    {
+        let toml_source = concat!(
+            "[[bindings]]\n",
+            "interface = \"IStorage\"\n",
+            "implementation = \"s3\"\n",
+            "\n",
+            "[[bindings]]\n",
+            "interface = \"ICache\"\n",
+            "implementation = \"memory\"\n",
+        );
+
+        assert_eq!(find_field_line(toml_source, "implementation", 1), Some(7));
+        assert_eq!(find_field_line(toml_source, "interface", 0), Some(2));
+
+        let json_source = concat!(
+            "{\"bindings\": [\n",
+            "  {\"interface\": \"IStorage\", \"implementation\": \"s3\"},\n",
+            "  {\"interface\": \"ICache\", \"implementation\": \"memory\"}\n",
+            "]}"
+        );
+
+        assert_eq!(find_field_line(json_source, "implementation", 1), Some(3));
+        assert_eq!(find_field_line(json_source, "implementation", 2), None);
+    }
+}
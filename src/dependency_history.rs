@@ -1,8 +1,13 @@
 //! Dependency history.
 
-use std::any::type_name;
-use std::collections::HashSet;
-use std::fmt::{Debug, Display};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::{type_name, TypeId};
+use core::fmt::{Debug, Display};
+
+use hashbrown::HashMap;
+
+use crate::di_container::BindingOptions;
 
 const BOLD_MODE: &str = "\x1b[1m";
 const RESET_BOLD_MODE: &str = "\x1b[22m";
@@ -10,8 +15,8 @@ const RESET_BOLD_MODE: &str = "\x1b[22m";
 /// Dependency history.
 ///
 /// Used by the implementations of [`Injectable`] and [`AsyncInjectable`] generated by
-/// the [`injectable`] macro to prevent circular dependencies when the `prevent-circular`
-/// crate feature is enabled.
+/// the [`injectable`] macro to keep track of the resolution path and to prevent
+/// circular dependencies when the `prevent-circular` crate feature is enabled.
 ///
 /// [`Injectable`]: crate::interfaces::injectable::Injectable
 /// [`AsyncInjectable`]: crate::interfaces::async_injectable::AsyncInjectable
@@ -19,81 +24,289 @@ const RESET_BOLD_MODE: &str = "\x1b[22m";
 #[derive(Clone, Debug, Default)]
 pub struct DependencyHistory
 {
-    inner: Vec<&'static str>,
+    frames: Vec<DependencyFrame>,
+
+    // Boxed to keep the errors containing a dependency history small
+    implementations: Box<ImplementationIndex>,
+
+    // The ids of the lazy singletons this resolution is initializing
+    #[cfg(feature = "async")]
+    #[cfg_attr(test, allow(dead_code))]
+    initializing_singletons: Vec<usize>,
 }
 
 impl DependencyHistory
 {
     /// Returns a new `DependencyHistory`.
     #[must_use]
-    pub const fn new() -> Self
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    /// Returns all of the frames in the history, from the root to the most recently
+    /// added.
+    #[must_use]
+    pub fn frames(&self) -> &[DependencyFrame]
+    {
+        &self.frames
+    }
+
+    /// Returns the frames forming a dependency cycle, starting and ending with the
+    /// implementation that depends on itself.
+    ///
+    /// Returns a empty slice if the history doesn't contain a cycle.
+    #[must_use]
+    pub fn cycle(&self) -> &[DependencyFrame]
     {
-        Self { inner: Vec::new() }
+        self.implementations
+            .cycle_start
+            .map_or_else(|| &[] as &[DependencyFrame], |start| &self.frames[start..])
+    }
+
+    /// Returns a value that displays the history with the implementation forming a
+    /// dependency cycle highlighted using ANSI escape codes.
+    ///
+    /// The [`Display`] implementation of `DependencyHistory` renders the same content
+    /// without any escape codes.
+    #[must_use]
+    pub fn colored(&self) -> ColoredDependencyHistory<'_>
+    {
+        ColoredDependencyHistory { history: self }
+    }
+
+    fn fmt_frames(
+        &self,
+        formatter: &mut core::fmt::Formatter<'_>,
+        colored: bool,
+    ) -> core::fmt::Result
+    {
+        let opt_cycle_frame = self.cycle().first();
+
+        for (index, frame) in self.frames.iter().enumerate() {
+            if colored && opt_cycle_frame.map_or_else(|| false, |cycle| frame == cycle) {
+                formatter
+                    .write_fmt(format_args!("{BOLD_MODE}{frame}{RESET_BOLD_MODE}"))?;
+            } else {
+                formatter.write_fmt(format_args!("{frame}"))?;
+            }
+
+            if index != self.frames.len() - 1 {
+                formatter.write_str(" -> ")?;
+            }
+        }
+
+        if opt_cycle_frame.is_some() {
+            formatter.write_str(" -> ...")?;
+        }
+
+        Ok(())
     }
 }
 
-#[cfg_attr(test, mockall::automock)]
+#[cfg_attr(test, mockall::automock, allow(dead_code))]
 impl DependencyHistory
 {
     /// Adds a dependency to the history.
     pub fn push<Dependency: 'static + ?Sized>(&mut self)
     {
-        self.inner.push(type_name::<Dependency>());
+        let frame = DependencyFrame::new::<Dependency>(
+            DependencyFrameKind::Implementation,
+            BindingOptions::new(),
+        );
+
+        let index = self.frames.len();
+
+        let implementations = &mut self.implementations;
+
+        match implementations.first_indices.get(&frame.type_id) {
+            Some(first_index) if implementations.cycle_start.is_none() => {
+                implementations.cycle_start = Some(*first_index);
+            }
+            Some(_) => {}
+            None => {
+                implementations.first_indices.insert(frame.type_id, index);
+            }
+        }
+
+        self.frames.push(frame);
+    }
+
+    /// Adds a interface that is about to be resolved to the history.
+    ///
+    /// Interfaces are only part of the resolution path and are not taken into account
+    /// by [`contains`].
+    ///
+    /// [`contains`]: Self::contains
+    pub fn push_interface<Interface: 'static + ?Sized>(
+        &mut self,
+        binding_options: BindingOptions<'static>,
+    )
+    {
+        self.frames.push(DependencyFrame::new::<Interface>(
+            DependencyFrameKind::Interface,
+            binding_options,
+        ));
+    }
+
+    /// Returns the number of implementations in the history.
+    #[allow(clippy::must_use_candidate)]
+    pub fn depth(&self) -> usize
+    {
+        self.frames
+            .iter()
+            .filter(|frame| frame.kind == DependencyFrameKind::Implementation)
+            .count()
     }
 
     /// Returns whether or not a dependency is present in the history.
     #[allow(clippy::must_use_candidate)]
     pub fn contains<Dependency: 'static + ?Sized>(&self) -> bool
     {
-        self.inner.contains(&type_name::<Dependency>())
+        self.implementations
+            .first_indices
+            .contains_key(&TypeId::of::<Dependency>())
+    }
+
+    /// Marks the lazy singleton with the given id as being initialized by this
+    /// resolution.
+    #[cfg(feature = "async")]
+    pub(crate) fn push_initializing_singleton(&mut self, singleton_id: usize)
+    {
+        self.initializing_singletons.push(singleton_id);
+    }
+
+    /// Returns the id of the lazy singleton this resolution most recently started
+    /// initializing.
+    #[cfg(feature = "async")]
+    pub(crate) fn last_initializing_singleton(&self) -> Option<usize>
+    {
+        self.initializing_singletons.last().copied()
+    }
+
+    /// Returns whether or not this resolution is initializing the lazy singleton with
+    /// the given id.
+    #[cfg(feature = "async")]
+    pub(crate) fn is_initializing_singleton(&self, singleton_id: usize) -> bool
+    {
+        self.initializing_singletons.contains(&singleton_id)
     }
 }
 
 impl Display for DependencyHistory
 {
-    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
     {
-        let mut found_items = HashSet::new();
+        self.fmt_frames(formatter, false)
+    }
+}
 
-        let opt_dupe_item = self.inner.iter().find(|item| {
-            if found_items.contains(item) {
-                return true;
-            }
+/// Displays a [`DependencyHistory`] with the implementation forming a dependency cycle
+/// highlighted using ANSI escape codes.
+///
+/// Returned by [`DependencyHistory::colored`].
+#[derive(Debug)]
+pub struct ColoredDependencyHistory<'history>
+{
+    history: &'history DependencyHistory,
+}
 
-            found_items.insert(*item);
+impl Display for ColoredDependencyHistory<'_>
+{
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        self.history.fmt_frames(formatter, true)
+    }
+}
 
-            false
-        });
+#[derive(Clone, Debug, Default)]
+struct ImplementationIndex
+{
+    /// The index of the first frame of each implementation.
+    first_indices: HashMap<TypeId, usize>,
 
-        for (index, item) in self.inner.iter().enumerate() {
-            let mut item_is_dupe = false;
+    /// The index of the first frame of the implementation that depends on itself.
+    cycle_start: Option<usize>,
+}
 
-            if let Some(dupe_item) = opt_dupe_item {
-                if *item == *dupe_item {
-                    formatter
-                        .write_fmt(format_args!("{BOLD_MODE}{item}{RESET_BOLD_MODE}"))?;
+/// A single step in a [`DependencyHistory`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DependencyFrame
+{
+    type_id: TypeId,
+    type_name: &'static str,
+    kind: DependencyFrameKind,
+    binding_options: BindingOptions<'static>,
+}
 
-                    item_is_dupe = true;
-                }
-            }
+impl DependencyFrame
+{
+    fn new<Type: 'static + ?Sized>(
+        kind: DependencyFrameKind,
+        binding_options: BindingOptions<'static>,
+    ) -> Self
+    {
+        Self {
+            type_id: TypeId::of::<Type>(),
+            type_name: type_name::<Type>(),
+            kind,
+            binding_options,
+        }
+    }
 
-            if !item_is_dupe {
-                formatter.write_str(item)?;
-            }
+    /// Returns the [`TypeId`] of the interface or implementation.
+    #[must_use]
+    pub fn type_id(&self) -> TypeId
+    {
+        self.type_id
+    }
 
-            if index != self.inner.len() - 1 {
-                formatter.write_str(" -> ")?;
-            }
-        }
+    /// Returns the name of the interface or implementation.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str
+    {
+        self.type_name
+    }
 
-        if opt_dupe_item.is_some() {
-            formatter.write_str(" -> ...")?;
-        }
+    /// Returns the kind of the frame.
+    #[must_use]
+    pub fn kind(&self) -> DependencyFrameKind
+    {
+        self.kind
+    }
 
-        Ok(())
+    /// Returns the name of the binding that was resolved, if the frame is a interface
+    /// with a named binding.
+    #[must_use]
+    pub fn binding_name(&self) -> Option<&'static str>
+    {
+        self.binding_options.name
+    }
+}
+
+impl Display for DependencyFrame
+{
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        match self.binding_name() {
+            Some(binding_name) => formatter
+                .write_fmt(format_args!("{} (\"{binding_name}\")", self.type_name)),
+            None => formatter.write_str(self.type_name),
+        }
     }
 }
 
+/// The kind of a [`DependencyFrame`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DependencyFrameKind
+{
+    /// A interface that was resolved using a binding.
+    Interface,
+
+    /// A implementation that was resolved.
+    Implementation,
+}
+
 #[cfg(test)]
 mod tests
 {
@@ -107,9 +320,36 @@ mod tests
 
         dependency_history.push::<dyn subjects::INumber>();
 
-        assert!(dependency_history
-            .inner
-            .contains(&type_name::<dyn subjects::INumber>()));
+        assert_eq!(
+            dependency_history.frames(),
+            &[DependencyFrame::new::<dyn subjects::INumber>(
+                DependencyFrameKind::Implementation,
+                BindingOptions::new()
+            )]
+        );
+    }
+
+    #[test]
+    fn can_push_interface()
+    {
+        let mut dependency_history = DependencyHistory::new();
+
+        dependency_history.push_interface::<dyn subjects::INumber>(
+            BindingOptions::new().name("special"),
+        );
+
+        assert_eq!(
+            dependency_history.frames(),
+            &[DependencyFrame::new::<dyn subjects::INumber>(
+                DependencyFrameKind::Interface,
+                BindingOptions::new().name("special")
+            )]
+        );
+
+        assert_eq!(
+            dependency_history.frames()[0].binding_name(),
+            Some("special")
+        );
     }
 
     #[test]
@@ -117,15 +357,72 @@ mod tests
     {
         let mut dependency_history = DependencyHistory::new();
 
-        dependency_history
-            .inner
-            .push(type_name::<dyn subjects::IUserManager>());
+        dependency_history.push::<dyn subjects::IUserManager>();
 
         assert!(dependency_history.contains::<dyn subjects::IUserManager>());
 
         assert!(!dependency_history.contains::<dyn subjects::INumber>());
     }
 
+    #[test]
+    fn contains_ignores_interfaces()
+    {
+        let mut dependency_history = DependencyHistory::new();
+
+        dependency_history.push_interface::<dyn subjects::INumber>(BindingOptions::new());
+
+        assert!(!dependency_history.contains::<dyn subjects::INumber>());
+    }
+
+    #[test]
+    fn depth_works()
+    {
+        let mut dependency_history = DependencyHistory::new();
+
+        assert_eq!(dependency_history.depth(), 0);
+
+        dependency_history.push::<dyn subjects::IUserManager>();
+        dependency_history.push_interface::<dyn subjects::INumber>(BindingOptions::new());
+
+        assert_eq!(dependency_history.depth(), 1);
+
+        dependency_history.push::<dyn subjects::INumber>();
+
+        assert_eq!(dependency_history.depth(), 2);
+    }
+
+    #[test]
+    fn cycle_works()
+    {
+        trait IKatana {}
+        struct Ninja;
+        struct Katana;
+
+        let mut dependency_history = DependencyHistory::new();
+
+        dependency_history.push::<Ninja>();
+        dependency_history.push_interface::<dyn IKatana>(BindingOptions::new());
+        dependency_history.push::<Katana>();
+
+        assert!(dependency_history.cycle().is_empty());
+
+        dependency_history.push::<Ninja>();
+
+        assert_eq!(
+            dependency_history
+                .cycle()
+                .iter()
+                .map(DependencyFrame::type_id)
+                .collect::<Vec<_>>(),
+            vec![
+                TypeId::of::<Ninja>(),
+                TypeId::of::<dyn IKatana>(),
+                TypeId::of::<Katana>(),
+                TypeId::of::<Ninja>()
+            ]
+        );
+    }
+
     #[test]
     fn display_works()
     {
@@ -135,9 +432,9 @@ mod tests
 
         let mut dependency_history = DependencyHistory::new();
 
-        dependency_history.inner.push(type_name::<dyn Ninja>());
-        dependency_history.inner.push(type_name::<dyn Katana>());
-        dependency_history.inner.push(type_name::<dyn Blade>());
+        dependency_history.push::<dyn Ninja>();
+        dependency_history.push::<dyn Katana>();
+        dependency_history.push::<dyn Blade>();
 
         assert_eq!(
             dependency_history.to_string(),
@@ -149,10 +446,36 @@ mod tests
             )
         );
 
-        dependency_history.inner.push(type_name::<dyn Katana>());
+        dependency_history.push::<dyn Katana>();
 
         assert_eq!(
             dependency_history.to_string(),
+            format!(
+                "{} -> {} -> {} -> {} -> ...",
+                type_name::<dyn Ninja>(),
+                type_name::<dyn Katana>(),
+                type_name::<dyn Blade>(),
+                type_name::<dyn Katana>(),
+            )
+        );
+    }
+
+    #[test]
+    fn colored_display_works()
+    {
+        trait Ninja {}
+        trait Katana {}
+        trait Blade {}
+
+        let mut dependency_history = DependencyHistory::new();
+
+        dependency_history.push::<dyn Ninja>();
+        dependency_history.push::<dyn Katana>();
+        dependency_history.push::<dyn Blade>();
+        dependency_history.push::<dyn Katana>();
+
+        assert_eq!(
+            dependency_history.colored().to_string(),
             format!(
                 concat!(
                     "{} -> {bold_mode}{}{reset_bold_mode} -> {} -> ",
@@ -167,4 +490,32 @@ mod tests
             )
         );
     }
+
+    #[test]
+    fn display_with_interfaces_works()
+    {
+        trait IRepository {}
+        trait IPool {}
+        struct App;
+        struct PostgresRepository;
+
+        let mut dependency_history = DependencyHistory::new();
+
+        dependency_history.push::<App>();
+        dependency_history
+            .push_interface::<dyn IRepository>(BindingOptions::new().name("primary"));
+        dependency_history.push::<PostgresRepository>();
+        dependency_history.push_interface::<dyn IPool>(BindingOptions::new());
+
+        assert_eq!(
+            dependency_history.to_string(),
+            format!(
+                "{} -> {} (\"primary\") -> {} -> {}",
+                type_name::<App>(),
+                type_name::<dyn IRepository>(),
+                type_name::<PostgresRepository>(),
+                type_name::<dyn IPool>()
+            )
+        );
+    }
 }
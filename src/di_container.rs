@@ -5,6 +5,8 @@
 pub mod asynchronous;
 
 pub mod blocking;
+pub mod listener;
+pub mod metrics;
 
 /// DI container binding options.
 ///
@@ -17,7 +19,7 @@ pub mod blocking;
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct BindingOptions<'a>
 {
-    name: Option<&'a str>,
+    pub(crate) name: Option<&'a str>,
 }
 
 impl<'a> BindingOptions<'a>
@@ -39,5 +41,83 @@ impl<'a> BindingOptions<'a>
     }
 }
 
+/// How singletons are handled when forking a DI container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SingletonForkPolicy
+{
+    /// The fork shares the singleton instances of the original DI container.
+    Share,
+
+    /// The fork creates it's own singleton instances. They are created the first time
+    /// they are resolved from the fork.
+    Recreate,
+}
+
+/// How bindings that exist in both DI containers are handled when merging DI
+/// containers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictPolicy
+{
+    /// The merge fails without any bindings being merged.
+    Error,
+
+    /// The existing bindings are kept.
+    KeepExisting,
+
+    /// The existing bindings are overwritten.
+    Overwrite,
+}
+
+/// Placeholder for the type parameter of a generic implementation template and it's
+/// interface.
+///
+/// Used with [`bind_generic`] to refer to all instances of a generic implementation
+/// that are listed in the `instances` flag of the [`injectable`] macro.
+///
+/// # Examples
+/// ```
+/// # use std::marker::PhantomData;
+/// #
+/// # use syrette::di_container::GenericParam;
+/// # use syrette::{injectable, DIContainer};
+/// #
+/// trait IRepository<Entity> {}
+///
+/// struct Repository<Entity>
+/// {
+///     _entity: PhantomData<Entity>,
+/// }
+///
+/// #[injectable(IRepository<Entity>, instances = [String, u64])]
+/// impl<Entity: 'static> Repository<Entity>
+/// {
+///     fn new() -> Self
+///     {
+///         Self {
+///             _entity: PhantomData,
+///         }
+///     }
+/// }
+///
+/// impl<Entity: 'static> IRepository<Entity> for Repository<Entity> {}
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut di_container = DIContainer::new();
+///
+/// di_container
+///     .bind_generic::<dyn IRepository<GenericParam>>()
+///     .to::<Repository<GenericParam>>()?;
+///
+/// di_container.get::<dyn IRepository<u64>>()?.transient()?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`bind_generic`]: crate::di_container::blocking::DIContainer::bind_generic
+/// [`injectable`]: crate::injectable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenericParam {}
+
 // Private.
 pub(crate) mod binding_storage;
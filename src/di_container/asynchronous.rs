@@ -50,29 +50,58 @@
 //! }
 //! ```
 use std::any::type_name;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+use std::time::{Duration, Instant};
 
-use crate::castable_function::threadsafe::ThreadsafeCastableFunction;
+use hashbrown::HashMap;
+
+use crate::castable_function::threadsafe::fallible::ThreadsafeFallibleCastableFunction;
+use crate::castable_function::threadsafe::{
+    AnyThreadsafeCastableFunction,
+    ThreadsafeCastableFunction,
+};
 use crate::di_container::asynchronous::binding::builder::AsyncBindingBuilder;
+use crate::di_container::asynchronous::container_ref::AsyncContainerRef;
+use crate::di_container::asynchronous::task_scope::TaskScoped;
+use crate::di_container::asynchronous::warm_up::{
+    order_in_stages,
+    WarmUpEntry,
+    WarmUpReport,
+};
 use crate::di_container::binding_storage::DIContainerBindingStorage;
-use crate::di_container::BindingOptions;
+use crate::di_container::listener::{ResolutionListener, ResolutionListeners};
+use crate::di_container::metrics::{BindingMetrics, MetricsStore};
+use crate::di_container::{BindingOptions, ConflictPolicy, SingletonForkPolicy};
 use crate::errors::async_di_container::AsyncDIContainerError;
-use crate::future::BoxFuture;
+use crate::errors::injectable::InjectableError;
+use crate::future::{join_all, BoxFuture};
+use crate::interfaces::async_dependency_resolver::AsyncDependencyResolver;
+use crate::interfaces::async_injectable::AsyncInjectable;
 use crate::private::cast::arc::CastArc;
 use crate::private::cast::boxed::CastBox;
 use crate::private::cast::error::CastError;
 use crate::provider::r#async::{AsyncProvidable, IAsyncProvider, ProvidableFunctionKind};
-use crate::ptr::{SomePtr, TransientPtr};
+use crate::ptr::{SomePtr, ThreadsafeSingletonPtr, TransientPtr};
+#[cfg(feature = "testing")]
+use crate::testing::AsyncBindingOverrideBuilder;
 use crate::util::use_double;
 
 use_double!(crate::dependency_history::DependencyHistory);
 
 pub mod binding;
+pub mod container_ref;
+pub mod task_scope;
+pub mod warm_up;
 
 /// Async dependency injection container.
 #[derive(Default)]
 pub struct AsyncDIContainer
 {
     binding_storage: DIContainerBindingStorage<dyn IAsyncProvider<Self>>,
+    listeners: ResolutionListeners<dyn ResolutionListener + Send + Sync>,
+    metrics: Mutex<MetricsStore>,
+    self_ref: Option<Weak<Self>>,
 }
 
 impl AsyncDIContainer
@@ -81,9 +110,631 @@ impl AsyncDIContainer
     #[must_use]
     pub fn new() -> Self
     {
-        Self {
-            binding_storage: DIContainerBindingStorage::new(),
-        }
+        Self {
+            binding_storage: DIContainerBindingStorage::new(),
+            listeners: ResolutionListeners::new(),
+            metrics: Mutex::new(MetricsStore::default()),
+            self_ref: None,
+        }
+    }
+
+    /// Moves the DI container into a `Arc`, making it possible for async injectables to
+    /// receive a [`AsyncContainerRef`] of it.
+    ///
+    /// The DI container can't be bound to after being shared, so all bindings should be
+    /// created beforehand. Singletons depending on a [`AsyncContainerRef`] must
+    /// therefore be bound in a lazy singleton scope, which defers creating them until
+    /// they are first resolved.
+    #[must_use]
+    pub fn into_shared(self) -> Arc<Self>
+    {
+        Arc::new_cyclic(|self_ref| Self {
+            self_ref: Some(self_ref.clone()),
+            ..self
+        })
+    }
+
+    /// Returns a snapshot of the resolution metrics of all bindings that have been
+    /// resolved, sorted by interface name and binding name.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # struct Connection {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl Connection
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container.bind::<Connection>().to::<Connection>()?;
+    ///
+    /// di_container.get::<Connection>().await?;
+    /// di_container.get::<Connection>().await?;
+    ///
+    /// assert_eq!(di_container.metrics()[0].resolution_count(), 2);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn metrics(&self) -> Vec<BindingMetrics>
+    {
+        self.metrics
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .snapshot()
+    }
+
+    /// Clears the resolution metrics of all bindings.
+    pub fn reset_metrics(&self)
+    {
+        self.metrics
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Returns a future that runs the future returned by `func` inside of a new task
+    /// scope.
+    ///
+    /// Task scoped bindings resolved anywhere inside of the future share the same
+    /// instances. Tasks spawned inside of the future are not part of the task scope.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::sync::Arc;
+    /// #
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # struct RequestContext {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl RequestContext
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<RequestContext>()
+    ///     .to::<RequestContext>()?
+    ///     .in_task_scope();
+    ///
+    /// let handle_request = || async {
+    ///     let request_context_a = di_container
+    ///         .get::<RequestContext>()
+    ///         .await?
+    ///         .threadsafe_singleton()?;
+    ///
+    ///     let request_context_b = di_container
+    ///         .get::<RequestContext>()
+    ///         .await?
+    ///         .threadsafe_singleton()?;
+    ///
+    ///     assert!(Arc::ptr_eq(&request_context_a, &request_context_b));
+    ///
+    ///     Ok::<_, Box<dyn std::error::Error>>(())
+    /// };
+    ///
+    /// AsyncDIContainer::scope(handle_request).await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn scope<Func, Fut>(func: Func) -> TaskScoped<Fut>
+    where
+        Func: FnOnce() -> Fut,
+        Fut: Future,
+    {
+        TaskScoped::new(func)
+    }
+
+    /// Initializes all lazy singletons.
+    ///
+    /// The lazy singletons are initialized in stages ordered by the dependencies
+    /// between them. Lazy singletons that don't depend on each other are initialized
+    /// concurrently.
+    ///
+    /// Failing to initialize a lazy singleton doesn't stop the warm-up. The failures
+    /// are instead included in the returned report.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::ptr::ThreadsafeSingletonPtr;
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # struct ConnectionPool {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl ConnectionPool
+    /// # {
+    /// #     async fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # struct UserRepository
+    /// # {
+    /// #     connection_pool: ThreadsafeSingletonPtr<ConnectionPool>,
+    /// # }
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl UserRepository
+    /// # {
+    /// #     async fn new(connection_pool: ThreadsafeSingletonPtr<ConnectionPool>) -> Self
+    /// #     {
+    /// #         Self { connection_pool }
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<ConnectionPool>()
+    ///     .to::<ConnectionPool>()?
+    ///     .in_lazy_singleton_scope();
+    ///
+    /// di_container
+    ///     .bind::<UserRepository>()
+    ///     .to::<UserRepository>()?
+    ///     .in_lazy_singleton_scope();
+    ///
+    /// let report = di_container.warm_up().await;
+    ///
+    /// for entry in report.entries() {
+    ///     println!(
+    ///         "Initialized {} in stage {} ({:?})",
+    ///         entry.implementation(),
+    ///         entry.stage(),
+    ///         entry.elapsed()
+    ///     );
+    /// }
+    ///
+    /// assert!(report.is_success());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn warm_up(&self) -> WarmUpReport
+    {
+        let start = Instant::now();
+
+        let lazy_singletons = self
+            .binding_storage
+            .iter()
+            .filter_map(|(type_id, binding_options, provider)| {
+                provider
+                    .lazy_singleton_info()
+                    .map(|info| (type_id, binding_options, provider, info))
+            })
+            .collect::<Vec<_>>();
+
+        let lazy_singleton_indices = lazy_singletons
+            .iter()
+            .enumerate()
+            .map(|(index, (type_id, binding_options, _, _))| {
+                ((*type_id, *binding_options), index)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let dependencies = lazy_singletons
+            .iter()
+            .map(|(_, _, _, info)| {
+                info.dependencies
+                    .iter()
+                    .filter_map(|dependency| {
+                        lazy_singleton_indices
+                            .get(&(dependency.type_id(), dependency.binding_options()))
+                            .copied()
+                    })
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+
+        let mut entries = Vec::with_capacity(lazy_singletons.len());
+
+        let (stages, cyclic_indices) = order_in_stages(&dependencies);
+
+        let cyclic_stage = stages.len();
+
+        for (stage, stage_indices) in stages.into_iter().enumerate() {
+            let results = join_all(
+                stage_indices
+                    .iter()
+                    .map(|index| {
+                        let (_, _, provider, _) = lazy_singletons[*index];
+
+                        async move {
+                            let start = Instant::now();
+
+                            let result =
+                                provider.provide(self, DependencyHistory::new()).await;
+
+                            (start.elapsed(), result)
+                        }
+                    })
+                    .collect(),
+            )
+            .await;
+
+            for (index, (elapsed, result)) in stage_indices.into_iter().zip(results) {
+                let (_, binding_options, _, info) = &lazy_singletons[index];
+
+                entries.push(WarmUpEntry {
+                    implementation: info.implementation,
+                    binding_name: binding_options.name,
+                    stage,
+                    elapsed,
+                    error: result.err(),
+                });
+            }
+        }
+
+        // Initializing these would fail or never finish
+        for index in cyclic_indices {
+            let (_, binding_options, _, info) = &lazy_singletons[index];
+
+            entries.push(WarmUpEntry {
+                implementation: info.implementation,
+                binding_name: binding_options.name,
+                stage: cyclic_stage,
+                elapsed: Duration::ZERO,
+                error: Some(InjectableError::InDependencyCycle {
+                    affected: info.implementation,
+                }),
+            });
+        }
+
+        WarmUpReport::new(entries, start.elapsed())
+    }
+
+    /// Adds a listener that will be notified about bindings being resolved.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::AsyncDIContainer;
+    /// # use syrette::di_container::listener::{ResolutionEvent, ResolutionListener};
+    /// #
+    /// struct ResolutionLogger;
+    ///
+    /// impl ResolutionListener for ResolutionLogger
+    /// {
+    ///     fn before_resolve(&self, event: &ResolutionEvent<'_>)
+    ///     {
+    ///         println!("Resolving {}", event.interface());
+    ///     }
+    /// }
+    ///
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container.add_listener(ResolutionLogger);
+    /// ```
+    pub fn add_listener<Listener>(&mut self, listener: Listener)
+    where
+        Listener: ResolutionListener + Send + Sync + 'static,
+    {
+        self.listeners.add(Box::new(listener));
+    }
+
+    /// Returns a fork of the DI container with a independent copy of all of it's
+    /// bindings.
+    ///
+    /// Bindings added to or removed from the fork do not affect the original DI container
+    /// and vice versa. Whether the fork shares the singleton instances of the original DI
+    /// container is decided by `singleton_policy`.
+    ///
+    /// Resolution listeners and metrics are not copied to the fork.
+    ///
+    /// The fork is not shared even if the original DI container is, so it has to be
+    /// shared with [`into_shared`] before async injectables can receive a
+    /// [`AsyncContainerRef`] of it. Shared singletons that hold a [`AsyncContainerRef`]
+    /// keep referencing the original DI container.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::di_container::SingletonForkPolicy;
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # struct Database {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl Database
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut base_di_container = AsyncDIContainer::new();
+    ///
+    /// base_di_container
+    ///     .bind::<Database>()
+    ///     .to::<Database>()?
+    ///     .in_singleton_scope()
+    ///     .await?;
+    ///
+    /// let tenant_di_container = base_di_container.fork(SingletonForkPolicy::Recreate);
+    ///
+    /// let base_database = base_di_container
+    ///     .get::<Database>()
+    ///     .await?
+    ///     .threadsafe_singleton()?;
+    ///
+    /// let tenant_database = tenant_di_container
+    ///     .get::<Database>()
+    ///     .await?
+    ///     .threadsafe_singleton()?;
+    ///
+    /// assert!(!std::sync::Arc::ptr_eq(&base_database, &tenant_database));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`into_shared`]: Self::into_shared
+    #[must_use]
+    pub fn fork(&self, singleton_policy: SingletonForkPolicy) -> Self
+    {
+        Self {
+            binding_storage: self
+                .binding_storage
+                .map_providers(|provider| provider.fork(singleton_policy)),
+            listeners: ResolutionListeners::new(),
+            metrics: Mutex::new(MetricsStore::default()),
+            self_ref: None,
+        }
+    }
+
+    /// Moves all of the bindings of `other` into the DI container.
+    ///
+    /// Bindings that exist in both DI containers are handled according to
+    /// `conflict_policy`.
+    ///
+    /// # Errors
+    /// Will return `Err` if `conflict_policy` is [`ConflictPolicy::Error`] and a binding
+    /// exists in both DI containers. No bindings are merged in that case.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::di_container::ConflictPolicy;
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # struct Storage {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl Storage
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # struct Mailer {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl Mailer
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container.bind::<Storage>().to::<Storage>()?;
+    ///
+    /// let mut mailer_di_container = AsyncDIContainer::new();
+    ///
+    /// mailer_di_container.bind::<Mailer>().to::<Mailer>()?;
+    ///
+    /// di_container.merge(mailer_di_container, ConflictPolicy::Error)?;
+    ///
+    /// di_container.get::<Mailer>().await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge(
+        &mut self,
+        other: Self,
+        conflict_policy: ConflictPolicy,
+    ) -> Result<(), AsyncDIContainerError>
+    {
+        if conflict_policy == ConflictPolicy::Error {
+            if let Some((interface, name)) =
+                self.binding_storage.find_conflict(&other.binding_storage)
+            {
+                return Err(AsyncDIContainerError::BindingConflict { interface, name });
+            }
+        }
+
+        self.binding_storage.extend(
+            other.binding_storage,
+            conflict_policy == ConflictPolicy::Overwrite,
+        );
+
+        Ok(())
+    }
+
+    /// Imports the binding of `Interface` from `other`.
+    ///
+    /// Singleton instances are shared between the DI containers.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` exists in `other`
+    /// - A binding for `Interface` already exists in the DI container
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # trait IMailer: Send + Sync {}
+    /// #
+    /// # struct Mailer {}
+    /// #
+    /// # #[injectable(IMailer, async = true)]
+    /// # impl Mailer
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IMailer for Mailer {}
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut mailer_di_container = AsyncDIContainer::new();
+    ///
+    /// mailer_di_container.bind::<dyn IMailer>().to::<Mailer>()?;
+    ///
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container.import::<dyn IMailer>(&mailer_di_container)?;
+    ///
+    /// di_container.get::<dyn IMailer>().await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn import<Interface>(&mut self, other: &Self) -> Result<(), AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.import_bound::<Interface>(other, BindingOptions::new())
+    }
+
+    /// Imports the binding of `Interface` with the specified name from `other`.
+    ///
+    /// Singleton instances are shared between the DI containers.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` with name `name` exists in `other`
+    /// - A binding for `Interface` with name `name` already exists in the DI container
+    pub fn import_named<Interface>(
+        &mut self,
+        other: &Self,
+        name: &'static str,
+    ) -> Result<(), AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.import_bound::<Interface>(other, BindingOptions::new().name(name))
+    }
+
+    /// Returns a new [`AsyncBindingOverrideBuilder`] for the given interface.
+    ///
+    /// The binding is overridden until the returned guard is dropped, after which the
+    /// original binding is restored.
+    ///
+    /// Requires mutable access to the DI container, so it can't be used on a DI
+    /// container shared using `into_shared`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::sync::Arc;
+    /// #
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # trait IClock: Send + Sync
+    /// # {
+    /// #     fn now(&self) -> u64;
+    /// # }
+    /// #
+    /// # struct SystemClock {}
+    /// #
+    /// # #[injectable(IClock, async = true)]
+    /// # impl SystemClock
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IClock for SystemClock
+    /// # {
+    /// #     fn now(&self) -> u64
+    /// #     {
+    /// #         1_700_000_000
+    /// #     }
+    /// # }
+    /// #
+    /// struct FakeClock {}
+    ///
+    /// impl IClock for FakeClock
+    /// {
+    ///     fn now(&self) -> u64
+    ///     {
+    ///         0
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container.bind::<dyn IClock>().to::<SystemClock>()?;
+    ///
+    /// {
+    ///     let di_container = di_container
+    ///         .override_binding::<dyn IClock>()
+    ///         .to_instance(Arc::new(FakeClock {}));
+    ///
+    ///     let clock = di_container
+    ///         .get::<dyn IClock>()
+    ///         .await?
+    ///         .threadsafe_singleton()?;
+    ///
+    ///     assert_eq!(clock.now(), 0);
+    /// }
+    ///
+    /// assert_ne!(
+    ///     di_container.get::<dyn IClock>().await?.transient()?.now(),
+    ///     0
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "testing")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "testing")))]
+    pub fn override_binding<Interface>(
+        &mut self,
+    ) -> AsyncBindingOverrideBuilder<'_, Interface>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        AsyncBindingOverrideBuilder::new(self)
     }
 }
 
@@ -269,11 +920,60 @@ impl AsyncDIContainer
     where
         Interface: 'static + ?Sized + Send + Sync,
     {
-        let binding_providable = self
-            .get_binding_providable::<Interface>(binding_options, dependency_history)
-            .await?;
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "get_bound",
+            interface = type_name::<Interface>(),
+            name = binding_options.name
+        );
+
+        let resolve = async {
+            let binding_name = binding_options.name;
 
-        self.handle_binding_providable(binding_providable).await
+            let opt_event = self
+                .listeners
+                .before_resolve::<Interface>(&dependency_history, binding_name);
+
+            let start = Instant::now();
+
+            let mut provider_kind = None;
+
+            let result = async {
+                let binding_providable = self
+                    .get_binding_providable::<Interface>(
+                        binding_options,
+                        dependency_history,
+                    )
+                    .await?;
+
+                provider_kind = Some(binding_providable.kind());
+
+                self.handle_binding_providable(binding_providable).await
+            }
+            .await;
+
+            let elapsed = start.elapsed();
+
+            self.metrics
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .record::<Interface>(
+                    binding_name,
+                    provider_kind,
+                    elapsed,
+                    result.is_ok(),
+                );
+
+            self.listeners
+                .after_resolve(opt_event, provider_kind, elapsed, &result);
+
+            result
+        };
+
+        #[cfg(feature = "tracing")]
+        let resolve = tracing::Instrument::instrument(resolve, span);
+
+        resolve.await
     }
 
     fn has_binding<Interface>(&self, binding_options: BindingOptions<'static>) -> bool
@@ -283,7 +983,7 @@ impl AsyncDIContainer
         self.binding_storage.has::<Interface>(binding_options)
     }
 
-    fn set_binding<Interface>(
+    pub(crate) fn set_binding<Interface>(
         &mut self,
         binding_options: BindingOptions<'static>,
         provider: Box<dyn IAsyncProvider<Self>>,
@@ -294,7 +994,7 @@ impl AsyncDIContainer
             .set::<Interface>(binding_options, provider);
     }
 
-    fn remove_binding<Interface>(
+    pub(crate) fn remove_binding<Interface>(
         &mut self,
         binding_options: BindingOptions<'static>,
     ) -> Option<Box<dyn IAsyncProvider<Self>>>
@@ -307,6 +1007,41 @@ impl AsyncDIContainer
 
 impl AsyncDIContainer
 {
+    fn import_bound<Interface>(
+        &mut self,
+        other: &Self,
+        binding_options: BindingOptions<'static>,
+    ) -> Result<(), AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        let name = binding_options.name;
+
+        let provider = other
+            .binding_storage
+            .get::<Interface>(binding_options.clone())
+            .ok_or_else(|| AsyncDIContainerError::BindingNotFound {
+                interface: type_name::<Interface>(),
+                name,
+            })?
+            .fork(SingletonForkPolicy::Share);
+
+        if self
+            .binding_storage
+            .has::<Interface>(binding_options.clone())
+        {
+            return Err(AsyncDIContainerError::BindingConflict {
+                interface: type_name::<Interface>(),
+                name,
+            });
+        }
+
+        self.binding_storage
+            .set::<Interface>(binding_options, provider);
+
+        Ok(())
+    }
+
     async fn handle_binding_providable<Interface>(
         &self,
         binding_providable: AsyncProvidable<Self>,
@@ -323,31 +1058,9 @@ impl AsyncDIContainer
                     }
                 })?,
             )),
-            AsyncProvidable::Singleton(singleton_binding) => {
-                Ok(SomePtr::ThreadsafeSingleton(
-                    singleton_binding
-                        .cast::<Interface>()
-                        .map_err(|err| match err {
-                            CastError::NotArcCastable(_) => {
-                                AsyncDIContainerError::InterfaceNotAsync(type_name::<
-                                    Interface,
-                                >(
-                                ))
-                            }
-                            CastError::CastFailed {
-                                source: _,
-                                from: _,
-                                to: _,
-                            }
-                            | CastError::GetCasterFailed(_) => {
-                                AsyncDIContainerError::CastFailed {
-                                    interface: type_name::<Interface>(),
-                                    binding_kind: "singleton",
-                                }
-                            }
-                        })?,
-                ))
-            }
+            AsyncProvidable::Singleton(singleton_binding) => Ok(
+                SomePtr::ThreadsafeSingleton(Self::cast_singleton(singleton_binding)?),
+            ),
             #[cfg(feature = "factory")]
             AsyncProvidable::Function(func_bound, ProvidableFunctionKind::UserCalled) => {
                 let factory = func_bound
@@ -397,6 +1110,45 @@ impl AsyncDIContainer
                     async_dynamic_value_func.call(self)().await,
                 ))
             }
+            #[cfg(feature = "factory")]
+            AsyncProvidable::Function(
+                func_bound,
+                ProvidableFunctionKind::FallibleUserCalled,
+            ) => self
+                .call_fallible_function::<Interface>(
+                    func_bound.as_ref(),
+                    "fallible factory",
+                )
+                .map(|factory| SomePtr::ThreadsafeFactory(factory.into())),
+            AsyncProvidable::Function(
+                func_bound,
+                ProvidableFunctionKind::FallibleInstant,
+            ) => self
+                .call_fallible_function(
+                    func_bound.as_ref(),
+                    "fallible dynamic value function",
+                )
+                .map(SomePtr::Transient),
+            #[cfg(feature = "testing")]
+            AsyncProvidable::Instance(instance) => instance
+                .downcast_ref::<ThreadsafeSingletonPtr<Interface>>()
+                .map(|instance| SomePtr::ThreadsafeSingleton(instance.clone()))
+                .ok_or_else(|| AsyncDIContainerError::CastFailed {
+                    interface: type_name::<Interface>(),
+                    binding_kind: "instance",
+                }),
+            #[cfg(feature = "testing")]
+            AsyncProvidable::TransientInstance(func) => {
+                type Func<Interface> =
+                    Box<dyn Fn() -> TransientPtr<Interface> + Send + Sync>;
+
+                func.downcast_ref::<Func<Interface>>()
+                    .map(|func| SomePtr::Transient(func()))
+                    .ok_or_else(|| AsyncDIContainerError::CastFailed {
+                        interface: type_name::<Interface>(),
+                        binding_kind: "transient instance",
+                    })
+            }
         }
     }
 
@@ -430,6 +1182,83 @@ impl AsyncDIContainer
                 interface: type_name::<Interface>(),
             })
     }
+
+    fn cast_singleton<Interface>(
+        singleton_binding: ThreadsafeSingletonPtr<dyn AsyncInjectable<Self>>,
+    ) -> Result<ThreadsafeSingletonPtr<Interface>, AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        singleton_binding
+            .cast::<Interface>()
+            .map_err(|err| match err {
+                CastError::NotArcCastable(_) => {
+                    AsyncDIContainerError::InterfaceNotAsync(type_name::<Interface>())
+                }
+                CastError::CastFailed {
+                    source: _,
+                    from: _,
+                    to: _,
+                }
+                | CastError::GetCasterFailed(_) => AsyncDIContainerError::CastFailed {
+                    interface: type_name::<Interface>(),
+                    binding_kind: "singleton",
+                },
+            })
+    }
+
+    fn call_fallible_function<Interface>(
+        &self,
+        func_bound: &dyn AnyThreadsafeCastableFunction,
+        binding_kind: &'static str,
+    ) -> Result<TransientPtr<Interface>, AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        let fallible_func = func_bound
+            .as_any()
+            .downcast_ref::<ThreadsafeFallibleCastableFunction<Interface, Self>>()
+            .ok_or_else(|| AsyncDIContainerError::CastFailed {
+                interface: type_name::<Interface>(),
+                binding_kind,
+            })?;
+
+        fallible_func.call(self).map_err(|err| {
+            AsyncDIContainerError::BindingResolveFailed {
+                reason: InjectableError::FunctionFailed {
+                    reason: err,
+                    interface: type_name::<Interface>(),
+                },
+                interface: type_name::<Interface>(),
+            }
+        })
+    }
+}
+
+impl AsyncDependencyResolver for AsyncDIContainer
+{
+    fn get_bound<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+        binding_options: BindingOptions<'static>,
+    ) -> BoxFuture<'_, Result<SomePtr<Interface>, AsyncDIContainerError>>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        Box::pin(AsyncDIContainer::get_bound(
+            self,
+            dependency_history,
+            binding_options,
+        ))
+    }
+
+    fn container_ref(&self) -> Result<AsyncContainerRef, AsyncDIContainerError>
+    {
+        self.self_ref
+            .clone()
+            .map(AsyncContainerRef::new)
+            .ok_or(AsyncDIContainerError::ContainerNotShared)
+    }
 }
 
 #[cfg(test)]
@@ -440,6 +1269,50 @@ mod tests
     use crate::test_utils::mocks::async_provider::MockAsyncProvider;
     use crate::test_utils::subjects_async;
 
+    #[tokio::test]
+    async fn can_get_metrics()
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        let mut mock_provider = MockAsyncProvider::new();
+
+        mock_provider.expect_do_clone().returning(|| {
+            let mut inner_mock_provider = MockAsyncProvider::new();
+
+            inner_mock_provider.expect_provide().returning(|_, _| {
+                Ok(AsyncProvidable::Transient(TransientPtr::new(
+                    subjects_async::UserManager::new(),
+                )))
+            });
+
+            Box::new(inner_mock_provider)
+        });
+
+        di_container
+            .binding_storage
+            .set::<dyn subjects_async::IUserManager>(
+                BindingOptions::new(),
+                Box::new(mock_provider),
+            );
+
+        for _ in 0..3 {
+            di_container
+                .get::<dyn subjects_async::IUserManager>()
+                .await
+                .unwrap();
+        }
+
+        let metrics = di_container.metrics();
+
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics[0].resolution_count(), 3);
+        assert_eq!(metrics[0].failure_count(), 0);
+
+        di_container.reset_metrics();
+
+        assert!(di_container.metrics().is_empty());
+    }
+
     #[tokio::test]
     async fn can_get()
     {
@@ -612,13 +1485,16 @@ mod tests
     {
         trait IUserManager: Send + Sync
         {
+            #[allow(dead_code)] // Never called by the test
             fn add_user(&mut self, user_id: i128);
 
+            #[allow(dead_code)] // Never called by the test
             fn remove_user(&mut self, user_id: i128);
         }
 
         struct UserManager
         {
+            #[allow(dead_code)] // Never read by the test
             users: Vec<i128>,
         }
 
@@ -696,13 +1572,16 @@ mod tests
     {
         trait IUserManager: Send + Sync
         {
+            #[allow(dead_code)] // Never called by the test
             fn add_user(&mut self, user_id: i128);
 
+            #[allow(dead_code)] // Never called by the test
             fn remove_user(&mut self, user_id: i128);
         }
 
         struct UserManager
         {
+            #[allow(dead_code)] // Never read by the test
             users: Vec<i128>,
         }
 
@@ -775,6 +1654,165 @@ mod tests
             .unwrap();
     }
 
+    #[tokio::test]
+    #[cfg(feature = "factory")]
+    async fn can_get_fallible_factory()
+    {
+        use std::sync::Arc;
+
+        use crate::castable_function::threadsafe::fallible::ThreadsafeFallibleCastableFunction;
+        use crate::provider::r#async::ProvidableFunctionKind;
+
+        type INumberFactory =
+            dyn Fn(i32) -> TransientPtr<dyn subjects_async::INumber> + Send + Sync;
+
+        let mut di_container = AsyncDIContainer::new();
+
+        let mut mock_provider = MockAsyncProvider::new();
+
+        mock_provider.expect_do_clone().returning(|| {
+            let mut inner_mock_provider = MockAsyncProvider::new();
+
+            inner_mock_provider.expect_provide().returning(|_, _| {
+                Ok(AsyncProvidable::Function(
+                    Arc::new(ThreadsafeFallibleCastableFunction::new(
+                        &|_: &AsyncDIContainer| {
+                            Ok::<Box<INumberFactory>, std::fmt::Error>(Box::new(|num| {
+                                let mut number = subjects_async::Number::new();
+
+                                number.num = num;
+
+                                TransientPtr::new(number)
+                                    as TransientPtr<dyn subjects_async::INumber>
+                            }))
+                        },
+                    )),
+                    ProvidableFunctionKind::FallibleUserCalled,
+                ))
+            });
+
+            Box::new(inner_mock_provider)
+        });
+
+        di_container
+            .binding_storage
+            .set::<INumberFactory>(BindingOptions::new(), Box::new(mock_provider));
+
+        let number_factory = di_container
+            .get::<INumberFactory>()
+            .await
+            .unwrap()
+            .threadsafe_factory()
+            .unwrap();
+
+        assert_eq!(number_factory(32).get(), 32);
+    }
+
+    #[tokio::test]
+    async fn can_get_fallible_dynamic_value()
+    {
+        use std::sync::Arc;
+
+        use crate::castable_function::threadsafe::fallible::ThreadsafeFallibleCastableFunction;
+        use crate::provider::r#async::ProvidableFunctionKind;
+
+        let mut di_container = AsyncDIContainer::new();
+
+        let mut mock_provider = MockAsyncProvider::new();
+
+        mock_provider.expect_do_clone().returning(|| {
+            let mut inner_mock_provider = MockAsyncProvider::new();
+
+            inner_mock_provider.expect_provide().returning(|_, _| {
+                Ok(AsyncProvidable::Function(
+                    Arc::new(ThreadsafeFallibleCastableFunction::new(
+                        &|_: &AsyncDIContainer| {
+                            Ok::<_, std::fmt::Error>(TransientPtr::new(
+                                subjects_async::UserManager::new(),
+                            )
+                                as TransientPtr<dyn subjects_async::IUserManager>)
+                        },
+                    )),
+                    ProvidableFunctionKind::FallibleInstant,
+                ))
+            });
+
+            Box::new(inner_mock_provider)
+        });
+
+        di_container
+            .binding_storage
+            .set::<dyn subjects_async::IUserManager>(
+                BindingOptions::new(),
+                Box::new(mock_provider),
+            );
+
+        di_container
+            .get::<dyn subjects_async::IUserManager>()
+            .await
+            .unwrap()
+            .transient()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn get_fallible_dynamic_value_returns_function_error()
+    {
+        use std::error::Error;
+        use std::sync::Arc;
+
+        use crate::castable_function::threadsafe::fallible::ThreadsafeFallibleCastableFunction;
+        use crate::provider::r#async::ProvidableFunctionKind;
+
+        let mut di_container = AsyncDIContainer::new();
+
+        let mut mock_provider = MockAsyncProvider::new();
+
+        mock_provider.expect_do_clone().returning(|| {
+            let mut inner_mock_provider = MockAsyncProvider::new();
+
+            inner_mock_provider.expect_provide().returning(|_, _| {
+                Ok(AsyncProvidable::Function(
+                    Arc::new(ThreadsafeFallibleCastableFunction::new(
+                        &|_: &AsyncDIContainer| {
+                            Err::<TransientPtr<dyn subjects_async::INumber>, _>(
+                                std::fmt::Error,
+                            )
+                        },
+                    )),
+                    ProvidableFunctionKind::FallibleInstant,
+                ))
+            });
+
+            Box::new(inner_mock_provider)
+        });
+
+        di_container
+            .binding_storage
+            .set::<dyn subjects_async::INumber>(
+                BindingOptions::new(),
+                Box::new(mock_provider),
+            );
+
+        let err = di_container
+            .get::<dyn subjects_async::INumber>()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            AsyncDIContainerError::BindingResolveFailed {
+                reason: InjectableError::FunctionFailed { .. },
+                interface
+            } if interface.ends_with("INumber")
+        ));
+
+        assert!(err
+            .source()
+            .and_then(Error::source)
+            .map_or_else(|| false, <dyn Error>::is::<std::fmt::Error>));
+    }
+
     #[tokio::test]
     async fn has_binding_works()
     {
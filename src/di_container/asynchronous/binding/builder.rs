@@ -3,6 +3,7 @@ use std::any::type_name;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+use crate::castable_function::threadsafe::fallible::ThreadsafeFallibleCastableFunction;
 use crate::castable_function::threadsafe::ThreadsafeCastableFunction;
 use crate::di_container::asynchronous::binding::scope_configurator::AsyncBindingScopeConfigurator;
 use crate::di_container::asynchronous::binding::when_configurator::AsyncBindingWhenConfigurator;
@@ -20,7 +21,7 @@ use_double!(crate::di_container::asynchronous::AsyncDIContainer);
 /// Alias for a threadsafe boxed function.
 #[cfg(feature = "factory")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
-pub type BoxFn<Args, Return> = Box<(dyn Fn<Args, Output = Return> + Send + Sync)>;
+pub type BoxFn<Args, Return> = Box<dyn Fn<Args, Output = Return> + Send + Sync>;
 
 /// Binding builder for type `Interface` inside a [`AsyncDIContainer`].
 #[must_use = "No binding will be created if you don't use the binding builder"]
@@ -205,6 +206,102 @@ where
         Ok(AsyncBindingWhenConfigurator::new(self.di_container))
     }
 
+    /// Creates a binding of factory type `Interface` to a fallible factory inside of the
+    /// associated [`AsyncDIContainer`].
+    ///
+    /// Unlike [`to_factory`], the given function may fail. The error is returned as
+    /// the source of a [`AsyncDIContainerError::BindingResolveFailed`] when the
+    /// factory is resolved.
+    ///
+    /// # Errors
+    /// Will return Err if the associated [`AsyncDIContainer`] already have a binding
+    /// for the interface.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # use syrette::AsyncDIContainer;
+    /// # use syrette::ptr::TransientPtr;
+    /// #
+    /// # trait Foo: Send + Sync {}
+    /// #
+    /// # struct Bar
+    /// # {
+    /// #   num: i32,
+    /// #   some_str: String
+    /// # }
+    /// #
+    /// # impl Foo for Bar {}
+    /// #
+    /// # type FooFactory = dyn Fn(i32) -> TransientPtr<dyn Foo> + Send + Sync;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>>
+    /// # {
+    /// # let mut di_container = AsyncDIContainer::new();
+    /// #
+    /// di_container
+    ///     .bind::<FooFactory>()
+    ///     .to_fallible_factory(&|_| {
+    ///         let some_str = std::env::var("SOME_STR")?;
+    ///
+    ///         Ok::<_, std::env::VarError>(Box::new(move |num| {
+    ///             let bar = TransientPtr::new(Bar {
+    ///                 num,
+    ///                 some_str: some_str.clone(),
+    ///             });
+    ///
+    ///             bar as TransientPtr<dyn Foo>
+    ///         }))
+    ///     })?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_factory`]: Self::to_factory
+    /// [`AsyncDIContainerError::BindingResolveFailed`]: crate::errors::async_di_container::AsyncDIContainerError::BindingResolveFailed
+    #[cfg(feature = "factory")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
+    pub fn to_fallible_factory<Args, Return, FactoryFunc, FuncError>(
+        self,
+        factory_func: &'static FactoryFunc,
+    ) -> Result<
+        AsyncBindingWhenConfigurator<'di_container, Interface>,
+        AsyncBindingBuilderError,
+    >
+    where
+        Args: std::marker::Tuple + 'static,
+        Return: 'static + ?Sized,
+        Interface: Fn<Args, Output = Return> + Send + Sync,
+        FactoryFunc:
+            Fn(&AsyncDIContainer) -> Result<BoxFn<Args, Return>, FuncError> + Send + Sync,
+        FuncError: std::error::Error + Send + Sync + 'static,
+    {
+        if self
+            .di_container
+            .has_binding::<Interface>(BindingOptions::new())
+        {
+            return Err(AsyncBindingBuilderError::BindingAlreadyExists(type_name::<
+                Interface,
+            >(
+            )));
+        }
+
+        let factory_impl = ThreadsafeFallibleCastableFunction::new(factory_func);
+
+        self.di_container.set_binding::<Interface>(
+            BindingOptions::new(),
+            Box::new(AsyncFunctionProvider::new(
+                Arc::new(factory_impl),
+                ProvidableFunctionKind::FallibleUserCalled,
+            )),
+        );
+
+        Ok(AsyncBindingWhenConfigurator::new(self.di_container))
+    }
+
     /// Creates a binding of factory type `Interface` to a async factory inside of the
     /// associated [`AsyncDIContainer`].
     ///
@@ -466,6 +563,92 @@ where
 
         Ok(AsyncBindingWhenConfigurator::new(self.di_container))
     }
+
+    /// Creates a binding of type `Interface` to a value resolved using the given
+    /// fallible function.
+    ///
+    /// The function is called every time the binding is resolved. If it fails, the
+    /// error is returned as the source of a
+    /// [`AsyncDIContainerError::BindingResolveFailed`].
+    ///
+    /// # Errors
+    /// Will return Err if the associated [`AsyncDIContainer`] already have a binding
+    /// for the interface.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// # use std::num::ParseIntError;
+    /// #
+    /// # use syrette::AsyncDIContainer;
+    /// # use syrette::ptr::TransientPtr;
+    /// #
+    /// # trait Foo: Send + Sync {}
+    /// #
+    /// # struct Bar
+    /// # {
+    /// #   num: i32,
+    /// #   some_str: String
+    /// # }
+    /// #
+    /// # impl Foo for Bar {}
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>>
+    /// # {
+    /// # let mut di_container = AsyncDIContainer::new();
+    /// #
+    /// di_container
+    ///     .bind::<dyn Foo>()
+    ///     .to_fallible_dynamic_value(&|_| {
+    ///         let bar = TransientPtr::new(Bar {
+    ///             num: "42".parse()?,
+    ///             some_str: "hello".to_string(),
+    ///         });
+    ///
+    ///         Ok::<_, ParseIntError>(bar as TransientPtr<dyn Foo>)
+    ///     })?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`AsyncDIContainerError::BindingResolveFailed`]: crate::errors::async_di_container::AsyncDIContainerError::BindingResolveFailed
+    pub fn to_fallible_dynamic_value<Func, FuncError>(
+        self,
+        func: &'static Func,
+    ) -> Result<
+        AsyncBindingWhenConfigurator<'di_container, Interface>,
+        AsyncBindingBuilderError,
+    >
+    where
+        Func: Fn(&AsyncDIContainer) -> Result<TransientPtr<Interface>, FuncError>
+            + Send
+            + Sync,
+        FuncError: std::error::Error + Send + Sync + 'static,
+    {
+        if self
+            .di_container
+            .has_binding::<Interface>(BindingOptions::new())
+        {
+            return Err(AsyncBindingBuilderError::BindingAlreadyExists(type_name::<
+                Interface,
+            >(
+            )));
+        }
+
+        let castable_func = ThreadsafeFallibleCastableFunction::new(func);
+
+        self.di_container.set_binding::<Interface>(
+            BindingOptions::new(),
+            Box::new(AsyncFunctionProvider::new(
+                Arc::new(castable_func),
+                ProvidableFunctionKind::FallibleInstant,
+            )),
+        );
+
+        Ok(AsyncBindingWhenConfigurator::new(self.di_container))
+    }
 }
 
 #[cfg(test)]
@@ -668,4 +851,79 @@ mod tests
             })
             .unwrap();
     }
+    #[tokio::test]
+    #[cfg(feature = "factory")]
+    async fn can_bind_to_fallible_factory()
+    {
+        use crate::ptr::TransientPtr;
+
+        type IUserManagerFactory = dyn Fn(String) -> TransientPtr<dyn subjects_async::IUserManager>
+            + Send
+            + Sync;
+
+        let mut di_container_mock = MockAsyncDIContainer::new();
+
+        di_container_mock
+            .expect_has_binding::<IUserManagerFactory>()
+            .with(eq(BindingOptions::new()))
+            .return_once(|_name| false)
+            .once();
+
+        di_container_mock
+            .expect_set_binding::<IUserManagerFactory>()
+            .withf(|binding_options, _provider| binding_options.name.is_none())
+            .return_once(|_name, _provider| ())
+            .once();
+
+        let binding_builder = AsyncBindingBuilder::<IUserManagerFactory>::new(
+            &mut di_container_mock,
+            MockDependencyHistory::new,
+        );
+
+        binding_builder
+            .to_fallible_factory(&|_| {
+                Ok::<BoxFn<_, _>, std::fmt::Error>(Box::new(|_text| {
+                    let user_manager: TransientPtr<dyn subjects_async::IUserManager> =
+                        TransientPtr::new(subjects_async::UserManager::new());
+
+                    user_manager
+                }))
+            })
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn can_bind_to_fallible_dynamic_value()
+    {
+        use crate::ptr::TransientPtr;
+
+        let mut di_container_mock = MockAsyncDIContainer::new();
+
+        di_container_mock
+            .expect_has_binding::<dyn subjects_async::IUserManager>()
+            .with(eq(BindingOptions::new()))
+            .return_once(|_name| false)
+            .once();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects_async::IUserManager>()
+            .withf(|binding_options, _provider| binding_options.name.is_none())
+            .return_once(|_name, _provider| ())
+            .once();
+
+        let binding_builder =
+            AsyncBindingBuilder::<dyn subjects_async::IUserManager>::new(
+                &mut di_container_mock,
+                MockDependencyHistory::new,
+            );
+
+        binding_builder
+            .to_fallible_dynamic_value(&|_| {
+                let user_manager: TransientPtr<dyn subjects_async::IUserManager> =
+                    TransientPtr::new(subjects_async::UserManager::new());
+
+                Ok::<_, std::fmt::Error>(user_manager)
+            })
+            .unwrap();
+    }
 }
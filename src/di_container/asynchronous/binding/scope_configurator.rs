@@ -5,7 +5,12 @@ use crate::di_container::asynchronous::binding::when_configurator::AsyncBindingW
 use crate::di_container::BindingOptions;
 use crate::errors::async_di_container::AsyncBindingScopeConfiguratorError;
 use crate::interfaces::async_injectable::AsyncInjectable;
-use crate::provider::r#async::{AsyncSingletonProvider, AsyncTransientTypeProvider};
+use crate::provider::r#async::{
+    AsyncLazySingletonProvider,
+    AsyncSingletonProvider,
+    AsyncTaskScopedProvider,
+    AsyncTransientTypeProvider,
+};
 use crate::ptr::ThreadsafeSingletonPtr;
 use crate::util::use_double;
 
@@ -174,6 +179,119 @@ where
         Ok(AsyncBindingWhenConfigurator::new(self.di_container))
     }
 
+    /// Configures the binding to be in a singleton scope where the singleton is lazily
+    /// resolved.
+    ///
+    /// Unlike [`in_singleton_scope`], the singleton is not resolved until the binding
+    /// is first retrieved from the [`AsyncDIContainer`]. Concurrent retrievals of the
+    /// binding all wait for the same resolution of the singleton. If resolving the
+    /// singleton fails, the error is returned and the resolution is retried the next
+    /// time the binding is retrieved.
+    ///
+    /// [`in_singleton_scope`]: Self::in_singleton_scope
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # struct ConnectionPool {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl ConnectionPool
+    /// # {
+    /// #     async fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<ConnectionPool>()
+    ///     .to::<ConnectionPool>()?
+    ///     .in_lazy_singleton_scope();
+    ///
+    /// let connection_pool = di_container
+    ///     .get::<ConnectionPool>()
+    ///     .await?
+    ///     .threadsafe_singleton()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::must_use_candidate)]
+    pub fn in_lazy_singleton_scope(
+        self,
+    ) -> AsyncBindingWhenConfigurator<'di_container, Interface>
+    {
+        self.di_container.set_binding::<Interface>(
+            BindingOptions::new(),
+            Box::new(
+                AsyncLazySingletonProvider::<Implementation, AsyncDIContainer>::new(),
+            ),
+        );
+
+        AsyncBindingWhenConfigurator::new(self.di_container)
+    }
+
+    /// Configures the binding to be in a task scope.
+    ///
+    /// A single instance of the implementation is shared by everything that resolves
+    /// the binding inside of the same task scope, which is entered using
+    /// [`AsyncDIContainer::scope`]. Resolving the binding outside of a task scope
+    /// results in a error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # struct RequestContext {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl RequestContext
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<RequestContext>()
+    ///     .to::<RequestContext>()?
+    ///     .in_task_scope();
+    ///
+    /// AsyncDIContainer::scope(|| async {
+    ///     let request_context = di_container
+    ///         .get::<RequestContext>()
+    ///         .await?
+    ///         .threadsafe_singleton()?;
+    ///
+    ///     Ok::<_, Box<dyn std::error::Error>>(())
+    /// })
+    /// .await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::must_use_candidate)]
+    pub fn in_task_scope(self) -> AsyncBindingWhenConfigurator<'di_container, Interface>
+    {
+        self.di_container.set_binding::<Interface>(
+            BindingOptions::new(),
+            Box::new(AsyncTaskScopedProvider::<Implementation, AsyncDIContainer>::new()),
+        );
+
+        AsyncBindingWhenConfigurator::new(self.di_container)
+    }
+
     pub(crate) fn set_in_transient_scope(&mut self)
     {
         self.di_container.set_binding::<Interface>(
@@ -235,4 +353,44 @@ mod tests
             .await
             .is_ok());
     }
+
+    #[test]
+    fn in_lazy_singleton_scope_works()
+    {
+        let mut di_container_mock = MockAsyncDIContainer::new();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects_async::IUserManager>()
+            .withf(|binding_options, _provider| binding_options.name.is_none())
+            .return_once(|_name, _provider| ())
+            .once();
+
+        let binding_scope_configurator =
+            AsyncBindingScopeConfigurator::<
+                dyn subjects_async::IUserManager,
+                subjects_async::UserManager,
+            >::new(&mut di_container_mock, MockDependencyHistory::new);
+
+        binding_scope_configurator.in_lazy_singleton_scope();
+    }
+
+    #[test]
+    fn in_task_scope_works()
+    {
+        let mut di_container_mock = MockAsyncDIContainer::new();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects_async::IUserManager>()
+            .withf(|binding_options, _provider| binding_options.name.is_none())
+            .return_once(|_name, _provider| ())
+            .once();
+
+        let binding_scope_configurator =
+            AsyncBindingScopeConfigurator::<
+                dyn subjects_async::IUserManager,
+                subjects_async::UserManager,
+            >::new(&mut di_container_mock, MockDependencyHistory::new);
+
+        binding_scope_configurator.in_task_scope();
+    }
 }
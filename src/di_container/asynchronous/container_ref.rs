@@ -0,0 +1,172 @@
+//! Reference to a shared [`AsyncDIContainer`].
+//!
+//! [`AsyncDIContainer`]: crate::di_container::asynchronous::AsyncDIContainer
+use std::sync::{Arc, Weak};
+
+use crate::di_container::asynchronous::AsyncDIContainer;
+use crate::errors::async_di_container::AsyncDIContainerError;
+use crate::ptr::SomePtr;
+
+/// Weak reference to a [`AsyncDIContainer`] that has been moved into a `Arc` with
+/// [`AsyncDIContainer::into_shared`].
+///
+/// Can be given as a constructor argument of a async [`injectable`] to resolve
+/// bindings after construction. The reference is weak since the DI container owns it's
+/// singletons, which would otherwise keep the DI container alive. Singletons holding a
+/// `AsyncContainerRef` must be bound in a lazy singleton scope.
+///
+/// # Examples
+/// ```
+/// # use syrette::di_container::asynchronous::container_ref::AsyncContainerRef;
+/// # use syrette::{injectable, AsyncDIContainer};
+/// #
+/// trait IPlugin: Send + Sync
+/// {
+///     fn name(&self) -> &'static str;
+/// }
+///
+/// struct GreeterPlugin {}
+///
+/// #[injectable(IPlugin, async = true)]
+/// impl GreeterPlugin
+/// {
+///     fn new() -> Self
+///     {
+///         Self {}
+///     }
+/// }
+///
+/// impl IPlugin for GreeterPlugin
+/// {
+///     fn name(&self) -> &'static str
+///     {
+///         "greeter"
+///     }
+/// }
+///
+/// struct PluginHost
+/// {
+///     container_ref: AsyncContainerRef,
+/// }
+///
+/// #[injectable(async = true)]
+/// impl PluginHost
+/// {
+///     fn new(container_ref: AsyncContainerRef) -> Self
+///     {
+///         Self { container_ref }
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut di_container = AsyncDIContainer::new();
+///
+/// di_container
+///     .bind::<dyn IPlugin>()
+///     .to::<GreeterPlugin>()?
+///     .in_transient_scope()
+///     .when_named("greeter")?;
+///
+/// di_container
+///     .bind::<PluginHost>()
+///     .to::<PluginHost>()?
+///     .in_transient_scope();
+///
+/// let di_container = di_container.into_shared();
+///
+/// let plugin_host = di_container.get::<PluginHost>().await?.transient()?;
+///
+/// let plugin = plugin_host
+///     .container_ref
+///     .get_named::<dyn IPlugin>("greeter")
+///     .await?
+///     .transient()?;
+///
+/// assert_eq!(plugin.name(), "greeter");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`injectable`]: crate::injectable
+#[derive(Debug, Clone)]
+pub struct AsyncContainerRef
+{
+    di_container: Weak<AsyncDIContainer>,
+}
+
+impl AsyncContainerRef
+{
+    pub(crate) fn new(di_container: Weak<AsyncDIContainer>) -> Self
+    {
+        Self { di_container }
+    }
+
+    /// Returns the referenced DI container.
+    ///
+    /// # Errors
+    /// Will return `Err` if the DI container has been dropped.
+    pub fn upgrade(&self) -> Result<Arc<AsyncDIContainer>, AsyncDIContainerError>
+    {
+        self.di_container
+            .upgrade()
+            .ok_or(AsyncDIContainerError::ContainerDropped)
+    }
+
+    /// Returns the type bound with `Interface` in the referenced DI container.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - The DI container has been dropped
+    /// - Resolving the binding for `Interface` fails. See [`AsyncDIContainer::get`]
+    pub async fn get<Interface>(
+        &self,
+    ) -> Result<SomePtr<Interface>, AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.upgrade()?.get::<Interface>().await
+    }
+
+    /// Returns the type bound with `Interface` and the specified name in the
+    /// referenced DI container.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - The DI container has been dropped
+    /// - Resolving the binding for `Interface` fails. See [`AsyncDIContainer::get_named`]
+    pub async fn get_named<Interface>(
+        &self,
+        name: &'static str,
+    ) -> Result<SomePtr<Interface>, AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.upgrade()?.get_named::<Interface>(name).await
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::test_utils::subjects_async;
+
+    #[tokio::test]
+    async fn get_fails_when_container_is_dropped()
+    {
+        let di_container = AsyncDIContainer::new().into_shared();
+
+        let container_ref = AsyncContainerRef::new(Arc::downgrade(&di_container));
+
+        drop(di_container);
+
+        assert!(matches!(
+            container_ref
+                .get::<dyn subjects_async::IUserManager>()
+                .await,
+            Err(AsyncDIContainerError::ContainerDropped)
+        ));
+    }
+}
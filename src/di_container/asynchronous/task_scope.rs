@@ -0,0 +1,154 @@
+//! Task scopes for a [`AsyncDIContainer`].
+//!
+//! A task scope is entered using [`AsyncDIContainer::scope`]. All task scoped bindings
+//! resolved inside of the scope share the same instances.
+//!
+//! [`AsyncDIContainer`]: crate::di_container::asynchronous::AsyncDIContainer
+//! [`AsyncDIContainer::scope`]: crate::di_container::asynchronous::AsyncDIContainer::scope
+use std::any::Any;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll};
+
+use hashbrown::HashMap;
+use tokio::sync::OnceCell;
+use tokio::task::futures::TaskLocalFuture;
+
+tokio::task_local! {
+    static CURRENT_TASK_SCOPE: Arc<TaskScope>;
+}
+
+/// A future that runs inside of a task scope.
+///
+/// Returned by [`AsyncDIContainer::scope`].
+///
+/// [`AsyncDIContainer::scope`]: crate::di_container::asynchronous::AsyncDIContainer::scope
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TaskScoped<Fut>
+{
+    future: Pin<Box<TaskLocalFuture<Arc<TaskScope>, Fut>>>,
+}
+
+impl<Fut> TaskScoped<Fut>
+where
+    Fut: Future,
+{
+    pub(crate) fn new<Func>(func: Func) -> Self
+    where
+        Func: FnOnce() -> Fut,
+    {
+        let task_scope = Arc::new(TaskScope::default());
+
+        let future = CURRENT_TASK_SCOPE.sync_scope(task_scope.clone(), func);
+
+        Self {
+            future: Box::pin(CURRENT_TASK_SCOPE.scope(task_scope, future)),
+        }
+    }
+}
+
+impl<Fut> Future for TaskScoped<Fut>
+where
+    Fut: Future,
+{
+    type Output = Fut::Output;
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output>
+    {
+        self.future.as_mut().poll(context)
+    }
+}
+
+/// The instances of the task scoped bindings resolved inside of a task scope.
+#[derive(Default)]
+pub(crate) struct TaskScope
+{
+    instances: Mutex<HashMap<usize, Arc<dyn Any + Send + Sync>>>,
+}
+
+impl TaskScope
+{
+    /// Returns the task scope that is currently entered by this task.
+    pub fn current() -> Option<Arc<Self>>
+    {
+        CURRENT_TASK_SCOPE.try_with(Arc::clone).ok()
+    }
+
+    /// Returns the cell holding the instance of the binding with the given id.
+    pub fn instance_cell<Instance>(&self, binding_id: usize) -> Arc<OnceCell<Instance>>
+    where
+        Instance: Send + Sync + 'static,
+    {
+        let instance_cell = self
+            .instances
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(binding_id)
+            .or_insert_with(|| Arc::new(OnceCell::<Instance>::new()))
+            .clone();
+
+        instance_cell
+            .downcast::<OnceCell<Instance>>()
+            .expect("A binding id should only ever be used for a single instance type")
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[tokio::test]
+    async fn task_scope_is_current_inside_of_future()
+    {
+        assert!(TaskScope::current().is_none());
+
+        let (task_scope_a, task_scope_b) = TaskScoped::new(|| async {
+            let task_scope_a = TaskScope::current().unwrap();
+
+            tokio::task::yield_now().await;
+
+            (task_scope_a, TaskScope::current().unwrap())
+        })
+        .await;
+
+        assert!(Arc::ptr_eq(&task_scope_a, &task_scope_b));
+
+        assert!(TaskScope::current().is_none());
+    }
+
+    #[tokio::test]
+    async fn nested_task_scopes_are_restored()
+    {
+        TaskScoped::new(|| async {
+            let outer_task_scope = TaskScope::current().unwrap();
+
+            let inner_task_scope =
+                TaskScoped::new(|| async { TaskScope::current().unwrap() }).await;
+
+            assert!(!Arc::ptr_eq(&outer_task_scope, &inner_task_scope));
+
+            assert!(Arc::ptr_eq(
+                &outer_task_scope,
+                &TaskScope::current().unwrap()
+            ));
+        })
+        .await;
+    }
+
+    #[test]
+    fn instance_cell_is_shared()
+    {
+        let task_scope = TaskScope::default();
+
+        let instance_cell_a = task_scope.instance_cell::<u32>(3);
+        let instance_cell_b = task_scope.instance_cell::<u32>(3);
+
+        assert!(Arc::ptr_eq(&instance_cell_a, &instance_cell_b));
+
+        let other_instance_cell = task_scope.instance_cell::<u32>(4);
+
+        assert!(!Arc::ptr_eq(&instance_cell_a, &other_instance_cell));
+    }
+}
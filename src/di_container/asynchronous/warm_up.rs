@@ -0,0 +1,199 @@
+//! Warm-up of lazy singletons in a [`AsyncDIContainer`].
+//!
+//! [`AsyncDIContainer`]: crate::di_container::asynchronous::AsyncDIContainer
+use std::time::Duration;
+
+use crate::errors::injectable::InjectableError;
+
+/// Report of a [`AsyncDIContainer::warm_up`].
+///
+/// [`AsyncDIContainer::warm_up`]: crate::di_container::asynchronous::AsyncDIContainer::warm_up
+#[derive(Debug)]
+pub struct WarmUpReport
+{
+    entries: Vec<WarmUpEntry>,
+    total_time: Duration,
+}
+
+impl WarmUpReport
+{
+    pub(crate) fn new(entries: Vec<WarmUpEntry>, total_time: Duration) -> Self
+    {
+        Self {
+            entries,
+            total_time,
+        }
+    }
+
+    /// Returns a entry for every lazy singleton, ordered by stage.
+    #[must_use]
+    pub fn entries(&self) -> &[WarmUpEntry]
+    {
+        &self.entries
+    }
+
+    /// Returns the entries of the lazy singletons that failed to be initialized.
+    pub fn failures(&self) -> impl Iterator<Item = &WarmUpEntry>
+    {
+        self.entries.iter().filter(|entry| entry.error.is_some())
+    }
+
+    /// Returns whether or not all of the lazy singletons were successfully
+    /// initialized.
+    #[must_use]
+    pub fn is_success(&self) -> bool
+    {
+        self.failures().next().is_none()
+    }
+
+    /// Returns the time the whole warm-up took.
+    #[must_use]
+    pub fn total_time(&self) -> Duration
+    {
+        self.total_time
+    }
+}
+
+/// The result of initializing a single lazy singleton during a warm-up.
+#[derive(Debug)]
+pub struct WarmUpEntry
+{
+    pub(crate) implementation: &'static str,
+    pub(crate) binding_name: Option<&'static str>,
+    pub(crate) stage: usize,
+    pub(crate) elapsed: Duration,
+    pub(crate) error: Option<InjectableError>,
+}
+
+impl WarmUpEntry
+{
+    /// Returns the name of the implementation of the lazy singleton.
+    #[must_use]
+    pub fn implementation(&self) -> &'static str
+    {
+        self.implementation
+    }
+
+    /// Returns the name of the binding of the lazy singleton.
+    #[must_use]
+    pub fn binding_name(&self) -> Option<&'static str>
+    {
+        self.binding_name
+    }
+
+    /// Returns the stage in which the lazy singleton was initialized.
+    ///
+    /// Lazy singletons in the same stage are initialized concurrently. A lazy singleton
+    /// is always initialized in a later stage than the lazy singletons it depends on.
+    ///
+    /// Lazy singletons that are part of or depend on a dependency cycle are not
+    /// initialized and are put in a stage after all of the others.
+    #[must_use]
+    pub fn stage(&self) -> usize
+    {
+        self.stage
+    }
+
+    /// Returns the time it took to initialize the lazy singleton.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration
+    {
+        self.elapsed
+    }
+
+    /// Returns the error that occurred when initializing the lazy singleton, if any.
+    #[must_use]
+    pub fn error(&self) -> Option<&InjectableError>
+    {
+        self.error.as_ref()
+    }
+}
+
+/// Orders nodes into stages where every node is in a later stage than the nodes it
+/// depends on.
+///
+/// `dependencies[index]` is the indices of the nodes that node `index` depends on.
+/// Nodes that are part of or depend on a dependency cycle can't be ordered and are
+/// returned separately from the stages.
+pub(crate) fn order_in_stages(
+    dependencies: &[Vec<usize>],
+) -> (Vec<Vec<usize>>, Vec<usize>)
+{
+    let mut remaining_dep_cnts = dependencies.iter().map(Vec::len).collect::<Vec<_>>();
+
+    let mut dependents = vec![Vec::new(); dependencies.len()];
+
+    for (index, node_dependencies) in dependencies.iter().enumerate() {
+        for dependency in node_dependencies {
+            dependents[*dependency].push(index);
+        }
+    }
+
+    let mut is_staged = vec![false; dependencies.len()];
+
+    let mut stages = Vec::new();
+
+    let mut current_stage = (0..dependencies.len())
+        .filter(|index| remaining_dep_cnts[*index] == 0)
+        .collect::<Vec<_>>();
+
+    while !current_stage.is_empty() {
+        let mut next_stage = Vec::new();
+
+        for index in &current_stage {
+            is_staged[*index] = true;
+
+            for dependent in &dependents[*index] {
+                remaining_dep_cnts[*dependent] -= 1;
+
+                if remaining_dep_cnts[*dependent] == 0 {
+                    next_stage.push(*dependent);
+                }
+            }
+        }
+
+        stages.push(current_stage);
+
+        current_stage = next_stage;
+    }
+
+    let cyclic_nodes = (0..dependencies.len())
+        .filter(|index| !is_staged[*index])
+        .collect::<Vec<_>>();
+
+    (stages, cyclic_nodes)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn order_in_stages_works()
+    {
+        // 0 -> 1 -> 3
+        // 2 -> 3
+        // 4
+        let dependencies = vec![vec![1], vec![3], vec![3], vec![], vec![]];
+
+        assert_eq!(
+            order_in_stages(&dependencies),
+            (vec![vec![3, 4], vec![1, 2], vec![0]], vec![])
+        );
+    }
+
+    #[test]
+    fn order_in_stages_separates_cycles()
+    {
+        // 0 -> 1 -> 0
+        // 2
+        // 3 -> 0
+        let dependencies = vec![vec![1], vec![0], vec![], vec![0]];
+
+        assert_eq!(
+            order_in_stages(&dependencies),
+            (vec![vec![2]], vec![0, 1, 3])
+        );
+    }
+}
@@ -1,6 +1,7 @@
-use std::any::TypeId;
+use alloc::boxed::Box;
+use core::any::{type_name, TypeId};
 
-use ahash::AHashMap;
+use hashbrown::HashMap;
 
 use crate::di_container::BindingOptions;
 
@@ -8,7 +9,7 @@ pub struct DIContainerBindingStorage<Provider>
 where
     Provider: 'static + ?Sized,
 {
-    inner: AHashMap<BindingId<'static>, Box<Provider>>,
+    inner: HashMap<BindingId<'static>, Box<Provider>>,
 }
 
 impl<Provider> DIContainerBindingStorage<Provider>
@@ -18,7 +19,7 @@ where
     pub fn new() -> Self
     {
         Self {
-            inner: AHashMap::new(),
+            inner: HashMap::new(),
         }
     }
 
@@ -54,6 +55,59 @@ where
         self.inner.remove(&BindingId::new::<Interface>(options))
     }
 
+    /// Returns a new binding storage with the providers returned by `map_provider` for
+    /// each of the bindings.
+    pub fn map_providers<MapProviderFn>(&self, mut map_provider: MapProviderFn) -> Self
+    where
+        MapProviderFn: FnMut(&Provider) -> Box<Provider>,
+    {
+        Self {
+            inner: self
+                .inner
+                .iter()
+                .map(|(binding_id, provider)| {
+                    (binding_id.clone(), map_provider(provider))
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the interface name and binding name of a binding that exists in both
+    /// this binding storage and `other`, if any.
+    pub fn find_conflict(
+        &self,
+        other: &Self,
+    ) -> Option<(&'static str, Option<&'static str>)>
+    {
+        other
+            .inner
+            .keys()
+            .find(|binding_id| self.inner.contains_key(*binding_id))
+            .map(|binding_id| (binding_id.interface, binding_id.options.name))
+    }
+
+    /// Moves all of the bindings of `other` into this binding storage.
+    ///
+    /// Bindings that exist in both are only replaced if `overwrite` is `true`.
+    pub fn extend(&mut self, other: Self, overwrite: bool)
+    {
+        for (binding_id, provider) in other.inner {
+            if overwrite || !self.inner.contains_key(&binding_id) {
+                self.inner.insert(binding_id, provider);
+            }
+        }
+    }
+
+    #[cfg(any(feature = "async", feature = "config"))]
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (TypeId, &BindingOptions<'static>, &Box<Provider>)>
+    {
+        self.inner.iter().map(|(binding_id, provider)| {
+            (binding_id.type_id, &binding_id.options, provider)
+        })
+    }
+
     pub fn has<Interface>(&self, options: BindingOptions) -> bool
     where
         Interface: 'static + ?Sized,
@@ -61,6 +115,26 @@ where
         self.inner
             .contains_key(&BindingId::new::<Interface>(options))
     }
+
+    /// Returns whether a binding exists for the interface with the given type ID (and
+    /// optionally a name).
+    #[cfg(feature = "config")]
+    pub fn has_with_type_id(&self, type_id: TypeId, opt_name: Option<&str>) -> bool
+    {
+        self.inner.keys().any(|binding_id| {
+            binding_id.type_id == type_id && binding_id.options.name == opt_name
+        })
+    }
+
+    /// Removes the binding for the interface with the given type ID (and optionally a
+    /// name).
+    #[cfg(feature = "config")]
+    pub fn remove_with_type_id(&mut self, type_id: TypeId, opt_name: Option<&str>)
+    {
+        self.inner.retain(|binding_id, _| {
+            binding_id.type_id != type_id || binding_id.options.name != opt_name
+        });
+    }
 }
 
 impl<Provider> Default for DIContainerBindingStorage<Provider>
@@ -73,10 +147,11 @@ where
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct BindingId<'opts>
 {
     type_id: TypeId,
+    interface: &'static str,
     options: BindingOptions<'opts>,
 }
 
@@ -88,6 +163,7 @@ impl<'opts> BindingId<'opts>
     {
         Self {
             type_id: TypeId::of::<Interface>(),
+            interface: type_name::<Interface>(),
             options,
         }
     }
@@ -277,4 +353,113 @@ mod tests
 
         assert!(binding_map.has::<Interface>(BindingOptions::new().name("awesome")));
     }
+
+    #[test]
+    fn can_map_providers()
+    {
+        type Interface = ();
+
+        let mut binding_map =
+            DIContainerBindingStorage::<dyn subjects::SomeProvider>::new();
+
+        binding_map.inner.insert(
+            BindingId::new::<Interface>(BindingOptions::new().name("cool")),
+            Box::new(subjects::SomeProviderImpl { id: 7 }),
+        );
+
+        let mapped_binding_map = binding_map.map_providers(|provider| {
+            Box::new(subjects::SomeProviderImpl {
+                id: provider.get_id() * 2,
+            })
+        });
+
+        assert!(mapped_binding_map
+            .get::<Interface>(BindingOptions::new().name("cool"))
+            .map_or_else(|| false, |provider| provider.get_id() == 14));
+
+        assert!(binding_map
+            .get::<Interface>(BindingOptions::new().name("cool"))
+            .map_or_else(|| false, |provider| provider.get_id() == 7));
+    }
+
+    #[test]
+    fn can_find_conflict()
+    {
+        let mut binding_map =
+            DIContainerBindingStorage::<dyn subjects::SomeProvider>::new();
+
+        let mut other_binding_map =
+            DIContainerBindingStorage::<dyn subjects::SomeProvider>::new();
+
+        binding_map.set::<u32>(
+            BindingOptions::new().name("foo"),
+            Box::new(subjects::SomeProviderImpl { id: 1 }),
+        );
+
+        other_binding_map.set::<u32>(
+            BindingOptions::new(),
+            Box::new(subjects::SomeProviderImpl { id: 2 }),
+        );
+
+        assert!(binding_map.find_conflict(&other_binding_map).is_none());
+
+        other_binding_map.set::<u32>(
+            BindingOptions::new().name("foo"),
+            Box::new(subjects::SomeProviderImpl { id: 3 }),
+        );
+
+        assert_eq!(
+            binding_map.find_conflict(&other_binding_map),
+            Some((type_name::<u32>(), Some("foo")))
+        );
+    }
+
+    #[test]
+    fn can_extend()
+    {
+        let create_binding_maps = || {
+            let mut binding_map =
+                DIContainerBindingStorage::<dyn subjects::SomeProvider>::new();
+
+            let mut other_binding_map =
+                DIContainerBindingStorage::<dyn subjects::SomeProvider>::new();
+
+            binding_map.set::<u32>(
+                BindingOptions::new(),
+                Box::new(subjects::SomeProviderImpl { id: 1 }),
+            );
+
+            other_binding_map.set::<u32>(
+                BindingOptions::new(),
+                Box::new(subjects::SomeProviderImpl { id: 2 }),
+            );
+
+            other_binding_map.set::<u64>(
+                BindingOptions::new(),
+                Box::new(subjects::SomeProviderImpl { id: 3 }),
+            );
+
+            (binding_map, other_binding_map)
+        };
+
+        let get_id =
+            |binding_map: &DIContainerBindingStorage<dyn subjects::SomeProvider>| {
+                binding_map
+                    .get::<u32>(BindingOptions::new())
+                    .map(|provider| provider.get_id())
+            };
+
+        let (mut binding_map, other_binding_map) = create_binding_maps();
+
+        binding_map.extend(other_binding_map, false);
+
+        assert_eq!(get_id(&binding_map), Some(1));
+        assert!(binding_map.has::<u64>(BindingOptions::new()));
+
+        let (mut binding_map, other_binding_map) = create_binding_maps();
+
+        binding_map.extend(other_binding_map, true);
+
+        assert_eq!(get_id(&binding_map), Some(2));
+    }
 }
@@ -49,45 +49,806 @@
 //!     Ok(())
 //! }
 //! ```
-use std::any::type_name;
+use alloc::boxed::Box;
+use alloc::rc::{Rc, Weak};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::cell::RefCell;
+
+use crate::castable_function::fallible::FallibleCastableFunction;
+use crate::castable_function::{AnyCastableFunction, CastableFunction};
+#[cfg(feature = "config")]
+use crate::config::{
+    find_binding_config,
+    intern_binding_name,
+    resolve_binding_configs,
+    ConfigFormat,
+    ContainerConfig,
+};
+use crate::di_container::binding_storage::DIContainerBindingStorage;
+use crate::di_container::blocking::binding::builder::{
+    BindingBuilder,
+    GenericBindingBuilder,
+};
+use crate::di_container::blocking::container_ref::ContainerRef;
+use crate::di_container::listener::{ResolutionListener, ResolutionListeners};
+use crate::di_container::metrics::{BindingMetrics, MetricsStore};
+use crate::di_container::{BindingOptions, ConflictPolicy, SingletonForkPolicy};
+#[cfg(feature = "config")]
+use crate::errors::config::ConfigError;
+#[cfg(feature = "config")]
+use crate::errors::di_container::BindingScopeConfiguratorError;
+use crate::errors::di_container::DIContainerError;
+use crate::errors::injectable::InjectableError;
+use crate::interfaces::dependency_resolver::DependencyResolver;
+use crate::private::cast::boxed::CastBox;
+use crate::private::cast::rc::CastRc;
+#[cfg(feature = "config")]
+use crate::private::registry::{RegisteredImplementation, RegisteredScope};
+use crate::provider::blocking::{IProvider, Providable, ProvidableFunctionKind};
+#[cfg(feature = "testing")]
+use crate::ptr::SingletonPtr;
+use crate::ptr::{SomePtr, TransientPtr};
+#[cfg(feature = "testing")]
+use crate::testing::BindingOverrideBuilder;
+use crate::util::{use_double, Stopwatch};
+
+use_double!(crate::dependency_history::DependencyHistory);
+
+pub mod binding;
+pub mod container_ref;
+
+/// Blocking dependency injection container.
+#[derive(Default)]
+pub struct DIContainer
+{
+    binding_storage: DIContainerBindingStorage<dyn IProvider<Self>>,
+    listeners: ResolutionListeners<dyn ResolutionListener>,
+    metrics: RefCell<MetricsStore>,
+    self_ref: Option<Weak<Self>>,
+}
+
+impl DIContainer
+{
+    /// Returns a new `DIContainer`.
+    #[must_use]
+    pub fn new() -> Self
+    {
+        Self {
+            binding_storage: DIContainerBindingStorage::new(),
+            listeners: ResolutionListeners::new(),
+            metrics: RefCell::new(MetricsStore::default()),
+            self_ref: None,
+        }
+    }
+
+    /// Returns a new `DIContainer` moved into a `Rc`, making it possible for
+    /// injectables to receive a [`ContainerRef`] of it.
+    ///
+    /// The bindings are created by `configure`. Unlike with [`into_shared`], the DI
+    /// container is already shared while they are created, so singletons can depend on
+    /// a [`ContainerRef`]. Resolving through the [`ContainerRef`] fails until
+    /// `new_shared` has returned.
+    ///
+    /// # Errors
+    /// Will return Err if `configure` does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::di_container::blocking::container_ref::ContainerRef;
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # struct PluginHost
+    /// # {
+    /// #     container_ref: ContainerRef,
+    /// # }
+    /// #
+    /// # #[injectable]
+    /// # impl PluginHost
+    /// # {
+    /// #     fn new(container_ref: ContainerRef) -> Self
+    /// #     {
+    /// #         Self { container_ref }
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let di_container = DIContainer::new_shared(|di_container| {
+    ///     di_container
+    ///         .bind::<PluginHost>()
+    ///         .to::<PluginHost>()?
+    ///         .in_singleton_scope()?;
+    ///
+    ///     Ok::<_, Box<dyn std::error::Error>>(())
+    /// })?;
+    ///
+    /// let plugin_host = di_container.get::<PluginHost>()?.singleton()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`into_shared`]: Self::into_shared
+    pub fn new_shared<Configure, Error>(configure: Configure) -> Result<Rc<Self>, Error>
+    where
+        Configure: FnOnce(&mut Self) -> Result<(), Error>,
+    {
+        let mut result = Ok(());
+
+        let di_container = Rc::new_cyclic(|self_ref| {
+            let mut di_container = Self {
+                self_ref: Some(self_ref.clone()),
+                ..Self::new()
+            };
+
+            result = configure(&mut di_container);
+
+            di_container
+        });
+
+        result.map(|()| di_container)
+    }
+
+    /// Moves the DI container into a `Rc`, making it possible for injectables to
+    /// receive a [`ContainerRef`] of it.
+    ///
+    /// The DI container can't be bound to after being shared, so all bindings should be
+    /// created beforehand. Singletons depending on a [`ContainerRef`] can't be created
+    /// before the DI container is shared, use [`new_shared`] for those.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::DIContainer;
+    /// #
+    /// let di_container = DIContainer::new().into_shared();
+    ///
+    /// assert!(di_container.get::<u32>().is_err());
+    /// ```
+    ///
+    /// [`new_shared`]: Self::new_shared
+    #[must_use]
+    pub fn into_shared(self) -> Rc<Self>
+    {
+        Rc::new_cyclic(|self_ref| Self {
+            self_ref: Some(self_ref.clone()),
+            ..self
+        })
+    }
+
+    /// Returns a snapshot of the resolution metrics of all bindings that have been
+    /// resolved, sorted by interface name and binding name.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # struct Connection {}
+    /// #
+    /// # #[injectable]
+    /// # impl Connection
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container.bind::<Connection>().to::<Connection>()?;
+    ///
+    /// di_container.get::<Connection>()?;
+    /// di_container.get::<Connection>()?;
+    ///
+    /// assert_eq!(di_container.metrics()[0].resolution_count(), 2);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn metrics(&self) -> Vec<BindingMetrics>
+    {
+        self.metrics.borrow().snapshot()
+    }
+
+    /// Clears the resolution metrics of all bindings.
+    pub fn reset_metrics(&self)
+    {
+        self.metrics.borrow_mut().clear();
+    }
+
+    /// Adds a listener that will be notified about bindings being resolved.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::DIContainer;
+    /// # use syrette::di_container::listener::{ResolutionEvent, ResolutionListener};
+    /// #
+    /// struct ResolutionLogger;
+    ///
+    /// impl ResolutionListener for ResolutionLogger
+    /// {
+    ///     fn before_resolve(&self, event: &ResolutionEvent<'_>)
+    ///     {
+    ///         println!("Resolving {}", event.interface());
+    ///     }
+    /// }
+    ///
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container.add_listener(ResolutionLogger);
+    /// ```
+    pub fn add_listener<Listener>(&mut self, listener: Listener)
+    where
+        Listener: ResolutionListener + 'static,
+    {
+        self.listeners.add(Box::new(listener));
+    }
+
+    /// Returns a fork of the DI container with a independent copy of all of it's
+    /// bindings.
+    ///
+    /// Bindings added to or removed from the fork do not affect the original DI container
+    /// and vice versa. Whether the fork shares the singleton instances of the original DI
+    /// container is decided by `singleton_policy`.
+    ///
+    /// Resolution listeners and metrics are not copied to the fork.
+    ///
+    /// The fork is not shared even if the original DI container is, so it has to be
+    /// shared with [`into_shared`] before injectables can receive a [`ContainerRef`] of
+    /// it. Shared singletons that hold a [`ContainerRef`] keep referencing the original
+    /// DI container.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::di_container::SingletonForkPolicy;
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # struct Database {}
+    /// #
+    /// # #[injectable]
+    /// # impl Database
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut base_di_container = DIContainer::new();
+    ///
+    /// base_di_container
+    ///     .bind::<Database>()
+    ///     .to::<Database>()?
+    ///     .in_singleton_scope()?;
+    ///
+    /// let tenant_di_container = base_di_container.fork(SingletonForkPolicy::Recreate);
+    ///
+    /// let base_database = base_di_container.get::<Database>()?.singleton()?;
+    /// let tenant_database = tenant_di_container.get::<Database>()?.singleton()?;
+    ///
+    /// assert!(!std::rc::Rc::ptr_eq(&base_database, &tenant_database));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`into_shared`]: Self::into_shared
+    #[must_use]
+    pub fn fork(&self, singleton_policy: SingletonForkPolicy) -> Self
+    {
+        Self {
+            binding_storage: self
+                .binding_storage
+                .map_providers(|provider| provider.fork(singleton_policy)),
+            listeners: ResolutionListeners::new(),
+            metrics: RefCell::new(MetricsStore::default()),
+            self_ref: None,
+        }
+    }
+
+    /// Moves all of the bindings of `other` into the DI container.
+    ///
+    /// Bindings that exist in both DI containers are handled according to
+    /// `conflict_policy`.
+    ///
+    /// # Errors
+    /// Will return `Err` if `conflict_policy` is [`ConflictPolicy::Error`] and a binding
+    /// exists in both DI containers. No bindings are merged in that case.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::di_container::ConflictPolicy;
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # struct Storage {}
+    /// #
+    /// # #[injectable]
+    /// # impl Storage
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # struct Mailer {}
+    /// #
+    /// # #[injectable]
+    /// # impl Mailer
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container.bind::<Storage>().to::<Storage>()?;
+    ///
+    /// let mut mailer_di_container = DIContainer::new();
+    ///
+    /// mailer_di_container.bind::<Mailer>().to::<Mailer>()?;
+    ///
+    /// di_container.merge(mailer_di_container, ConflictPolicy::Error)?;
+    ///
+    /// di_container.get::<Mailer>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge(
+        &mut self,
+        other: Self,
+        conflict_policy: ConflictPolicy,
+    ) -> Result<(), DIContainerError>
+    {
+        if conflict_policy == ConflictPolicy::Error {
+            if let Some((interface, name)) =
+                self.binding_storage.find_conflict(&other.binding_storage)
+            {
+                return Err(DIContainerError::BindingConflict {
+                    interface,
+                    name: name.map(ToString::to_string),
+                });
+            }
+        }
+
+        self.binding_storage.extend(
+            other.binding_storage,
+            conflict_policy == ConflictPolicy::Overwrite,
+        );
+
+        Ok(())
+    }
+
+    /// Imports the binding of `Interface` from `other`.
+    ///
+    /// Singleton instances are shared between the DI containers.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` exists in `other`
+    /// - A binding for `Interface` already exists in the DI container
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IMailer {}
+    /// #
+    /// # struct Mailer {}
+    /// #
+    /// # #[injectable(IMailer)]
+    /// # impl Mailer
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IMailer for Mailer {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut mailer_di_container = DIContainer::new();
+    ///
+    /// mailer_di_container.bind::<dyn IMailer>().to::<Mailer>()?;
+    ///
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container.import::<dyn IMailer>(&mailer_di_container)?;
+    ///
+    /// di_container.get::<dyn IMailer>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn import<Interface>(&mut self, other: &Self) -> Result<(), DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.import_bound::<Interface>(other, BindingOptions::new())
+    }
+
+    /// Imports the binding of `Interface` with the specified name from `other`.
+    ///
+    /// Singleton instances are shared between the DI containers.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` with name `name` exists in `other`
+    /// - A binding for `Interface` with name `name` already exists in the DI container
+    pub fn import_named<Interface>(
+        &mut self,
+        other: &Self,
+        name: &'static str,
+    ) -> Result<(), DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.import_bound::<Interface>(other, BindingOptions::new().name(name))
+    }
+
+    /// Returns a new [`BindingOverrideBuilder`] for the given interface.
+    ///
+    /// The binding is overridden until the returned guard is dropped, after which the
+    /// original binding is restored.
+    ///
+    /// Requires mutable access to the DI container, so it can't be used on a DI
+    /// container shared using `new_shared` or `into_shared`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::rc::Rc;
+    /// #
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IClock
+    /// # {
+    /// #     fn now(&self) -> u64;
+    /// # }
+    /// #
+    /// # struct SystemClock {}
+    /// #
+    /// # #[injectable(IClock)]
+    /// # impl SystemClock
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IClock for SystemClock
+    /// # {
+    /// #     fn now(&self) -> u64
+    /// #     {
+    /// #         1_700_000_000
+    /// #     }
+    /// # }
+    /// #
+    /// struct FakeClock {}
+    ///
+    /// impl IClock for FakeClock
+    /// {
+    ///     fn now(&self) -> u64
+    ///     {
+    ///         0
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container.bind::<dyn IClock>().to::<SystemClock>()?;
+    ///
+    /// {
+    ///     let di_container = di_container
+    ///         .override_binding::<dyn IClock>()
+    ///         .to_instance(Rc::new(FakeClock {}));
+    ///
+    ///     assert_eq!(di_container.get::<dyn IClock>()?.singleton()?.now(), 0);
+    /// }
+    ///
+    /// assert_ne!(di_container.get::<dyn IClock>()?.transient()?.now(), 0);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "testing")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "testing")))]
+    pub fn override_binding<Interface>(&mut self) -> BindingOverrideBuilder<'_, Interface>
+    where
+        Interface: 'static + ?Sized,
+    {
+        BindingOverrideBuilder::new(self)
+    }
+
+    /// Creates the bindings described by the configuration file at the given path.
+    ///
+    /// The format of the file is determined by it's file extension, `toml` or `json`.
+    /// See the [`config`] module for a description of the format.
+    ///
+    /// Names of the bindings are kept for the rest of the program since they must be
+    /// `'static`. Every distinct name is only allocated once.
+    ///
+    /// # Errors
+    /// Will return Err if:
+    /// - Reading the file fails.
+    /// - The file is not a valid configuration.
+    /// - A configured implementation isn't registered.
+    /// - A configured binding already exists.
+    /// - Resolving a configured singleton fails.
+    ///
+    /// No bindings are created if any of them fails to be created. Singletons that were
+    /// resolved before the failure are dropped.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IStorage {}
+    /// #
+    /// # struct S3Storage {}
+    /// #
+    /// # #[injectable(IStorage, key = "s3")]
+    /// # impl S3Storage
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IStorage for S3Storage {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container.load_config("container.toml")?;
+    ///
+    /// let storage = di_container.get::<dyn IStorage>()?.transient()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`config`]: crate::config
+    #[cfg(feature = "config")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "config")))]
+    pub fn load_config(
+        &mut self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<(), ConfigError>
+    {
+        let path = path.as_ref();
 
-use crate::castable_function::CastableFunction;
-use crate::di_container::binding_storage::DIContainerBindingStorage;
-use crate::di_container::blocking::binding::builder::BindingBuilder;
-use crate::di_container::BindingOptions;
-use crate::errors::di_container::DIContainerError;
-use crate::private::cast::boxed::CastBox;
-use crate::private::cast::rc::CastRc;
-use crate::provider::blocking::{IProvider, Providable, ProvidableFunctionKind};
-use crate::ptr::{SomePtr, TransientPtr};
-use crate::util::use_double;
+        let format = ConfigFormat::from_path(path)?;
 
-use_double!(crate::dependency_history::DependencyHistory);
+        let source =
+            std::fs::read_to_string(path).map_err(|err| ConfigError::ReadFailed {
+                reason: err,
+                path: path.to_path_buf(),
+            })?;
 
-pub mod binding;
+        self.load_config_str(&source, format)
+    }
+
+    /// Creates the bindings described by the given configuration.
+    ///
+    /// See [`load_config`] for more details.
+    ///
+    /// # Errors
+    /// See [`load_config`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::config::ConfigFormat;
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IStorage {}
+    /// #
+    /// # struct S3Storage {}
+    /// #
+    /// # #[injectable(IStorage, key = "s3")]
+    /// # impl S3Storage
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IStorage for S3Storage {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container.load_config_str(
+    ///     r#"
+    ///     [[bindings]]
+    ///     interface = "IStorage"
+    ///     implementation = "s3"
+    ///     scope = "singleton"
+    ///     "#,
+    ///     ConfigFormat::Toml,
+    /// )?;
+    ///
+    /// let storage = di_container.get::<dyn IStorage>()?.singleton()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`load_config`]: Self::load_config
+    #[cfg(feature = "config")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "config")))]
+    pub fn load_config_str(
+        &mut self,
+        source: &str,
+        format: ConfigFormat,
+    ) -> Result<(), ConfigError>
+    {
+        let config = ContainerConfig::parse(source, format)?;
 
-#[cfg(not(test))]
-pub(crate) type BindingOptionsWithLt<'a> = BindingOptions<'a>;
+        let resolved_binding_configs = resolve_binding_configs(config, source)?;
 
-#[cfg(test)]
-pub(crate) type BindingOptionsWithLt = BindingOptions<'static>;
+        for (index, resolved) in resolved_binding_configs.iter().enumerate() {
+            let interface_type_id = (resolved.registered.interface)();
 
-/// Blocking dependency injection container.
-#[derive(Default)]
-pub struct DIContainer
-{
-    binding_storage: DIContainerBindingStorage<dyn IProvider<Self>>,
-}
+            let is_configured_earlier =
+                resolved_binding_configs[..index].iter().any(|earlier| {
+                    (earlier.registered.interface)() == interface_type_id
+                        && earlier.name == resolved.name
+                });
 
-impl DIContainer
-{
-    /// Returns a new `DIContainer`.
-    #[must_use]
-    pub fn new() -> Self
-    {
-        Self {
-            binding_storage: DIContainerBindingStorage::new(),
+            if is_configured_earlier
+                || self
+                    .binding_storage
+                    .has_with_type_id(interface_type_id, resolved.name.as_deref())
+            {
+                return Err(ConfigError::BindingAlreadyExists {
+                    interface: resolved.registered.interface_key.to_string(),
+                    name: resolved.name.clone(),
+                    line: resolved.line,
+                });
+            }
         }
+
+        let mut created_bindings = Vec::with_capacity(resolved_binding_configs.len());
+
+        for resolved in resolved_binding_configs {
+            let opt_name = resolved.name.as_deref().map(intern_binding_name);
+
+            if let Err(err) =
+                self.bind_registered(resolved.registered, resolved.scope, opt_name)
+            {
+                for (interface_type_id, opt_name) in created_bindings {
+                    self.binding_storage
+                        .remove_with_type_id(interface_type_id, opt_name);
+                }
+
+                return Err(ConfigError::BindingFailed {
+                    reason: Box::new(err),
+                    interface: resolved.registered.interface_key.to_string(),
+                    key: resolved.registered.key.to_string(),
+                    line: resolved.line,
+                });
+            }
+
+            created_bindings.push(((resolved.registered.interface)(), opt_name));
+        }
+
+        Ok(())
+    }
+
+    /// Writes the bindings of the DI container to a configuration file at the given
+    /// path.
+    ///
+    /// The format of the file is determined by it's file extension, `toml` or `json`.
+    /// See [`export_config_str`] for which bindings are exported.
+    ///
+    /// # Errors
+    /// Will return Err if:
+    /// - The format of the file cannot be determined.
+    /// - Serializing the configuration fails.
+    /// - Writing the file fails.
+    ///
+    /// [`export_config_str`]: Self::export_config_str
+    #[cfg(feature = "config")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "config")))]
+    pub fn export_config(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<(), ConfigError>
+    {
+        let path = path.as_ref();
+
+        let source = self.export_config_str(ConfigFormat::from_path(path)?)?;
+
+        std::fs::write(path, source).map_err(|err| ConfigError::WriteFailed {
+            reason: err,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Returns the bindings of the DI container as a configuration in the given format.
+    ///
+    /// Only bindings to implementations registered under a key can be described by a
+    /// configuration. Other bindings are left out.
+    ///
+    /// # Errors
+    /// Will return Err if serializing the configuration fails.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::config::ConfigFormat;
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IStorage {}
+    /// #
+    /// # struct S3Storage {}
+    /// #
+    /// # #[injectable(IStorage, key = "s3")]
+    /// # impl S3Storage
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IStorage for S3Storage {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container.bind::<dyn IStorage>().to_registered("s3")?;
+    ///
+    /// assert_eq!(
+    ///     di_container.export_config_str(ConfigFormat::Toml)?,
+    ///     concat!(
+    ///         "[[bindings]]\n",
+    ///         "interface = \"IStorage\"\n",
+    ///         "implementation = \"s3\"\n",
+    ///         "scope = \"transient\"\n",
+    ///     )
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "config")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "config")))]
+    pub fn export_config_str(&self, format: ConfigFormat) -> Result<String, ConfigError>
+    {
+        let mut bindings: Vec<_> = self
+            .binding_storage
+            .iter()
+            .filter_map(|(interface_type_id, options, provider)| {
+                let (implementation_type_id, scope) = provider.provided_type()?;
+
+                find_binding_config(
+                    interface_type_id,
+                    implementation_type_id,
+                    scope,
+                    options.name,
+                )
+            })
+            .collect();
+
+        bindings.sort_by(|binding_a, binding_b| {
+            (&binding_a.interface, &binding_a.name)
+                .cmp(&(&binding_b.interface, &binding_b.name))
+        });
+
+        ContainerConfig { bindings }.serialize(format)
     }
 }
 
@@ -131,6 +892,60 @@ impl DIContainer
         BindingBuilder::new(self, DependencyHistory::new)
     }
 
+    /// Returns a new [`GenericBindingBuilder`] for the given interface template.
+    ///
+    /// The type parameter of the interface template is written as [`GenericParam`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::fmt::Display;
+    /// # use std::marker::PhantomData;
+    /// #
+    /// # use syrette::di_container::GenericParam;
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IFormatter<Value> {}
+    /// #
+    /// # struct Formatter<Value>
+    /// # {
+    /// #     _value: PhantomData<Value>,
+    /// # }
+    /// #
+    /// # #[injectable(IFormatter<Value>, instances = [String, i32])]
+    /// # impl<Value: Display + 'static> Formatter<Value>
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self { _value: PhantomData }
+    /// #     }
+    /// # }
+    /// #
+    /// # impl<Value: Display + 'static> IFormatter<Value> for Formatter<Value> {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind_generic::<dyn IFormatter<GenericParam>>()
+    ///     .to::<Formatter<GenericParam>>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`GenericParam`]: crate::di_container::GenericParam
+    #[allow(clippy::missing_panics_doc)]
+    pub fn bind_generic<Interface>(&mut self) -> GenericBindingBuilder<'_, Interface>
+    where
+        Interface: 'static + ?Sized,
+    {
+        #[cfg(test)]
+        panic!("Nope");
+
+        #[cfg(not(test))]
+        GenericBindingBuilder::new(self, DependencyHistory::new)
+    }
+
     /// Returns the type bound with `Interface`.
     ///
     /// # Errors
@@ -215,58 +1030,188 @@ impl DIContainer
     where
         Interface: 'static + ?Sized,
     {
-        self.get_bound::<Interface>(
-            DependencyHistory::new(),
-            BindingOptions::new().name(name),
-        )
+        self.get_bound::<Interface>(
+            DependencyHistory::new(),
+            BindingOptions::new().name(name),
+        )
+    }
+
+    /// Returns the type bound with `Interface` where the binding has the specified
+    /// options.
+    ///
+    /// `dependency_history` is passed to the bound type when it is being resolved.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use syrette::di_container::blocking::DIContainer;
+    /// # use syrette::dependency_history::DependencyHistory;
+    /// # use syrette::di_container::BindingOptions;
+    /// #
+    /// # struct EventHandler {}
+    /// # struct Button {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let di_container = DIContainer::new();
+    /// #
+    /// let mut dependency_history = DependencyHistory::new();
+    ///
+    /// dependency_history.push::<EventHandler>();
+    ///
+    /// di_container.get_bound::<Button>(
+    ///     dependency_history,
+    ///     BindingOptions::new().name("huge_red"),
+    /// )?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_bound<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+        binding_options: BindingOptions<'static>,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "get_bound",
+            interface = type_name::<Interface>(),
+            name = binding_options.name
+        )
+        .entered();
+
+        let binding_name = binding_options.name;
+
+        let opt_event = self
+            .listeners
+            .before_resolve::<Interface>(&dependency_history, binding_name);
+
+        let stopwatch = Stopwatch::start();
+
+        let mut provider_kind = None;
+
+        let result = self
+            .get_binding_providable::<Interface>(binding_options, dependency_history)
+            .and_then(|binding_providable| {
+                provider_kind = Some(binding_providable.kind());
+
+                self.handle_binding_providable(binding_providable)
+            });
+
+        let elapsed = stopwatch.elapsed();
+
+        self.metrics.borrow_mut().record::<Interface>(
+            binding_name,
+            provider_kind,
+            elapsed,
+            result.is_ok(),
+        );
+
+        self.listeners
+            .after_resolve(opt_event, provider_kind, elapsed, &result);
+
+        result
+    }
+
+    pub(crate) fn has_binding<Interface>(
+        &self,
+        binding_options: BindingOptions<'static>,
+    ) -> bool
+    where
+        Interface: ?Sized + 'static,
+    {
+        self.binding_storage.has::<Interface>(binding_options)
+    }
+
+    pub(crate) fn set_binding<Interface>(
+        &mut self,
+        binding_options: BindingOptions<'static>,
+        provider: Box<dyn IProvider<Self>>,
+    ) where
+        Interface: 'static + ?Sized,
+    {
+        self.binding_storage
+            .set::<Interface>(binding_options, provider);
+    }
+
+    pub(crate) fn remove_binding<Interface>(
+        &mut self,
+        binding_options: BindingOptions<'static>,
+    ) -> Option<Box<dyn IProvider<Self>>>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.binding_storage.remove::<Interface>(binding_options)
+    }
+}
+
+impl DIContainer
+{
+    #[cfg(feature = "config")]
+    #[cfg_attr(test, allow(unused_variables, clippy::unused_self))]
+    fn bind_registered(
+        &mut self,
+        registered: &RegisteredImplementation,
+        scope: RegisteredScope,
+        opt_name: Option<&'static str>,
+    ) -> Result<(), BindingScopeConfiguratorError>
+    {
+        #[cfg(test)]
+        panic!("Nope");
+
+        #[cfg(not(test))]
+        (registered.bind)(self, DependencyHistory::new, scope, opt_name)
+    }
+
+    fn import_bound<Interface>(
+        &mut self,
+        other: &Self,
+        binding_options: BindingOptions<'static>,
+    ) -> Result<(), DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        let name = binding_options.name;
+
+        let provider = other
+            .binding_storage
+            .get::<Interface>(binding_options.clone())
+            .ok_or_else(|| DIContainerError::BindingNotFound {
+                interface: type_name::<Interface>(),
+                name: name.map(ToString::to_string),
+            })?
+            .fork(SingletonForkPolicy::Share);
+
+        if self
+            .binding_storage
+            .has::<Interface>(binding_options.clone())
+        {
+            return Err(DIContainerError::BindingConflict {
+                interface: type_name::<Interface>(),
+                name: name.map(ToString::to_string),
+            });
+        }
+
+        self.binding_storage
+            .set::<Interface>(binding_options, provider);
+
+        Ok(())
     }
 
-    /// Returns the type bound with `Interface` where the binding has the specified
-    /// options.
-    ///
-    /// `dependency_history` is passed to the bound type when it is being resolved.
-    ///
-    /// # Errors
-    /// Will return `Err` if:
-    /// - No binding for `Interface` exists
-    /// - Resolving the binding for `Interface` fails
-    /// - Casting the binding for `Interface` fails
-    ///
-    /// # Examples
-    /// ```no_run
-    /// # use syrette::di_container::blocking::DIContainer;
-    /// # use syrette::dependency_history::DependencyHistory;
-    /// # use syrette::di_container::BindingOptions;
-    /// #
-    /// # struct EventHandler {}
-    /// # struct Button {}
-    /// #
-    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let di_container = DIContainer::new();
-    /// #
-    /// let mut dependency_history = DependencyHistory::new();
-    ///
-    /// dependency_history.push::<EventHandler>();
-    ///
-    /// di_container.get_bound::<Button>(
-    ///     dependency_history,
-    ///     BindingOptions::new().name("huge_red"),
-    /// )?;
-    /// #
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub fn get_bound<Interface>(
+    fn handle_binding_providable<Interface>(
         &self,
-        dependency_history: DependencyHistory,
-        binding_options: BindingOptionsWithLt,
+        binding_providable: Providable<Self>,
     ) -> Result<SomePtr<Interface>, DIContainerError>
     where
         Interface: 'static + ?Sized,
     {
-        let binding_providable = self
-            .get_binding_providable::<Interface>(binding_options, dependency_history)?;
-
         match binding_providable {
             Providable::Transient(transient_binding) => Ok(SomePtr::Transient(
                 transient_binding.cast::<Interface>().map_err(|_| {
@@ -310,43 +1255,60 @@ impl DIContainer
 
                 Ok(SomePtr::Transient(dynamic_val_func.call(self)()))
             }
-        }
-    }
-
-    fn has_binding<Interface>(&self, binding_options: BindingOptionsWithLt) -> bool
-    where
-        Interface: ?Sized + 'static,
-    {
-        self.binding_storage.has::<Interface>(binding_options)
-    }
-
-    fn set_binding<Interface>(
-        &mut self,
-        binding_options: BindingOptions<'static>,
-        provider: Box<dyn IProvider<Self>>,
-    ) where
-        Interface: 'static + ?Sized,
-    {
-        self.binding_storage
-            .set::<Interface>(binding_options, provider);
-    }
+            #[cfg(feature = "factory")]
+            Providable::Function(
+                func_bound,
+                ProvidableFunctionKind::FallibleUserCalled,
+            ) => self
+                .call_fallible_function::<Interface>(
+                    func_bound.as_ref(),
+                    "fallible factory",
+                )
+                .map(|factory| SomePtr::Factory(factory.into())),
+            Providable::Function(func_bound, ProvidableFunctionKind::FallibleInstant) => {
+                self.call_fallible_function(
+                    func_bound.as_ref(),
+                    "fallible dynamic value function",
+                )
+                .map(SomePtr::Transient)
+            }
+            #[cfg(feature = "factory")]
+            Providable::AssistedFactory(factory) => {
+                let factory =
+                    factory.downcast::<TransientPtr<Interface>>().map_err(|_| {
+                        DIContainerError::CastFailed {
+                            interface: type_name::<Interface>(),
+                            binding_kind: "assisted factory",
+                        }
+                    })?;
 
-    fn remove_binding<Interface>(
-        &mut self,
-        binding_options: BindingOptions<'static>,
-    ) -> Option<Box<dyn IProvider<Self>>>
-    where
-        Interface: 'static + ?Sized,
-    {
-        self.binding_storage.remove::<Interface>(binding_options)
+                Ok(SomePtr::Factory((*factory).into()))
+            }
+            #[cfg(feature = "testing")]
+            Providable::Instance(instance) => instance
+                .downcast_ref::<SingletonPtr<Interface>>()
+                .map(|instance| SomePtr::Singleton(instance.clone()))
+                .ok_or_else(|| DIContainerError::CastFailed {
+                    interface: type_name::<Interface>(),
+                    binding_kind: "instance",
+                }),
+            #[cfg(feature = "testing")]
+            Providable::TransientInstance(func) => {
+                type Func<Interface> = Box<dyn Fn() -> TransientPtr<Interface>>;
+
+                func.downcast_ref::<Func<Interface>>()
+                    .map(|func| SomePtr::Transient(func()))
+                    .ok_or_else(|| DIContainerError::CastFailed {
+                        interface: type_name::<Interface>(),
+                        binding_kind: "transient instance",
+                    })
+            }
+        }
     }
-}
 
-impl DIContainer
-{
     fn get_binding_providable<Interface>(
         &self,
-        binding_options: BindingOptionsWithLt,
+        binding_options: BindingOptions<'static>,
         dependency_history: DependencyHistory,
     ) -> Result<Providable<Self>, DIContainerError>
     where
@@ -371,12 +1333,62 @@ impl DIContainer
                 interface: type_name::<Interface>(),
             })
     }
+
+    fn call_fallible_function<Interface>(
+        &self,
+        func_bound: &dyn AnyCastableFunction,
+        binding_kind: &'static str,
+    ) -> Result<TransientPtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        let fallible_func = func_bound
+            .as_any()
+            .downcast_ref::<FallibleCastableFunction<Interface, Self>>()
+            .ok_or_else(|| DIContainerError::CastFailed {
+                interface: type_name::<Interface>(),
+                binding_kind,
+            })?;
+
+        fallible_func
+            .call(self)
+            .map_err(|err| DIContainerError::BindingResolveFailed {
+                reason: InjectableError::FunctionFailed {
+                    reason: err,
+                    interface: type_name::<Interface>(),
+                },
+                interface: type_name::<Interface>(),
+            })
+    }
+}
+
+impl DependencyResolver for DIContainer
+{
+    fn get_bound<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+        binding_options: BindingOptions<'static>,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        DIContainer::get_bound(self, dependency_history, binding_options)
+    }
+
+    fn container_ref(&self) -> Result<ContainerRef, DIContainerError>
+    {
+        self.self_ref
+            .clone()
+            .map(ContainerRef::new)
+            .ok_or(DIContainerError::ContainerNotShared)
+    }
 }
 
 #[cfg(test)]
 mod tests
 {
     use super::*;
+    use crate::di_container::listener::ProviderKind;
     use crate::provider::blocking::MockIProvider;
     use crate::ptr::{SingletonPtr, TransientPtr};
     use crate::test_utils::subjects;
@@ -408,6 +1420,65 @@ mod tests
             .unwrap();
     }
 
+    #[test]
+    fn can_get_metrics()
+    {
+        let mut di_container = DIContainer::new();
+
+        let mut mock_provider = MockIProvider::new();
+
+        mock_provider.expect_provide().returning(|_, _| {
+            Ok(Providable::Transient(TransientPtr::new(
+                subjects::UserManager::new(),
+            )))
+        });
+
+        di_container
+            .binding_storage
+            .set::<dyn subjects::IUserManager>(
+                BindingOptions::new(),
+                Box::new(mock_provider),
+            );
+
+        for _ in 0..3 {
+            di_container.get::<dyn subjects::IUserManager>().unwrap();
+        }
+
+        assert!(di_container.get::<dyn subjects::INumber>().is_err());
+
+        let metrics = di_container.metrics();
+
+        assert_eq!(metrics.len(), 2);
+
+        let user_manager_metrics = metrics
+            .iter()
+            .find(|binding_metrics| {
+                binding_metrics.interface() == type_name::<dyn subjects::IUserManager>()
+            })
+            .unwrap();
+
+        assert_eq!(user_manager_metrics.resolution_count(), 3);
+        assert_eq!(user_manager_metrics.failure_count(), 0);
+        assert_eq!(
+            user_manager_metrics.provider_kind(),
+            Some(ProviderKind::Transient)
+        );
+
+        let number_metrics = metrics
+            .iter()
+            .find(|binding_metrics| {
+                binding_metrics.interface() == type_name::<dyn subjects::INumber>()
+            })
+            .unwrap();
+
+        assert_eq!(number_metrics.resolution_count(), 0);
+        assert_eq!(number_metrics.failure_count(), 1);
+
+        di_container.reset_metrics();
+
+        assert!(di_container.metrics().is_empty());
+    }
+
     #[test]
     fn can_get_named()
     {
@@ -512,12 +1583,12 @@ mod tests
     #[cfg(feature = "factory")]
     fn can_get_factory()
     {
-        use std::rc::Rc;
+        use alloc::rc::Rc;
 
         use crate::castable_function::CastableFunction;
         use crate::provider::blocking::ProvidableFunctionKind;
-        use crate::ptr::FactoryPtr;
 
+        #[allow(dead_code)]
         trait IUserManager
         {
             fn add_user(&mut self, user_id: i128);
@@ -525,6 +1596,7 @@ mod tests
             fn remove_user(&mut self, user_id: i128);
         }
 
+        #[allow(dead_code)]
         struct UserManager
         {
             users: Vec<i128>,
@@ -591,11 +1663,12 @@ mod tests
     #[cfg(feature = "factory")]
     fn can_get_factory_named()
     {
-        use std::rc::Rc;
+        use alloc::rc::Rc;
 
         use crate::castable_function::CastableFunction;
         use crate::provider::blocking::ProvidableFunctionKind;
 
+        #[allow(dead_code)]
         trait IUserManager
         {
             fn add_user(&mut self, user_id: i128);
@@ -603,6 +1676,7 @@ mod tests
             fn remove_user(&mut self, user_id: i128);
         }
 
+        #[allow(dead_code)]
         struct UserManager
         {
             users: Vec<i128>,
@@ -666,6 +1740,161 @@ mod tests
             .unwrap();
     }
 
+    #[test]
+    #[cfg(feature = "factory")]
+    fn can_get_assisted_factory()
+    {
+        type INumberFactory = dyn Fn(i32) -> TransientPtr<dyn subjects::INumber>;
+
+        let mut di_container = DIContainer::new();
+
+        let mut mock_provider = MockIProvider::new();
+
+        mock_provider.expect_provide().returning_st(|_, _| {
+            let factory: TransientPtr<INumberFactory> = TransientPtr::new(|num| {
+                let mut number = subjects::Number::new();
+
+                number.num = num;
+
+                TransientPtr::new(number) as TransientPtr<dyn subjects::INumber>
+            });
+
+            Ok(Providable::AssistedFactory(TransientPtr::new(factory)))
+        });
+
+        di_container
+            .binding_storage
+            .set::<INumberFactory>(BindingOptions::new(), Box::new(mock_provider));
+
+        let number_factory = di_container
+            .get::<INumberFactory>()
+            .unwrap()
+            .factory()
+            .unwrap();
+
+        assert_eq!(number_factory(74).get(), 74);
+    }
+
+    #[test]
+    #[cfg(feature = "factory")]
+    fn can_get_fallible_factory()
+    {
+        use alloc::rc::Rc;
+
+        use crate::castable_function::fallible::FallibleCastableFunction;
+
+        type INumberFactory = dyn Fn(i32) -> TransientPtr<dyn subjects::INumber>;
+
+        let mut di_container = DIContainer::new();
+
+        let mut mock_provider = MockIProvider::new();
+
+        mock_provider.expect_provide().returning_st(|_, _| {
+            Ok(Providable::Function(
+                Rc::new(FallibleCastableFunction::new(&|_: &DIContainer| {
+                    Ok::<Box<INumberFactory>, core::fmt::Error>(Box::new(|num| {
+                        let mut number = subjects::Number::new();
+
+                        number.num = num;
+
+                        TransientPtr::new(number) as TransientPtr<dyn subjects::INumber>
+                    }))
+                })),
+                ProvidableFunctionKind::FallibleUserCalled,
+            ))
+        });
+
+        di_container
+            .binding_storage
+            .set::<INumberFactory>(BindingOptions::new(), Box::new(mock_provider));
+
+        let number_factory = di_container
+            .get::<INumberFactory>()
+            .unwrap()
+            .factory()
+            .unwrap();
+
+        assert_eq!(number_factory(19).get(), 19);
+    }
+
+    #[test]
+    fn can_get_fallible_dynamic_value()
+    {
+        use alloc::rc::Rc;
+
+        use crate::castable_function::fallible::FallibleCastableFunction;
+
+        let mut di_container = DIContainer::new();
+
+        let mut mock_provider = MockIProvider::new();
+
+        mock_provider.expect_provide().returning_st(|_, _| {
+            Ok(Providable::Function(
+                Rc::new(FallibleCastableFunction::new(&|_: &DIContainer| {
+                    Ok::<_, core::fmt::Error>(TransientPtr::new(
+                        subjects::UserManager::new(),
+                    )
+                        as TransientPtr<dyn subjects::IUserManager>)
+                })),
+                ProvidableFunctionKind::FallibleInstant,
+            ))
+        });
+
+        di_container
+            .binding_storage
+            .set::<dyn subjects::IUserManager>(
+                BindingOptions::new(),
+                Box::new(mock_provider),
+            );
+
+        di_container
+            .get::<dyn subjects::IUserManager>()
+            .unwrap()
+            .transient()
+            .unwrap();
+    }
+
+    #[test]
+    fn get_fallible_dynamic_value_returns_function_error()
+    {
+        use alloc::rc::Rc;
+        use std::error::Error;
+
+        use crate::castable_function::fallible::FallibleCastableFunction;
+
+        let mut di_container = DIContainer::new();
+
+        let mut mock_provider = MockIProvider::new();
+
+        mock_provider.expect_provide().returning_st(|_, _| {
+            Ok(Providable::Function(
+                Rc::new(FallibleCastableFunction::new(&|_: &DIContainer| {
+                    Err::<TransientPtr<dyn subjects::INumber>, _>(core::fmt::Error)
+                })),
+                ProvidableFunctionKind::FallibleInstant,
+            ))
+        });
+
+        di_container
+            .binding_storage
+            .set::<dyn subjects::INumber>(BindingOptions::new(), Box::new(mock_provider));
+
+        let err = di_container.get::<dyn subjects::INumber>().unwrap_err();
+
+        assert!(matches!(
+            err,
+            DIContainerError::BindingResolveFailed {
+                reason: InjectableError::FunctionFailed { .. },
+                interface
+            } if interface.ends_with("INumber")
+        ));
+
+        assert!(err
+            .source()
+            .and_then(Error::source)
+            .map_or_else(|| false, <dyn Error>::is::<core::fmt::Error>));
+    }
+
     #[test]
     fn has_binding_works()
     {
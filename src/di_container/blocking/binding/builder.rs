@@ -1,14 +1,27 @@
 //! Binding builder for types inside of a [`DIContainer`].
-use std::any::type_name;
-use std::marker::PhantomData;
-use std::rc::Rc;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::marker::PhantomData;
 
+use crate::castable_function::fallible::FallibleCastableFunction;
 use crate::castable_function::CastableFunction;
-use crate::di_container::blocking::binding::scope_configurator::BindingScopeConfigurator;
+use crate::di_container::blocking::binding::scope_configurator::{
+    BindingScopeConfigurator,
+    GenericBindingScopeConfigurator,
+    RegisteredBindingScopeConfigurator,
+};
 use crate::di_container::blocking::binding::when_configurator::BindingWhenConfigurator;
 use crate::di_container::BindingOptions;
 use crate::errors::di_container::BindingBuilderError;
+#[cfg(feature = "factory")]
+use crate::interfaces::assisted_injectable::AssistedInjectable;
 use crate::interfaces::injectable::Injectable;
+use crate::private::registry::{find_generic_instances, find_registered};
+#[cfg(feature = "factory")]
+use crate::provider::blocking::AssistedFactoryProvider;
 use crate::provider::blocking::{FunctionProvider, ProvidableFunctionKind};
 use crate::ptr::TransientPtr;
 use crate::util::use_double;
@@ -111,6 +124,137 @@ where
         Ok(binding_scope_configurator)
     }
 
+    /// Creates a binding of type `Interface` to the implementation registered under
+    /// the given key inside of the associated [`DIContainer`].
+    ///
+    /// Implementations are registered under a key using the `key` flag of the
+    /// [`injectable`] macro. This makes it possible to select a implementation at
+    /// runtime, for example from a configuration value.
+    ///
+    /// The scope of the binding is transient. But that can be changed by using the
+    /// returned [`RegisteredBindingScopeConfigurator`]
+    ///
+    /// # Errors
+    /// Will return Err if:
+    /// - The associated [`DIContainer`] already have a binding for the interface.
+    /// - No implementation of the interface is registered under the key.
+    /// - Multiple implementations of the interface are registered under the key.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// trait IStorage {}
+    ///
+    /// struct S3Storage {}
+    ///
+    /// #[injectable(IStorage, key = "s3")]
+    /// impl S3Storage
+    /// {
+    ///     fn new() -> Self
+    ///     {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// impl IStorage for S3Storage {}
+    ///
+    /// struct LocalStorage {}
+    ///
+    /// #[injectable(IStorage, key = "local")]
+    /// impl LocalStorage
+    /// {
+    ///     fn new() -> Self
+    ///     {
+    ///         Self {}
+    ///     }
+    /// }
+    ///
+    /// impl IStorage for LocalStorage {}
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>>
+    /// # {
+    /// let storage_backend = "local";
+    ///
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn IStorage>()
+    ///     .to_registered(storage_backend)?;
+    ///
+    /// assert!(di_container
+    ///     .bind::<dyn IStorage>()
+    ///     .to_registered("gcs")
+    ///     .is_err());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`injectable`]: crate::injectable
+    pub fn to_registered(
+        self,
+        key: &str,
+    ) -> Result<
+        RegisteredBindingScopeConfigurator<'di_container, Interface>,
+        BindingBuilderError,
+    >
+    {
+        if self
+            .di_container
+            .has_binding::<Interface>(BindingOptions::new())
+        {
+            return Err(BindingBuilderError::BindingAlreadyExists(type_name::<
+                Interface,
+            >()));
+        }
+
+        let registered_implementations = find_registered::<Interface>();
+
+        let (matching, other): (Vec<_>, Vec<_>) = registered_implementations
+            .into_iter()
+            .partition(|registered| registered.key == key);
+
+        let registered = match matching.as_slice() {
+            [registered] => *registered,
+            [] => {
+                let mut available_keys: Vec<_> =
+                    other.iter().map(|registered| registered.key).collect();
+
+                available_keys.sort_unstable();
+                available_keys.dedup();
+
+                return Err(BindingBuilderError::UnregisteredKey {
+                    interface: type_name::<Interface>(),
+                    key: key.to_string(),
+                    available_keys,
+                });
+            }
+            _ => {
+                return Err(BindingBuilderError::AmbiguousRegisteredKey {
+                    interface: type_name::<Interface>(),
+                    key: key.to_string(),
+                    implementations: matching
+                        .iter()
+                        .map(|registered| (registered.implementation_name)())
+                        .collect(),
+                });
+            }
+        };
+
+        let mut binding_scope_configurator = RegisteredBindingScopeConfigurator::new(
+            self.di_container,
+            self.dependency_history_factory,
+            registered.bind,
+        );
+
+        binding_scope_configurator.set_in_transient_scope();
+
+        Ok(binding_scope_configurator)
+    }
+
     /// Creates a binding of factory type `Interface` to a factory inside of the
     /// associated [`DIContainer`].
     ///
@@ -179,7 +323,7 @@ where
         factory_func: &'static Func,
     ) -> Result<BindingWhenConfigurator<'di_container, Interface>, BindingBuilderError>
     where
-        Args: std::marker::Tuple + 'static,
+        Args: core::marker::Tuple + 'static,
         Return: 'static + ?Sized,
         Interface: Fn<Args, Output = crate::ptr::TransientPtr<Return>>,
         Func: Fn(&DIContainer) -> Box<Interface>,
@@ -206,6 +350,170 @@ where
         Ok(BindingWhenConfigurator::new(self.di_container))
     }
 
+    /// Creates a binding of factory type `Interface` to a fallible factory inside of the
+    /// associated [`DIContainer`].
+    ///
+    /// Unlike [`to_factory`], the given function may fail. The error is returned as
+    /// the source of a [`DIContainerError::BindingResolveFailed`] when the factory
+    /// is resolved.
+    ///
+    /// # Errors
+    /// Will return Err if the associated [`DIContainer`] already have a binding for
+    /// the interface.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # use syrette::DIContainer;
+    /// # use syrette::ptr::TransientPtr;
+    /// #
+    /// # trait IConnection {}
+    /// #
+    /// # struct Connection
+    /// # {
+    /// #   address: String,
+    /// #   port: u16
+    /// # }
+    /// #
+    /// # impl IConnection for Connection {}
+    /// #
+    /// # type IConnectionFactory = dyn Fn(u16) -> TransientPtr<dyn IConnection>;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>>
+    /// # {
+    /// # let mut di_container = DIContainer::new();
+    /// #
+    /// di_container
+    ///     .bind::<IConnectionFactory>()
+    ///     .to_fallible_factory(&|_| {
+    ///         let address = std::env::var("ADDRESS")?;
+    ///
+    ///         Ok::<_, std::env::VarError>(Box::new(move |port| {
+    ///             let address = address.clone();
+    ///
+    ///             TransientPtr::new(Connection { address, port })
+    ///                 as TransientPtr<dyn IConnection>
+    ///         }))
+    ///     });
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_factory`]: Self::to_factory
+    /// [`DIContainerError::BindingResolveFailed`]: crate::errors::di_container::DIContainerError::BindingResolveFailed
+    #[cfg(feature = "factory")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
+    pub fn to_fallible_factory<Args, Return, Func, FuncError>(
+        self,
+        factory_func: &'static Func,
+    ) -> Result<BindingWhenConfigurator<'di_container, Interface>, BindingBuilderError>
+    where
+        Args: core::marker::Tuple + 'static,
+        Return: 'static + ?Sized,
+        Interface: Fn<Args, Output = crate::ptr::TransientPtr<Return>>,
+        Func: Fn(&DIContainer) -> Result<Box<Interface>, FuncError>,
+        FuncError: crate::util::Error + Send + Sync + 'static,
+    {
+        if self
+            .di_container
+            .has_binding::<Interface>(BindingOptions::new())
+        {
+            return Err(BindingBuilderError::BindingAlreadyExists(type_name::<
+                Interface,
+            >()));
+        }
+
+        let factory_impl = FallibleCastableFunction::new(factory_func);
+
+        self.di_container.set_binding::<Interface>(
+            BindingOptions::new(),
+            Box::new(FunctionProvider::new(
+                Rc::new(factory_impl),
+                ProvidableFunctionKind::FallibleUserCalled,
+            )),
+        );
+
+        Ok(BindingWhenConfigurator::new(self.di_container))
+    }
+
+    /// Creates a binding of factory type `Interface` to the factory of the assisted
+    /// injectable `Implementation` inside of the associated [`DIContainer`].
+    ///
+    /// The factory is generated by the [`injectable`] macro when the `factory` flag is
+    /// given. Constructor arguments marked with `#[assisted]` become parameters of the
+    /// factory while the rest are resolved from the [`DIContainer`].
+    ///
+    /// # Errors
+    /// Will return Err if the associated [`DIContainer`] already have a binding for
+    /// the interface.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # use syrette::{DIContainer, injectable};
+    /// # use syrette::ptr::TransientPtr;
+    /// #
+    /// # trait ICustomer {}
+    /// #
+    /// # struct Customer
+    /// # {
+    /// #   name: String,
+    /// # }
+    /// #
+    /// type ICustomerFactory = dyn Fn(String) -> TransientPtr<dyn ICustomer>;
+    ///
+    /// #[injectable(ICustomer, factory = ICustomerFactory)]
+    /// impl Customer
+    /// {
+    ///     fn new(#[assisted] name: String) -> Self
+    ///     {
+    ///         Self { name }
+    ///     }
+    /// }
+    /// #
+    /// # impl ICustomer for Customer {}
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>>
+    /// # {
+    /// # let mut di_container = DIContainer::new();
+    /// #
+    /// di_container
+    ///     .bind::<ICustomerFactory>()
+    ///     .to_assisted_factory::<Customer>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`injectable`]: crate::injectable
+    #[cfg(feature = "factory")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
+    pub fn to_assisted_factory<Implementation>(
+        self,
+    ) -> Result<BindingWhenConfigurator<'di_container, Interface>, BindingBuilderError>
+    where
+        Implementation: AssistedInjectable<DIContainer, Factory = Interface>,
+    {
+        if self
+            .di_container
+            .has_binding::<Interface>(BindingOptions::new())
+        {
+            return Err(BindingBuilderError::BindingAlreadyExists(type_name::<
+                Interface,
+            >()));
+        }
+
+        self.di_container.set_binding::<Interface>(
+            BindingOptions::new(),
+            Box::new(AssistedFactoryProvider::<Implementation, DIContainer>::new()),
+        );
+
+        Ok(BindingWhenConfigurator::new(self.di_container))
+    }
+
     /// Creates a binding of type `Interface` to a value resolved using the given
     /// function.
     ///
@@ -280,6 +588,205 @@ where
 
         Ok(BindingWhenConfigurator::new(self.di_container))
     }
+
+    /// Creates a binding of type `Interface` to a value resolved using the given
+    /// fallible function.
+    ///
+    /// The function is called every time the binding is resolved. If it fails, the
+    /// error is returned as the source of a
+    /// [`DIContainerError::BindingResolveFailed`].
+    ///
+    /// # Errors
+    /// Will return Err if the associated [`DIContainer`] already have a binding for
+    /// the interface.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// # use std::num::ParseIntError;
+    /// #
+    /// # use syrette::DIContainer;
+    /// # use syrette::ptr::TransientPtr;
+    /// #
+    /// # trait IPort {}
+    /// #
+    /// # struct Port(u16);
+    /// #
+    /// # impl IPort for Port {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>>
+    /// # {
+    /// # let mut di_container = DIContainer::new();
+    /// #
+    /// di_container
+    ///     .bind::<dyn IPort>()
+    ///     .to_fallible_dynamic_value(&|_| {
+    ///         let port = "8080".parse::<u16>()?;
+    ///
+    ///         Ok::<_, ParseIntError>(
+    ///             TransientPtr::new(Port(port)) as TransientPtr<dyn IPort>
+    ///         )
+    ///     });
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`DIContainerError::BindingResolveFailed`]: crate::errors::di_container::DIContainerError::BindingResolveFailed
+    pub fn to_fallible_dynamic_value<Func, FuncError>(
+        self,
+        func: &'static Func,
+    ) -> Result<BindingWhenConfigurator<'di_container, Interface>, BindingBuilderError>
+    where
+        Func: Fn(&DIContainer) -> Result<TransientPtr<Interface>, FuncError>,
+        FuncError: crate::util::Error + Send + Sync + 'static,
+    {
+        if self
+            .di_container
+            .has_binding::<Interface>(BindingOptions::new())
+        {
+            return Err(BindingBuilderError::BindingAlreadyExists(type_name::<
+                Interface,
+            >()));
+        }
+
+        let castable_func = FallibleCastableFunction::new(func);
+
+        self.di_container.set_binding::<Interface>(
+            BindingOptions::new(),
+            Box::new(FunctionProvider::new(
+                Rc::new(castable_func),
+                ProvidableFunctionKind::FallibleInstant,
+            )),
+        );
+
+        Ok(BindingWhenConfigurator::new(self.di_container))
+    }
+}
+
+/// Binding builder for the instances of a generic interface template `Interface` inside
+/// a [`DIContainer`].
+#[must_use = "No binding will be created if you don't use the binding builder"]
+pub struct GenericBindingBuilder<'di_container, Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    di_container: &'di_container mut DIContainer,
+    dependency_history_factory: fn() -> DependencyHistory,
+
+    interface_phantom: PhantomData<Interface>,
+}
+
+impl<'di_container, Interface> GenericBindingBuilder<'di_container, Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    pub(crate) fn new(
+        di_container: &'di_container mut DIContainer,
+        dependency_history_factory: fn() -> DependencyHistory,
+    ) -> Self
+    {
+        Self {
+            di_container,
+            dependency_history_factory,
+            interface_phantom: PhantomData,
+        }
+    }
+
+    /// Creates bindings of every instance of the interface template `Interface` to the
+    /// corresponding instance of the generic implementation template `Implementation`
+    /// inside of the associated [`DIContainer`].
+    ///
+    /// The instances are the types listed in the `instances` flag of the
+    /// [`injectable`] macro.
+    ///
+    /// The scope of the bindings can be configured with the returned
+    /// [`GenericBindingScopeConfigurator`].
+    ///
+    /// # Errors
+    /// Will return Err if:
+    /// - No instances of `Implementation` are registered for `Interface`.
+    /// - The associated [`DIContainer`] already have a binding for a instance of the
+    ///   interface.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// # use std::marker::PhantomData;
+    /// #
+    /// # use syrette::di_container::GenericParam;
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// trait IQueue<Message> {}
+    ///
+    /// struct MemoryQueue<Message>
+    /// {
+    ///     _message: PhantomData<Message>,
+    /// }
+    ///
+    /// #[injectable(IQueue<Message>, instances = [String, Vec<u8>])]
+    /// impl<Message: 'static> MemoryQueue<Message>
+    /// {
+    ///     fn new() -> Self
+    ///     {
+    ///         Self {
+    ///             _message: PhantomData,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl<Message: 'static> IQueue<Message> for MemoryQueue<Message> {}
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>>
+    /// # {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind_generic::<dyn IQueue<GenericParam>>()
+    ///     .to::<MemoryQueue<GenericParam>>()?
+    ///     .in_singleton_scope()?;
+    ///
+    /// di_container.get::<dyn IQueue<Vec<u8>>>()?.singleton()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`injectable`]: crate::injectable
+    pub fn to<Implementation>(
+        self,
+    ) -> Result<GenericBindingScopeConfigurator<'di_container>, BindingBuilderError>
+    where
+        Implementation: 'static + ?Sized,
+    {
+        let instances = find_generic_instances::<Interface, Implementation>();
+
+        if instances.is_empty() {
+            return Err(BindingBuilderError::NoGenericInstances {
+                interface: type_name::<Interface>(),
+                implementation: type_name::<Implementation>(),
+            });
+        }
+
+        if let Some(bound_instance) = instances
+            .iter()
+            .find(|instance| (instance.is_bound)(self.di_container))
+        {
+            return Err(BindingBuilderError::BindingAlreadyExists((bound_instance
+                .interface_name)(
+            )));
+        }
+
+        let mut binding_scope_configurator = GenericBindingScopeConfigurator::new(
+            self.di_container,
+            self.dependency_history_factory,
+            instances.iter().map(|instance| instance.bind).collect(),
+        );
+
+        binding_scope_configurator.set_in_transient_scope();
+
+        Ok(binding_scope_configurator)
+    }
 }
 
 #[cfg(test)]
@@ -357,6 +864,59 @@ mod tests
             .unwrap();
     }
 
+    #[test]
+    #[cfg(feature = "factory")]
+    fn can_bind_to_assisted_factory()
+    {
+        use crate::errors::injectable::InjectableError;
+        use crate::interfaces::assisted_injectable::AssistedInjectable;
+        use crate::ptr::TransientPtr;
+
+        type IUserManagerFactory =
+            dyn Fn(i32) -> TransientPtr<dyn subjects::IUserManager>;
+
+        struct UserManagerFactoryMarker;
+
+        impl AssistedInjectable<MockDIContainer> for UserManagerFactoryMarker
+        {
+            type Factory = IUserManagerFactory;
+
+            fn resolve_factory(
+                _di_container: &MockDIContainer,
+                _dependency_history: MockDependencyHistory,
+            ) -> Result<TransientPtr<Self::Factory>, InjectableError>
+            {
+                Ok(TransientPtr::new(|_num| {
+                    TransientPtr::new(subjects::UserManager::new())
+                        as TransientPtr<dyn subjects::IUserManager>
+                }))
+            }
+        }
+
+        let mut mock_di_container = MockDIContainer::new();
+
+        mock_di_container
+            .expect_has_binding::<IUserManagerFactory>()
+            .with(eq(BindingOptions::new()))
+            .return_once(|_| false)
+            .once();
+
+        mock_di_container
+            .expect_set_binding::<IUserManagerFactory>()
+            .withf(|options, _provider| options.name.is_none())
+            .return_once(|_, _provider| ())
+            .once();
+
+        let binding_builder = BindingBuilder::<IUserManagerFactory>::new(
+            &mut mock_di_container,
+            MockDependencyHistory::new,
+        );
+
+        binding_builder
+            .to_assisted_factory::<UserManagerFactoryMarker>()
+            .unwrap();
+    }
+
     #[test]
     fn can_bind_to_dynamic_value()
     {
@@ -392,4 +952,95 @@ mod tests
             })
             .unwrap();
     }
+
+    #[test]
+    #[cfg(feature = "factory")]
+    fn can_bind_to_fallible_factory()
+    {
+        use crate::ptr::TransientPtr;
+
+        type IUserManagerFactory =
+            dyn Fn(i32, String) -> TransientPtr<dyn subjects::IUserManager>;
+
+        let mut mock_di_container = MockDIContainer::new();
+
+        mock_di_container
+            .expect_has_binding::<IUserManagerFactory>()
+            .with(eq(BindingOptions::new()))
+            .return_once(|_| false)
+            .once();
+
+        mock_di_container
+            .expect_set_binding::<IUserManagerFactory>()
+            .withf(|options, _provider| options.name.is_none())
+            .return_once(|_, _provider| ())
+            .once();
+
+        let binding_builder = BindingBuilder::<IUserManagerFactory>::new(
+            &mut mock_di_container,
+            MockDependencyHistory::new,
+        );
+
+        binding_builder
+            .to_fallible_factory(&|_| {
+                Ok::<_, core::fmt::Error>(Box::new(move |_num, _text| {
+                    let user_manager: TransientPtr<dyn subjects::IUserManager> =
+                        TransientPtr::new(subjects::UserManager::new());
+
+                    user_manager
+                }))
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn can_bind_to_fallible_dynamic_value()
+    {
+        use crate::ptr::TransientPtr;
+
+        let mut mock_di_container = MockDIContainer::new();
+
+        mock_di_container
+            .expect_has_binding::<dyn subjects::IUserManager>()
+            .with(eq(BindingOptions::new()))
+            .return_once(|_| false)
+            .once();
+
+        mock_di_container
+            .expect_set_binding::<dyn subjects::IUserManager>()
+            .withf(|options, _provider| options.name.is_none())
+            .return_once(|_, _provider| ())
+            .once();
+
+        let binding_builder = BindingBuilder::<dyn subjects::IUserManager>::new(
+            &mut mock_di_container,
+            MockDependencyHistory::new,
+        );
+
+        binding_builder
+            .to_fallible_dynamic_value(&|_| {
+                let user_manager: TransientPtr<dyn subjects::IUserManager> =
+                    TransientPtr::new(subjects::UserManager::new());
+
+                Ok::<_, core::fmt::Error>(user_manager)
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn bind_generic_without_instances_fails()
+    {
+        let mut mock_di_container = MockDIContainer::new();
+
+        let generic_binding_builder =
+            GenericBindingBuilder::<dyn subjects::IUserManager>::new(
+                &mut mock_di_container,
+                MockDependencyHistory::new,
+            );
+
+        assert!(matches!(
+            generic_binding_builder.to::<subjects::UserManager>(),
+            Err(BindingBuilderError::NoGenericInstances { .. })
+        ));
+    }
 }
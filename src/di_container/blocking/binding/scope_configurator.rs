@@ -1,10 +1,13 @@
 //! Scope configurator for a binding for types inside of a [`DIContainer`].
-use std::marker::PhantomData;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
 
 use crate::di_container::blocking::binding::when_configurator::BindingWhenConfigurator;
 use crate::di_container::BindingOptions;
 use crate::errors::di_container::BindingScopeConfiguratorError;
 use crate::interfaces::injectable::Injectable;
+use crate::private::registry::{BlockingBinder, RegisteredScope};
 use crate::provider::blocking::{SingletonProvider, TransientTypeProvider};
 use crate::ptr::SingletonPtr;
 use crate::util::use_double;
@@ -172,6 +175,152 @@ where
     }
 }
 
+/// Scope configurator for a binding for type `Interface` to a implementation registered
+/// under a key inside a [`DIContainer`].
+pub struct RegisteredBindingScopeConfigurator<'di_container, Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    di_container: &'di_container mut DIContainer,
+    dependency_history_factory: fn() -> DependencyHistory,
+    binder: BlockingBinder,
+
+    interface_phantom: PhantomData<Interface>,
+}
+
+impl<'di_container, Interface>
+    RegisteredBindingScopeConfigurator<'di_container, Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    pub(crate) fn new(
+        di_container: &'di_container mut DIContainer,
+        dependency_history_factory: fn() -> DependencyHistory,
+        binder: BlockingBinder,
+    ) -> Self
+    {
+        Self {
+            di_container,
+            dependency_history_factory,
+            binder,
+            interface_phantom: PhantomData,
+        }
+    }
+
+    /// Configures the binding to be in a transient scope.
+    ///
+    /// This is the default.
+    #[allow(clippy::must_use_candidate)]
+    pub fn in_transient_scope(
+        mut self,
+    ) -> BindingWhenConfigurator<'di_container, Interface>
+    {
+        self.set_in_transient_scope();
+
+        BindingWhenConfigurator::new(self.di_container)
+    }
+
+    /// Configures the binding to be in a singleton scope.
+    ///
+    /// # Errors
+    /// Will return Err if resolving the implementation fails.
+    pub fn in_singleton_scope(
+        self,
+    ) -> Result<
+        BindingWhenConfigurator<'di_container, Interface>,
+        BindingScopeConfiguratorError,
+    >
+    {
+        (self.binder)(
+            self.di_container,
+            self.dependency_history_factory,
+            RegisteredScope::Singleton,
+            None,
+        )?;
+
+        Ok(BindingWhenConfigurator::new(self.di_container))
+    }
+
+    pub(crate) fn set_in_transient_scope(&mut self)
+    {
+        // Binding in a transient scope never resolves the implementation and
+        // therefore cannot fail
+        let _ = (self.binder)(
+            self.di_container,
+            self.dependency_history_factory,
+            RegisteredScope::Transient,
+            None,
+        );
+    }
+}
+
+/// Scope configurator for bindings for the instances of a generic implementation
+/// template inside a [`DIContainer`].
+pub struct GenericBindingScopeConfigurator<'di_container>
+{
+    di_container: &'di_container mut DIContainer,
+    dependency_history_factory: fn() -> DependencyHistory,
+    binders: Vec<BlockingBinder>,
+}
+
+impl<'di_container> GenericBindingScopeConfigurator<'di_container>
+{
+    pub(crate) fn new(
+        di_container: &'di_container mut DIContainer,
+        dependency_history_factory: fn() -> DependencyHistory,
+        binders: Vec<BlockingBinder>,
+    ) -> Self
+    {
+        Self {
+            di_container,
+            dependency_history_factory,
+            binders,
+        }
+    }
+
+    /// Configures the bindings to be in a transient scope.
+    ///
+    /// This is the default.
+    pub fn in_transient_scope(mut self)
+    {
+        self.set_in_transient_scope();
+    }
+
+    /// Configures the bindings to be in a singleton scope.
+    ///
+    /// Every instance of the implementation is resolved as a separate singleton.
+    ///
+    /// # Errors
+    /// Will return Err if resolving a instance of the implementation fails.
+    pub fn in_singleton_scope(self) -> Result<(), BindingScopeConfiguratorError>
+    {
+        for binder in &self.binders {
+            binder(
+                self.di_container,
+                self.dependency_history_factory,
+                RegisteredScope::Singleton,
+                None,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn set_in_transient_scope(&mut self)
+    {
+        for binder in &self.binders {
+            // Binding in a transient scope never resolves the implementation and
+            // therefore cannot fail
+            let _ = binder(
+                self.di_container,
+                self.dependency_history_factory,
+                RegisteredScope::Transient,
+                None,
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests
 {
@@ -223,4 +372,39 @@ mod tests
 
         assert!(binding_scope_configurator.in_singleton_scope().is_ok());
     }
+
+    #[test]
+    fn generic_in_transient_scope_works()
+    {
+        let mut di_container_mock = MockDIContainer::new();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects::IUserManager>()
+            .withf(|options, _provider| options.name.is_none())
+            .return_once(|_name, _provider| ())
+            .once();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects::INumber>()
+            .withf(|options, _provider| options.name.is_none())
+            .return_once(|_name, _provider| ())
+            .once();
+
+        let binding_scope_configurator = GenericBindingScopeConfigurator::new(
+            &mut di_container_mock,
+            MockDependencyHistory::new,
+            vec![
+                crate::private::registry::bind_blocking::<
+                    dyn subjects::IUserManager,
+                    subjects::UserManager,
+                >,
+                crate::private::registry::bind_blocking::<
+                    dyn subjects::INumber,
+                    subjects::Number,
+                >,
+            ],
+        );
+
+        binding_scope_configurator.in_transient_scope();
+    }
 }
@@ -1,6 +1,6 @@
 //! When configurator for a binding for types inside of a [`DIContainer`].
-use std::any::type_name;
-use std::marker::PhantomData;
+use core::any::type_name;
+use core::marker::PhantomData;
 
 use crate::di_container::BindingOptions;
 use crate::errors::di_container::BindingWhenConfiguratorError;
@@ -0,0 +1,193 @@
+//! Reference to a shared [`DIContainer`].
+//!
+//! [`DIContainer`]: crate::di_container::blocking::DIContainer
+use alloc::rc::{Rc, Weak};
+
+use crate::di_container::blocking::DIContainer;
+use crate::errors::di_container::DIContainerError;
+use crate::ptr::SomePtr;
+
+/// Weak reference to a [`DIContainer`] that has been created with
+/// [`DIContainer::new_shared`] or moved into a `Rc` with [`DIContainer::into_shared`].
+///
+/// Can be given as a constructor argument of a [`injectable`] to resolve bindings
+/// after construction. The reference is weak since the DI container owns it's
+/// singletons, which would otherwise keep the DI container alive. Singletons holding a
+/// `ContainerRef` must be bound with [`DIContainer::new_shared`].
+///
+/// # Examples
+/// ```
+/// # use syrette::di_container::blocking::container_ref::ContainerRef;
+/// # use syrette::{injectable, DIContainer};
+/// #
+/// trait IPlugin
+/// {
+///     fn name(&self) -> &'static str;
+/// }
+///
+/// struct GreeterPlugin {}
+///
+/// #[injectable(IPlugin)]
+/// impl GreeterPlugin
+/// {
+///     fn new() -> Self
+///     {
+///         Self {}
+///     }
+/// }
+///
+/// impl IPlugin for GreeterPlugin
+/// {
+///     fn name(&self) -> &'static str
+///     {
+///         "greeter"
+///     }
+/// }
+///
+/// struct PluginHost
+/// {
+///     container_ref: ContainerRef,
+/// }
+///
+/// #[injectable]
+/// impl PluginHost
+/// {
+///     fn new(container_ref: ContainerRef) -> Self
+///     {
+///         Self { container_ref }
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut di_container = DIContainer::new();
+///
+/// di_container
+///     .bind::<dyn IPlugin>()
+///     .to::<GreeterPlugin>()?
+///     .in_transient_scope()
+///     .when_named("greeter")?;
+///
+/// di_container.bind::<PluginHost>().to::<PluginHost>()?;
+///
+/// let di_container = di_container.into_shared();
+///
+/// let plugin_host = di_container.get::<PluginHost>()?.transient()?;
+///
+/// let plugin = plugin_host
+///     .container_ref
+///     .get_named::<dyn IPlugin>("greeter")?
+///     .transient()?;
+///
+/// assert_eq!(plugin.name(), "greeter");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`injectable`]: crate::injectable
+#[derive(Debug, Clone)]
+pub struct ContainerRef
+{
+    di_container: Weak<DIContainer>,
+}
+
+impl ContainerRef
+{
+    pub(crate) fn new(di_container: Weak<DIContainer>) -> Self
+    {
+        Self { di_container }
+    }
+
+    /// Returns the referenced DI container.
+    ///
+    /// # Errors
+    /// Will return `Err` if the DI container has been dropped.
+    pub fn upgrade(&self) -> Result<Rc<DIContainer>, DIContainerError>
+    {
+        self.di_container
+            .upgrade()
+            .ok_or(DIContainerError::ContainerDropped)
+    }
+
+    /// Returns the type bound with `Interface` in the referenced DI container.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - The DI container has been dropped
+    /// - Resolving the binding for `Interface` fails. See [`DIContainer::get`]
+    pub fn get<Interface>(&self) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.upgrade()?.get::<Interface>()
+    }
+
+    /// Returns the type bound with `Interface` and the specified name in the
+    /// referenced DI container.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - The DI container has been dropped
+    /// - Resolving the binding for `Interface` fails. See [`DIContainer::get_named`]
+    pub fn get_named<Interface>(
+        &self,
+        name: &'static str,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.upgrade()?.get_named::<Interface>(name)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::di_container::BindingOptions;
+    use crate::provider::blocking::{MockIProvider, Providable};
+    use crate::ptr::TransientPtr;
+    use crate::test_utils::subjects;
+
+    #[test]
+    fn can_get()
+    {
+        let mut di_container = DIContainer::new();
+
+        let mut mock_provider = MockIProvider::new();
+
+        mock_provider.expect_provide().returning(|_, _| {
+            Ok(Providable::Transient(TransientPtr::new(
+                subjects::UserManager::new(),
+            )))
+        });
+
+        di_container.set_binding::<dyn subjects::IUserManager>(
+            BindingOptions::new(),
+            Box::new(mock_provider),
+        );
+
+        let di_container = di_container.into_shared();
+
+        let container_ref = ContainerRef::new(Rc::downgrade(&di_container));
+
+        assert!(container_ref.get::<dyn subjects::IUserManager>().is_ok());
+    }
+
+    #[test]
+    fn get_fails_when_container_is_dropped()
+    {
+        let di_container = DIContainer::new().into_shared();
+
+        let container_ref = ContainerRef::new(Rc::downgrade(&di_container));
+
+        drop(di_container);
+
+        assert!(matches!(
+            container_ref.get::<dyn subjects::INumber>(),
+            Err(DIContainerError::ContainerDropped)
+        ));
+    }
+}
@@ -0,0 +1,385 @@
+//! Resolution event listeners.
+//!
+//! # Examples
+//! ```
+//! use std::error::Error;
+//!
+//! use syrette::di_container::listener::{ResolutionEvent, ResolutionListener};
+//! use syrette::{injectable, DIContainer};
+//!
+//! struct SlowResolutionLogger;
+//!
+//! impl ResolutionListener for SlowResolutionLogger
+//! {
+//!     fn after_resolve(&self, event: &ResolutionEvent<'_>)
+//!     {
+//!         if event
+//!             .elapsed()
+//!             .map_or(false, |elapsed| elapsed.as_millis() > 100)
+//!         {
+//!             println!("Resolving {} was slow", event.interface());
+//!         }
+//!     }
+//! }
+//!
+//! struct Database {}
+//!
+//! #[injectable]
+//! impl Database
+//! {
+//!     fn new() -> Self
+//!     {
+//!         Self {}
+//!     }
+//! }
+//!
+//! fn main() -> Result<(), Box<dyn Error>>
+//! {
+//!     let mut di_container = DIContainer::new();
+//!
+//!     di_container.add_listener(SlowResolutionLogger);
+//!
+//!     di_container.bind::<Database>().to::<Database>()?;
+//!
+//!     let database = di_container.get::<Database>()?.transient()?;
+//!
+//!     Ok(())
+//! }
+//! ```
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::time::Duration;
+
+use crate::util::{use_double, Error};
+
+use_double!(crate::dependency_history::DependencyHistory);
+
+/// Receives events about bindings being resolved by a DI container.
+///
+/// All of the methods have empty default implementations.
+pub trait ResolutionListener
+{
+    /// Called before a binding is resolved.
+    fn before_resolve(&self, _event: &ResolutionEvent<'_>) {}
+
+    /// Called after a binding is successfully resolved.
+    fn after_resolve(&self, _event: &ResolutionEvent<'_>) {}
+
+    /// Called after a binding failed to be resolved.
+    fn on_failure(&self, _event: &ResolutionEvent<'_>, _err: &dyn Error) {}
+}
+
+/// A event about a binding being resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolutionEvent<'a>
+{
+    interface: &'static str,
+    binding_name: Option<&'a str>,
+    provider_kind: Option<ProviderKind>,
+    depth: usize,
+    elapsed: Option<Duration>,
+}
+
+impl<'a> ResolutionEvent<'a>
+{
+    /// Returns the name of the interface that is being resolved.
+    #[must_use]
+    pub fn interface(&self) -> &'static str
+    {
+        self.interface
+    }
+
+    /// Returns the name of the binding that is being resolved.
+    #[must_use]
+    pub fn binding_name(&self) -> Option<&'a str>
+    {
+        self.binding_name
+    }
+
+    /// Returns the kind of the provider of the binding.
+    ///
+    /// Is `None` before the binding has been provided and if no binding was found.
+    #[must_use]
+    pub fn provider_kind(&self) -> Option<ProviderKind>
+    {
+        self.provider_kind
+    }
+
+    /// Returns the number of implementations the binding is being resolved as a
+    /// dependency of.
+    ///
+    /// Is `0` for bindings resolved directly from the DI container.
+    #[must_use]
+    pub fn depth(&self) -> usize
+    {
+        self.depth
+    }
+
+    /// Returns the time it took to resolve the binding.
+    ///
+    /// Is `None` before the binding has been resolved. Is always zero if the `std`
+    /// feature is disabled.
+    #[must_use]
+    pub fn elapsed(&self) -> Option<Duration>
+    {
+        self.elapsed
+    }
+}
+
+/// The kind of provider of a binding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProviderKind
+{
+    /// A transient binding.
+    Transient,
+
+    /// A singleton binding.
+    Singleton,
+
+    /// A factory binding.
+    #[cfg(feature = "factory")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
+    Factory,
+
+    /// A dynamic value binding.
+    DynamicValue,
+
+    /// A assisted factory binding.
+    #[cfg(feature = "factory")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
+    AssistedFactory,
+}
+
+pub(crate) struct ResolutionListeners<ListenerT: ?Sized>
+{
+    listeners: Vec<Box<ListenerT>>,
+}
+
+impl<ListenerT> ResolutionListeners<ListenerT>
+where
+    ListenerT: ResolutionListener + ?Sized,
+{
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    pub fn add(&mut self, listener: Box<ListenerT>)
+    {
+        self.listeners.push(listener);
+    }
+
+    /// Notifies the listeners that `Interface` is about to be resolved.
+    ///
+    /// Returns `None` if there are no listeners.
+    pub fn before_resolve<'a, Interface>(
+        &self,
+        dependency_history: &DependencyHistory,
+        binding_name: Option<&'a str>,
+    ) -> Option<ResolutionEvent<'a>>
+    where
+        Interface: 'static + ?Sized,
+    {
+        if self.listeners.is_empty() {
+            return None;
+        }
+
+        let event = ResolutionEvent {
+            interface: type_name::<Interface>(),
+            binding_name,
+            provider_kind: None,
+            depth: dependency_history.depth(),
+            elapsed: None,
+        };
+
+        for listener in &self.listeners {
+            listener.before_resolve(&event);
+        }
+
+        Some(event)
+    }
+
+    /// Notifies the listeners about the result of a resolution.
+    pub fn after_resolve<Value, ErrorT>(
+        &self,
+        opt_event: Option<ResolutionEvent<'_>>,
+        provider_kind: Option<ProviderKind>,
+        elapsed: Duration,
+        result: &Result<Value, ErrorT>,
+    ) where
+        ErrorT: Error,
+    {
+        let mut event = match opt_event {
+            Some(event) => event,
+            None => return,
+        };
+
+        event.provider_kind = provider_kind;
+        event.elapsed = Some(elapsed);
+
+        for listener in &self.listeners {
+            match result {
+                Ok(_) => listener.after_resolve(&event),
+                Err(err) => listener.on_failure(&event, err),
+            }
+        }
+    }
+}
+
+impl<ListenerT: ?Sized> Default for ResolutionListeners<ListenerT>
+{
+    fn default() -> Self
+    {
+        Self {
+            listeners: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use alloc::rc::Rc;
+    use core::cell::RefCell;
+    use core::fmt::Error as FmtError;
+
+    use super::*;
+    use crate::dependency_history::MockDependencyHistory;
+    use crate::test_utils::subjects;
+
+    type RecordedEvent = (
+        &'static str,
+        &'static str,
+        Option<String>,
+        Option<ProviderKind>,
+        usize,
+        Option<Duration>,
+    );
+
+    struct EventRecorder
+    {
+        events: Rc<RefCell<Vec<RecordedEvent>>>,
+    }
+
+    impl EventRecorder
+    {
+        fn record(&self, callback: &'static str, event: &ResolutionEvent<'_>)
+        {
+            self.events.borrow_mut().push((
+                callback,
+                event.interface(),
+                event.binding_name().map(ToString::to_string),
+                event.provider_kind(),
+                event.depth(),
+                event.elapsed(),
+            ));
+        }
+    }
+
+    impl ResolutionListener for EventRecorder
+    {
+        fn before_resolve(&self, event: &ResolutionEvent<'_>)
+        {
+            self.record("before", event);
+        }
+
+        fn after_resolve(&self, event: &ResolutionEvent<'_>)
+        {
+            self.record("after", event);
+        }
+
+        fn on_failure(&self, event: &ResolutionEvent<'_>, _err: &dyn Error)
+        {
+            self.record("failure", event);
+        }
+    }
+
+    #[test]
+    fn before_resolve_without_listeners_does_nothing()
+    {
+        let listeners = ResolutionListeners::<dyn ResolutionListener>::new();
+
+        let dependency_history = MockDependencyHistory::new();
+
+        assert!(listeners
+            .before_resolve::<dyn subjects::IUserManager>(&dependency_history, None)
+            .is_none());
+    }
+
+    #[test]
+    fn can_notify_listeners()
+    {
+        let events = Rc::new(RefCell::new(Vec::new()));
+
+        let mut listeners = ResolutionListeners::<dyn ResolutionListener>::new();
+
+        listeners.add(Box::new(EventRecorder {
+            events: events.clone(),
+        }));
+
+        let mut dependency_history = MockDependencyHistory::new();
+
+        dependency_history.expect_depth().return_const(2usize);
+
+        let opt_event = listeners.before_resolve::<dyn subjects::IUserManager>(
+            &dependency_history,
+            Some("special"),
+        );
+
+        listeners.after_resolve(
+            opt_event,
+            Some(ProviderKind::Transient),
+            Duration::from_millis(3),
+            &Ok::<_, FmtError>(()),
+        );
+
+        let opt_event =
+            listeners.before_resolve::<dyn subjects::INumber>(&dependency_history, None);
+
+        listeners.after_resolve(
+            opt_event,
+            None,
+            Duration::from_millis(1),
+            &Err::<(), _>(FmtError),
+        );
+
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                (
+                    "before",
+                    type_name::<dyn subjects::IUserManager>(),
+                    Some("special".to_string()),
+                    None,
+                    2,
+                    None
+                ),
+                (
+                    "after",
+                    type_name::<dyn subjects::IUserManager>(),
+                    Some("special".to_string()),
+                    Some(ProviderKind::Transient),
+                    2,
+                    Some(Duration::from_millis(3))
+                ),
+                (
+                    "before",
+                    type_name::<dyn subjects::INumber>(),
+                    None,
+                    None,
+                    2,
+                    None
+                ),
+                (
+                    "failure",
+                    type_name::<dyn subjects::INumber>(),
+                    None,
+                    None,
+                    2,
+                    Some(Duration::from_millis(1))
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Per-binding resolution metrics.
+//!
+//! # Examples
+//! ```
+//! use std::error::Error;
+//!
+//! use syrette::di_container::listener::ProviderKind;
+//! use syrette::{injectable, DIContainer};
+//!
+//! struct Connection {}
+//!
+//! #[injectable]
+//! impl Connection
+//! {
+//!     fn new() -> Self
+//!     {
+//!         Self {}
+//!     }
+//! }
+//!
+//! fn main() -> Result<(), Box<dyn Error>>
+//! {
+//!     let mut di_container = DIContainer::new();
+//!
+//!     di_container.bind::<Connection>().to::<Connection>()?;
+//!
+//!     for _ in 0..3 {
+//!         di_container.get::<Connection>()?.transient()?;
+//!     }
+//!
+//!     for binding_metrics in di_container.metrics() {
+//!         if binding_metrics.provider_kind() == Some(ProviderKind::Transient)
+//!             && binding_metrics.resolution_count() > 2
+//!         {
+//!             println!(
+//!                 "{} was constructed {} times",
+//!                 binding_metrics.interface(),
+//!                 binding_metrics.resolution_count()
+//!             );
+//!         }
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+use alloc::vec::Vec;
+use core::any::{type_name, TypeId};
+use core::time::Duration;
+
+use hashbrown::HashMap;
+
+use crate::di_container::listener::ProviderKind;
+
+/// Resolution metrics of a single binding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingMetrics
+{
+    interface: &'static str,
+    binding_name: Option<&'static str>,
+    provider_kind: Option<ProviderKind>,
+    resolution_count: u64,
+    failure_count: u64,
+    total_time: Duration,
+    max_time: Duration,
+}
+
+impl BindingMetrics
+{
+    fn new(interface: &'static str, binding_name: Option<&'static str>) -> Self
+    {
+        Self {
+            interface,
+            binding_name,
+            provider_kind: None,
+            resolution_count: 0,
+            failure_count: 0,
+            total_time: Duration::ZERO,
+            max_time: Duration::ZERO,
+        }
+    }
+
+    /// Returns the name of the interface of the binding.
+    #[must_use]
+    pub fn interface(&self) -> &'static str
+    {
+        self.interface
+    }
+
+    /// Returns the name of the binding.
+    #[must_use]
+    pub fn binding_name(&self) -> Option<&'static str>
+    {
+        self.binding_name
+    }
+
+    /// Returns the kind of the provider of the binding.
+    ///
+    /// Is `None` if the binding has never been found.
+    #[must_use]
+    pub fn provider_kind(&self) -> Option<ProviderKind>
+    {
+        self.provider_kind
+    }
+
+    /// Returns the number of times the binding has been successfully resolved.
+    #[must_use]
+    pub fn resolution_count(&self) -> u64
+    {
+        self.resolution_count
+    }
+
+    /// Returns the number of times the binding has failed to be resolved.
+    #[must_use]
+    pub fn failure_count(&self) -> u64
+    {
+        self.failure_count
+    }
+
+    /// Returns the total time spent successfully resolving the binding, including the
+    /// time spent resolving it's dependencies.
+    ///
+    /// Is always zero if the `std` feature is disabled.
+    #[must_use]
+    pub fn total_time(&self) -> Duration
+    {
+        self.total_time
+    }
+
+    /// Returns the longest time a single successful resolution of the binding took.
+    ///
+    /// Is always zero if the `std` feature is disabled.
+    #[must_use]
+    pub fn max_time(&self) -> Duration
+    {
+        self.max_time
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct MetricsStore
+{
+    inner: HashMap<(TypeId, Option<&'static str>), BindingMetrics>,
+}
+
+impl MetricsStore
+{
+    pub fn record<Interface>(
+        &mut self,
+        binding_name: Option<&'static str>,
+        provider_kind: Option<ProviderKind>,
+        elapsed: Duration,
+        succeeded: bool,
+    ) where
+        Interface: 'static + ?Sized,
+    {
+        let binding_metrics = self
+            .inner
+            .entry((TypeId::of::<Interface>(), binding_name))
+            .or_insert_with(|| {
+                BindingMetrics::new(type_name::<Interface>(), binding_name)
+            });
+
+        if provider_kind.is_some() {
+            binding_metrics.provider_kind = provider_kind;
+        }
+
+        if !succeeded {
+            binding_metrics.failure_count += 1;
+            return;
+        }
+
+        binding_metrics.resolution_count += 1;
+        binding_metrics.total_time += elapsed;
+        binding_metrics.max_time = binding_metrics.max_time.max(elapsed);
+    }
+
+    /// Returns the metrics of all bindings, sorted by interface name and binding name.
+    pub fn snapshot(&self) -> Vec<BindingMetrics>
+    {
+        let mut all_metrics = self.inner.values().cloned().collect::<Vec<_>>();
+
+        all_metrics.sort_by(|metrics_a, metrics_b| {
+            (metrics_a.interface, metrics_a.binding_name)
+                .cmp(&(metrics_b.interface, metrics_b.binding_name))
+        });
+
+        all_metrics
+    }
+
+    pub fn clear(&mut self)
+    {
+        self.inner.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::test_utils::subjects;
+
+    #[test]
+    fn can_record()
+    {
+        let mut metrics_store = MetricsStore::default();
+
+        metrics_store.record::<dyn subjects::IUserManager>(
+            None,
+            Some(ProviderKind::Transient),
+            Duration::from_millis(5),
+            true,
+        );
+
+        metrics_store.record::<dyn subjects::IUserManager>(
+            None,
+            Some(ProviderKind::Transient),
+            Duration::from_millis(2),
+            true,
+        );
+
+        metrics_store.record::<dyn subjects::IUserManager>(
+            None,
+            Some(ProviderKind::Transient),
+            Duration::from_millis(7),
+            false,
+        );
+
+        metrics_store.record::<dyn subjects::IUserManager>(
+            Some("special"),
+            None,
+            Duration::from_millis(1),
+            false,
+        );
+
+        assert_eq!(
+            metrics_store.snapshot(),
+            vec![
+                BindingMetrics {
+                    interface: type_name::<dyn subjects::IUserManager>(),
+                    binding_name: None,
+                    provider_kind: Some(ProviderKind::Transient),
+                    resolution_count: 2,
+                    failure_count: 1,
+                    total_time: Duration::from_millis(7),
+                    max_time: Duration::from_millis(5),
+                },
+                BindingMetrics {
+                    interface: type_name::<dyn subjects::IUserManager>(),
+                    binding_name: Some("special"),
+                    provider_kind: None,
+                    resolution_count: 0,
+                    failure_count: 1,
+                    total_time: Duration::ZERO,
+                    max_time: Duration::ZERO,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn can_clear()
+    {
+        let mut metrics_store = MetricsStore::default();
+
+        metrics_store.record::<dyn subjects::INumber>(
+            None,
+            Some(ProviderKind::Singleton),
+            Duration::from_millis(5),
+            true,
+        );
+
+        metrics_store.clear();
+
+        assert!(metrics_store.snapshot().is_empty());
+    }
+}
@@ -1,5 +1,6 @@
 //! Error types for various components of the library.
 
+pub mod casting;
 pub mod di_container;
 pub mod injectable;
 pub mod ptr;
@@ -7,3 +8,7 @@ pub mod ptr;
 #[cfg(feature = "async")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
 pub mod async_di_container;
+
+#[cfg(feature = "config")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "config")))]
+pub mod config;
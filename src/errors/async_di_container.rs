@@ -2,6 +2,7 @@
 //!
 //! [`AsyncDIContainer`]: crate::di_container::asynchronous::AsyncDIContainer
 
+use crate::dependency_history::DependencyHistory;
 use crate::errors::injectable::InjectableError;
 
 /// Error type for [`AsyncDIContainer`].
@@ -49,9 +50,149 @@ pub enum AsyncDIContainerError
         name: Option<&'static str>,
     },
 
+    /// A binding already exists for a interface (and optionally a name).
+    #[error(
+        "A binding already exists for interface '{interface}' {}",
+        .name.map_or_else(String::new, |name| format!("with name '{name}'"))
+    )]
+    BindingConflict
+    {
+        /// The interface that already has a binding.
+        interface: &'static str,
+
+        /// The name of the binding if it has one.
+        name: Option<&'static str>,
+    },
+
     /// A interface has not been marked async.
     #[error("Interface '{0}' has not been marked async")]
     InterfaceNotAsync(&'static str),
+
+    /// The DI container has not been moved into a `Arc` with `into_shared`, so a
+    /// [`AsyncContainerRef`] of it can't be created.
+    ///
+    /// [`AsyncContainerRef`]: crate::di_container::asynchronous::container_ref::AsyncContainerRef
+    #[error("The DI container is not shared")]
+    ContainerNotShared,
+
+    /// The DI container referenced by a [`AsyncContainerRef`] has been dropped.
+    ///
+    /// [`AsyncContainerRef`]: crate::di_container::asynchronous::container_ref::AsyncContainerRef
+    #[error("The referenced DI container has been dropped")]
+    ContainerDropped,
+}
+
+impl AsyncDIContainerError
+{
+    /// Returns the resolution path from the root binding to the binding that failed.
+    ///
+    /// Returns `None` if the error didn't occur while resolving a dependency of
+    /// another binding.
+    #[must_use]
+    pub fn resolution_path(&self) -> Option<&DependencyHistory>
+    {
+        let mut current = self;
+        let mut opt_resolution_path = None;
+
+        while let Self::BindingResolveFailed {
+            reason:
+                InjectableError::AsyncResolveFailed {
+                    reason,
+                    affected: _,
+                    dependency_history,
+                },
+            interface: _,
+        } = current
+        {
+            opt_resolution_path = Some(dependency_history);
+            current = reason;
+        }
+
+        if let Self::BindingResolveFailed {
+            reason: InjectableError::DetectedCircular { dependency_history },
+            interface: _,
+        } = current
+        {
+            return Some(dependency_history);
+        }
+
+        opt_resolution_path
+    }
+
+    /// Returns a report of the error containing the full resolution path from the root
+    /// binding to the binding that failed.
+    ///
+    /// # Examples
+    /// ```text
+    /// App -> dyn IRepo ("primary") -> PgRepo -> dyn IPool: binding not found
+    /// ```
+    #[must_use]
+    pub fn report(&self) -> String
+    {
+        let cause = self.cause_description();
+
+        if let Some(resolution_path) = self.resolution_path() {
+            return format!("{resolution_path}: {cause}");
+        }
+
+        let interface = match self {
+            Self::CastFailed {
+                interface,
+                binding_kind: _,
+            }
+            | Self::BindingResolveFailed {
+                reason: _,
+                interface,
+            }
+            | Self::BindingNotFound {
+                interface,
+                name: None,
+            }
+            | Self::BindingConflict {
+                interface,
+                name: None,
+            }
+            | Self::InterfaceNotAsync(interface) => (*interface).to_string(),
+            Self::BindingNotFound {
+                interface,
+                name: Some(name),
+            }
+            | Self::BindingConflict {
+                interface,
+                name: Some(name),
+            } => format!("{interface} (\"{name}\")"),
+            Self::ContainerNotShared | Self::ContainerDropped => return cause,
+        };
+
+        format!("{interface}: {cause}")
+    }
+
+    pub(crate) fn cause_description(&self) -> String
+    {
+        match self {
+            Self::CastFailed {
+                interface: _,
+                binding_kind,
+            } => format!("failed to cast binding of kind '{binding_kind}'"),
+            Self::BindingResolveFailed {
+                reason,
+                interface: _,
+            } => reason.cause_description(),
+            Self::BindingNotFound {
+                interface: _,
+                name: _,
+            } => "binding not found".to_string(),
+            Self::BindingConflict {
+                interface: _,
+                name: _,
+            } => "binding already exists".to_string(),
+            Self::ContainerNotShared => "DI container is not shared".to_string(),
+            Self::ContainerDropped => "DI container has been dropped".to_string(),
+            Self::InterfaceNotAsync(_) => {
+                "interface has not been marked async".to_string()
+            }
+        }
+    }
 }
 
 /// Error type for [`AsyncBindingBuilder`].
@@ -0,0 +1,40 @@
+//! Error types for casting between interfaces.
+
+/// Error type for the functions of the [`casting`] module.
+///
+/// [`casting`]: crate::casting
+#[derive(thiserror::Error, Debug)]
+pub enum CastingError
+{
+    /// The interface is not declared for the concrete type behind the pointer.
+    #[error("Interface '{to}' is not declared for the concrete type behind '{from}'")]
+    NotDeclared
+    {
+        /// The interface of the pointer.
+        from: &'static str,
+
+        /// The interface that was cast to.
+        to: &'static str,
+    },
+
+    /// The interface is not declared as threadsafe sharable for the concrete type behind
+    /// a threadsafe pointer.
+    #[error(
+        "Interface '{to}' is not declared as threadsafe sharable for the concrete type behind '{from}'"
+    )]
+    NotThreadsafeSharable
+    {
+        /// The interface of the pointer.
+        from: &'static str,
+
+        /// The interface that was cast to.
+        to: &'static str,
+    },
+
+    /// Tried to cast a threadsafe pointer with a interface that doesn't extend
+    /// [`CastFromArc`].
+    ///
+    /// [`CastFromArc`]: crate::casting::CastFromArc
+    #[error("Casting the threadsafe pointer requires '{0}' to extend CastFromArc")]
+    ThreadsafePtr(&'static str),
+}
@@ -0,0 +1,163 @@
+//! Error types for loading and exporting configurations of a [`DIContainer`].
+//!
+//! [`DIContainer`]: crate::di_container::blocking::DIContainer
+
+use std::path::PathBuf;
+
+use crate::errors::di_container::BindingScopeConfiguratorError;
+
+/// Error type for configurations of a [`DIContainer`].
+///
+/// [`DIContainer`]: crate::di_container::blocking::DIContainer
+#[derive(thiserror::Error, Debug)]
+pub enum ConfigError
+{
+    /// Reading a configuration file failed.
+    #[error("Failed to read configuration file '{}'", path.display())]
+    ReadFailed
+    {
+        /// The reason for the problem.
+        #[source]
+        reason: std::io::Error,
+
+        /// The path of the configuration file.
+        path: PathBuf,
+    },
+
+    /// Writing a configuration file failed.
+    #[error("Failed to write configuration file '{}'", path.display())]
+    WriteFailed
+    {
+        /// The reason for the problem.
+        #[source]
+        reason: std::io::Error,
+
+        /// The path of the configuration file.
+        path: PathBuf,
+    },
+
+    /// The format of a configuration file could not be determined.
+    #[error(
+        "Unknown format of configuration file '{}'. Expected a file extension of 'toml' or 'json'",
+        path.display()
+    )]
+    UnknownFormat
+    {
+        /// The path of the configuration file.
+        path: PathBuf,
+    },
+
+    /// Parsing a configuration failed.
+    #[error("Failed to parse configuration: {message}")]
+    ParseFailed
+    {
+        /// The line of the problem, if known.
+        line: Option<usize>,
+
+        /// Description of the problem.
+        message: String,
+    },
+
+    /// Serializing a configuration failed.
+    #[error("Failed to serialize configuration: {message}")]
+    SerializeFailed
+    {
+        /// Description of the problem.
+        message: String,
+    },
+
+    /// No implementation is registered for a interface.
+    #[error("{}No implementation is registered for interface '{interface}'", at_line(*line))]
+    UnknownInterface
+    {
+        /// The interface.
+        interface: String,
+
+        /// The line of the binding, if known.
+        line: Option<usize>,
+    },
+
+    /// No implementation of a interface is registered under a key.
+    #[error(
+        "{}No implementation of interface '{interface}' is registered with key '{key}'. Available keys: [{}]",
+        at_line(*line),
+        available_keys.join(", ")
+    )]
+    UnregisteredKey
+    {
+        /// The interface.
+        interface: String,
+
+        /// The key.
+        key: String,
+
+        /// The keys that implementations of the interface are registered with.
+        available_keys: Vec<&'static str>,
+
+        /// The line of the binding, if known.
+        line: Option<usize>,
+    },
+
+    /// Multiple implementations of a interface are registered under the same key.
+    #[error(
+        "{}Multiple implementations of interface '{interface}' are registered with key '{key}': [{}]",
+        at_line(*line),
+        implementations.join(", ")
+    )]
+    AmbiguousRegisteredKey
+    {
+        /// The interface.
+        interface: String,
+
+        /// The key.
+        key: String,
+
+        /// The implementations registered with the key.
+        implementations: Vec<&'static str>,
+
+        /// The line of the binding, if known.
+        line: Option<usize>,
+    },
+
+    /// A binding already exists for a interface (and optionally a name).
+    #[error(
+        "{}A binding already exists for interface '{interface}' {}",
+        at_line(*line),
+        name.as_ref().map(|name| format!("with name '{name}'")).unwrap_or_default()
+    )]
+    BindingAlreadyExists
+    {
+        /// The interface.
+        interface: String,
+
+        /// The name of the binding if it has one.
+        name: Option<String>,
+
+        /// The line of the binding, if known.
+        line: Option<usize>,
+    },
+
+    /// Binding a interface to it's registered implementation failed.
+    #[error("{}Failed to bind interface '{interface}' with key '{key}'", at_line(*line))]
+    BindingFailed
+    {
+        /// The reason for the problem.
+        #[source]
+        reason: Box<BindingScopeConfiguratorError>,
+
+        /// The interface.
+        interface: String,
+
+        /// The key.
+        key: String,
+
+        /// The line of the binding, if known.
+        line: Option<usize>,
+    },
+}
+
+fn at_line(line: Option<usize>) -> String
+{
+    line.map(|line| format!("Line {line}: "))
+        .unwrap_or_default()
+}
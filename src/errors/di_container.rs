@@ -2,6 +2,11 @@
 //!
 //! [`DIContainer`]: crate::di_container::blocking::DIContainer
 
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::dependency_history::DependencyHistory;
 use crate::errors::injectable::InjectableError;
 
 /// Error type for [`DIContainer`].
@@ -48,6 +53,142 @@ pub enum DIContainerError
         /// The name of the binding if one exists.
         name: Option<String>,
     },
+
+    /// A binding already exists for a interface (and optionally a name).
+    #[error(
+        "A binding already exists for interface '{interface}' {}",
+        name.as_ref().map(|name| format!("with name '{name}'")).unwrap_or_default()
+    )]
+    BindingConflict
+    {
+        /// The interface that already has a binding.
+        interface: &'static str,
+
+        /// The name of the binding if it has one.
+        name: Option<String>,
+    },
+
+    /// The DI container has neither been created with `new_shared` nor moved into a
+    /// `Rc` with `into_shared`, so a [`ContainerRef`] of it can't be created.
+    ///
+    /// [`ContainerRef`]: crate::di_container::blocking::container_ref::ContainerRef
+    #[error("The DI container is not shared")]
+    ContainerNotShared,
+
+    /// The DI container referenced by a [`ContainerRef`] has been dropped.
+    ///
+    /// [`ContainerRef`]: crate::di_container::blocking::container_ref::ContainerRef
+    #[error("The referenced DI container has been dropped")]
+    ContainerDropped,
+}
+
+impl DIContainerError
+{
+    /// Returns the resolution path from the root binding to the binding that failed.
+    ///
+    /// Returns `None` if the error didn't occur while resolving a dependency of
+    /// another binding.
+    #[must_use]
+    pub fn resolution_path(&self) -> Option<&DependencyHistory>
+    {
+        let mut current = self;
+        let mut opt_resolution_path = None;
+
+        while let Self::BindingResolveFailed {
+            reason:
+                InjectableError::ResolveFailed {
+                    reason,
+                    affected: _,
+                    dependency_history,
+                },
+            interface: _,
+        } = current
+        {
+            opt_resolution_path = Some(dependency_history);
+            current = reason;
+        }
+
+        if let Self::BindingResolveFailed {
+            reason: InjectableError::DetectedCircular { dependency_history },
+            interface: _,
+        } = current
+        {
+            return Some(dependency_history);
+        }
+
+        opt_resolution_path
+    }
+
+    /// Returns a report of the error containing the full resolution path from the root
+    /// binding to the binding that failed.
+    ///
+    /// # Examples
+    /// ```text
+    /// App -> dyn IRepo ("primary") -> PgRepo -> dyn IPool: binding not found
+    /// ```
+    #[must_use]
+    pub fn report(&self) -> String
+    {
+        let cause = self.cause_description();
+
+        if let Some(resolution_path) = self.resolution_path() {
+            return format!("{resolution_path}: {cause}");
+        }
+
+        let interface = match self {
+            Self::CastFailed {
+                interface,
+                binding_kind: _,
+            }
+            | Self::BindingResolveFailed {
+                reason: _,
+                interface,
+            }
+            | Self::BindingNotFound {
+                interface,
+                name: None,
+            }
+            | Self::BindingConflict {
+                interface,
+                name: None,
+            } => (*interface).to_string(),
+            Self::BindingNotFound {
+                interface,
+                name: Some(name),
+            }
+            | Self::BindingConflict {
+                interface,
+                name: Some(name),
+            } => format!("{interface} (\"{name}\")"),
+            Self::ContainerNotShared | Self::ContainerDropped => return cause,
+        };
+
+        format!("{interface}: {cause}")
+    }
+
+    pub(crate) fn cause_description(&self) -> String
+    {
+        match self {
+            Self::CastFailed {
+                interface: _,
+                binding_kind,
+            } => format!("failed to cast binding of kind '{binding_kind}'"),
+            Self::BindingResolveFailed {
+                reason,
+                interface: _,
+            } => reason.cause_description(),
+            Self::BindingNotFound {
+                interface: _,
+                name: _,
+            } => "binding not found".to_string(),
+            Self::BindingConflict {
+                interface: _,
+                name: _,
+            } => "binding already exists".to_string(),
+            Self::ContainerNotShared => "DI container is not shared".to_string(),
+            Self::ContainerDropped => "DI container has been dropped".to_string(),
+        }
+    }
 }
 
 /// Error type for [`BindingBuilder`].
@@ -59,6 +200,53 @@ pub enum BindingBuilderError
     /// A binding already exists for a interface.
     #[error("Binding already exists for interface '{0}'")]
     BindingAlreadyExists(&'static str),
+
+    /// No implementation of a interface is registered under a key.
+    #[error(
+        "No implementation of interface '{interface}' is registered with key '{key}'. Available keys: [{}]",
+        .available_keys.join(", ")
+    )]
+    UnregisteredKey
+    {
+        /// The interface.
+        interface: &'static str,
+
+        /// The key.
+        key: String,
+
+        /// The keys that implementations of the interface are registered with.
+        available_keys: Vec<&'static str>,
+    },
+
+    /// Multiple implementations of a interface are registered under the same key.
+    #[error(
+        "Multiple implementations of interface '{interface}' are registered with key '{key}': [{}]",
+        .implementations.join(", ")
+    )]
+    AmbiguousRegisteredKey
+    {
+        /// The interface.
+        interface: &'static str,
+
+        /// The key.
+        key: String,
+
+        /// The implementations registered with the key.
+        implementations: Vec<&'static str>,
+    },
+
+    /// No instances of a generic implementation are registered for a interface.
+    #[error(
+        "No instances of generic implementation '{implementation}' are registered for interface '{interface}'"
+    )]
+    NoGenericInstances
+    {
+        /// The interface.
+        interface: &'static str,
+
+        /// The generic implementation.
+        implementation: &'static str,
+    },
 }
 
 /// Error type for [`BindingScopeConfigurator`].
@@ -2,6 +2,10 @@
 //!
 //! [`Injectable`]: crate::interfaces::injectable::Injectable
 
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+
 use crate::dependency_history::DependencyHistory;
 use crate::errors::di_container::DIContainerError;
 use crate::errors::ptr::SomePtrError;
@@ -22,6 +26,10 @@ pub enum InjectableError
 
         /// The affected injectable type.
         affected: &'static str,
+
+        /// The resolution path from the root binding to the dependency that failed
+        /// to resolve.
+        dependency_history: DependencyHistory,
     },
 
     /// Failed to resolve dependencies.
@@ -35,6 +43,10 @@ pub enum InjectableError
 
         /// The affected injectable type.
         affected: &'static str,
+
+        /// The resolution path from the root binding to the dependency that failed
+        /// to resolve.
+        dependency_history: DependencyHistory,
     },
     /// Detected circular dependencies.
     #[error("Detected circular dependencies. {dependency_history}")]
@@ -55,4 +67,92 @@ pub enum InjectableError
         /// The name of the dependency.
         dependency_name: &'static str,
     },
+
+    /// The constructor of a injectable failed.
+    #[error("The constructor of '{affected}' failed")]
+    ConstructorFailed
+    {
+        /// The error returned by the constructor.
+        #[source]
+        source: Box<dyn crate::util::Error + Send + Sync>,
+
+        /// The affected injectable type.
+        affected: &'static str,
+    },
+
+    /// A fallible factory or dynamic value function failed.
+    #[error("The function bound to '{interface}' failed")]
+    FunctionFailed
+    {
+        /// The error returned by the function.
+        #[source]
+        reason: Box<dyn crate::util::Error + Send + Sync>,
+
+        /// The interface the function is bound to.
+        interface: &'static str,
+    },
+
+    /// Tried to resolve a task scoped injectable outside of a task scope.
+    #[cfg(feature = "async")]
+    #[error("Tried to resolve the task scoped '{affected}' outside of a task scope")]
+    OutsideOfTaskScope
+    {
+        /// The affected injectable type.
+        affected: &'static str,
+    },
+
+    /// A lazy singleton is part of or depends on a dependency cycle and was therefore
+    /// not initialized by a warm-up, or would wait for an initialization that is waiting
+    /// for itself.
+    #[cfg(feature = "async")]
+    #[error("Lazy singleton '{affected}' is part of or depends on a dependency cycle")]
+    InDependencyCycle
+    {
+        /// The affected injectable type.
+        affected: &'static str,
+    },
+}
+
+impl InjectableError
+{
+    /// Returns a short description of the cause of the error. Used when creating error
+    /// reports.
+    pub(crate) fn cause_description(&self) -> String
+    {
+        match self {
+            Self::ResolveFailed {
+                reason,
+                affected: _,
+                dependency_history: _,
+            } => reason.cause_description(),
+            #[cfg(feature = "async")]
+            Self::AsyncResolveFailed {
+                reason,
+                affected: _,
+                dependency_history: _,
+            } => reason.cause_description(),
+            Self::DetectedCircular {
+                dependency_history: _,
+            } => "detected circular dependencies".to_string(),
+            Self::PrepareDependencyFailed {
+                reason,
+                dependency_name,
+            } => format!("failed to prepare dependency '{dependency_name}': {reason}"),
+            Self::ConstructorFailed { source, affected } => {
+                format!("constructor of '{affected}' failed: {source}")
+            }
+            Self::FunctionFailed {
+                reason,
+                interface: _,
+            } => format!("function failed: {reason}"),
+            #[cfg(feature = "async")]
+            Self::OutsideOfTaskScope { affected } => {
+                format!("'{affected}' was resolved outside of a task scope")
+            }
+            #[cfg(feature = "async")]
+            Self::InDependencyCycle { affected } => {
+                format!("'{affected}' is part of or depends on a dependency cycle")
+            }
+        }
+    }
 }
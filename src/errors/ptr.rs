@@ -16,4 +16,16 @@ pub enum SomePtrError
         /// The found smart pointer type.
         found: &'static str,
     },
+
+    /// Tried to convert into a smart pointer type that the found smart pointer type
+    /// cannot be converted into.
+    #[error("Cannot convert smart pointer type {found} into {target}")]
+    InconvertiblePtrType
+    {
+        /// The smart pointer type that was converted into.
+        target: &'static str,
+
+        /// The found smart pointer type.
+        found: &'static str,
+    },
 }
@@ -1,6 +1,79 @@
 //! Future related utilities.
 use std::future::Future;
 use std::pin::Pin;
+use std::task::{Context, Poll};
 
 /// A boxed future.
 pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Returns a future that polls all of the given futures concurrently and resolves to
+/// their outputs, in the same order as the futures were given.
+pub(crate) fn join_all<Fut>(futures: Vec<Fut>) -> JoinAll<Fut>
+where
+    Fut: Future,
+{
+    JoinAll {
+        outputs: futures.iter().map(|_| None).collect(),
+        futures: futures.into_iter().map(|fut| Some(Box::pin(fut))).collect(),
+    }
+}
+
+pub(crate) struct JoinAll<Fut>
+where
+    Fut: Future,
+{
+    futures: Vec<Option<Pin<Box<Fut>>>>,
+    outputs: Vec<Option<Fut::Output>>,
+}
+
+// The futures are boxed and the outputs are never pinned
+impl<Fut> Unpin for JoinAll<Fut> where Fut: Future {}
+
+impl<Fut> Future for JoinAll<Fut>
+where
+    Fut: Future,
+{
+    type Output = Vec<Fut::Output>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output>
+    {
+        let this = self.get_mut();
+
+        for (opt_future, output) in this.futures.iter_mut().zip(this.outputs.iter_mut()) {
+            if let Some(future) = opt_future {
+                if let Poll::Ready(future_output) = future.as_mut().poll(context) {
+                    *output = Some(future_output);
+                    *opt_future = None;
+                }
+            }
+        }
+
+        if this.futures.iter().any(Option::is_some) {
+            return Poll::Pending;
+        }
+
+        Poll::Ready(this.outputs.iter_mut().filter_map(Option::take).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[tokio::test]
+    async fn join_all_works()
+    {
+        let futures = (0..3)
+            .map(|num| async move {
+                for _ in 0..(3 - num) {
+                    tokio::task::yield_now().await;
+                }
+
+                num
+            })
+            .collect();
+
+        assert_eq!(join_all(futures).await, vec![0, 1, 2]);
+    }
+}
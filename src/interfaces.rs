@@ -1,7 +1,18 @@
 //! Various useful interfaces.
 
+pub mod dependency_resolver;
 pub mod injectable;
+pub mod static_injectable;
+pub mod static_resolver;
+
+#[cfg(feature = "factory")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
+pub mod assisted_injectable;
 
 #[cfg(feature = "async")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
 pub mod async_injectable;
+
+#[cfg(feature = "async")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
+pub mod async_dependency_resolver;
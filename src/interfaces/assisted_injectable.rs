@@ -0,0 +1,72 @@
+//! Interface for structs that are created by a factory which is given some of the
+//! constructor arguments by the caller.
+use crate::errors::injectable::InjectableError;
+use crate::ptr::TransientPtr;
+use crate::util::use_double;
+
+use_double!(crate::dependency_history::DependencyHistory);
+
+/// Interface for structs that are created by a factory which is given some of the
+/// constructor arguments by the caller.
+///
+/// The remaining constructor arguments are dependencies resolved from a DI container.
+///
+/// Implemented by the [`injectable`] macro when the `factory` flag is given.
+///
+/// The dependencies are resolved once when the factory is retrieved and are shared
+/// between all created instances, so they can't be transient. This also applies to
+/// dependencies given as a type alias.
+///
+/// # Examples
+/// ```compile_fail
+/// use syrette::injectable;
+/// use syrette::ptr::TransientPtr;
+///
+/// trait IClock {}
+///
+/// trait ITicket {}
+///
+/// type Owned<T> = TransientPtr<T>;
+///
+/// type ITicketFactory = dyn Fn(u32) -> TransientPtr<dyn ITicket>;
+///
+/// struct Ticket
+/// {
+///     number: u32,
+///     clock: Owned<dyn IClock>,
+/// }
+///
+/// // Fails to compile because the clock is transient
+/// #[injectable(ITicket, factory = ITicketFactory)]
+/// impl Ticket
+/// {
+///     fn new(
+///         #[assisted] number: u32,
+///         #[ptr = transient] clock: Owned<dyn IClock>,
+///     ) -> Self
+///     {
+///         Self { number, clock }
+///     }
+/// }
+///
+/// impl ITicket for Ticket {}
+/// ```
+///
+/// [`injectable`]: crate::injectable
+pub trait AssistedInjectable<DIContainerT>: 'static
+{
+    /// The interface of the factory that creates the injectable.
+    type Factory: ?Sized + 'static;
+
+    /// Resolves the dependencies of the injectable and returns a factory that creates
+    /// it.
+    ///
+    /// # Errors
+    /// Will return `Err` if resolving the dependencies fails.
+    fn resolve_factory(
+        di_container: &DIContainerT,
+        dependency_history: DependencyHistory,
+    ) -> Result<TransientPtr<Self::Factory>, InjectableError>
+    where
+        Self: Sized;
+}
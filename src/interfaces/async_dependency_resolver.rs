@@ -0,0 +1,127 @@
+//! Interface for things that asynchronously resolve the dependencies of async
+//! injectables.
+use crate::di_container::asynchronous::container_ref::AsyncContainerRef;
+use crate::di_container::BindingOptions;
+use crate::errors::async_di_container::AsyncDIContainerError;
+use crate::future::BoxFuture;
+use crate::ptr::SomePtr;
+use crate::util::use_double;
+
+use_double!(crate::dependency_history::DependencyHistory);
+
+/// Interface for things that asynchronously resolve the dependencies of async
+/// injectables.
+///
+/// Async injectables created with the [`injectable`] macro resolve their dependencies
+/// using this trait, which makes it possible to test a async injectable without a real
+/// [`AsyncDIContainer`].
+///
+/// # Examples
+/// ```
+/// use std::any::{type_name, Any, TypeId};
+/// use std::collections::HashMap;
+///
+/// use syrette::dependency_history::DependencyHistory;
+/// use syrette::di_container::BindingOptions;
+/// use syrette::errors::async_di_container::AsyncDIContainerError;
+/// use syrette::future::BoxFuture;
+/// use syrette::injectable;
+/// use syrette::interfaces::async_dependency_resolver::AsyncDependencyResolver;
+/// use syrette::interfaces::async_injectable::AsyncInjectable;
+/// use syrette::ptr::{SomePtr, ThreadsafeSingletonPtr};
+///
+/// struct Config
+/// {
+///     verbose: bool,
+/// }
+///
+/// struct Logger
+/// {
+///     config: ThreadsafeSingletonPtr<Config>,
+/// }
+///
+/// #[injectable(async = true)]
+/// impl Logger
+/// {
+///     fn new(config: ThreadsafeSingletonPtr<Config>) -> Self
+///     {
+///         Self { config }
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct FakeResolver
+/// {
+///     singletons: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+/// }
+///
+/// impl AsyncDependencyResolver for FakeResolver
+/// {
+///     fn get_bound<Interface>(
+///         &self,
+///         _dependency_history: DependencyHistory,
+///         _binding_options: BindingOptions<'static>,
+///     ) -> BoxFuture<'_, Result<SomePtr<Interface>, AsyncDIContainerError>>
+///     where
+///         Interface: 'static + ?Sized + Send + Sync,
+///     {
+///         Box::pin(async move {
+///             self.singletons
+///                 .get(&TypeId::of::<Interface>())
+///                 .and_then(|singleton| {
+///                     singleton.downcast_ref::<ThreadsafeSingletonPtr<Interface>>()
+///                 })
+///                 .map(|singleton| SomePtr::ThreadsafeSingleton(singleton.clone()))
+///                 .ok_or_else(|| AsyncDIContainerError::BindingNotFound {
+///                     interface: type_name::<Interface>(),
+///                     name: None,
+///                 })
+///         })
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut fake_resolver = FakeResolver::default();
+///
+/// fake_resolver.singletons.insert(
+///     TypeId::of::<Config>(),
+///     Box::new(ThreadsafeSingletonPtr::new(Config { verbose: true })),
+/// );
+///
+/// let logger = Logger::resolve(&fake_resolver, DependencyHistory::new()).await?;
+///
+/// assert!(logger.config.verbose);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`injectable`]: crate::injectable
+/// [`AsyncDIContainer`]: crate::di_container::asynchronous::AsyncDIContainer
+pub trait AsyncDependencyResolver: Send + Sync
+{
+    /// Returns the type bound with `Interface` where the binding has the specified
+    /// options.
+    ///
+    /// # Errors
+    /// The returned future will resolve to `Err` if resolving the binding for
+    /// `Interface` fails.
+    fn get_bound<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+        binding_options: BindingOptions<'static>,
+    ) -> BoxFuture<'_, Result<SomePtr<Interface>, AsyncDIContainerError>>
+    where
+        Interface: 'static + ?Sized + Send + Sync;
+
+    /// Returns a reference to the DI container that the dependencies are resolved from.
+    ///
+    /// # Errors
+    /// Will return `Err` if the resolver can't be referenced, which the default
+    /// implementation never can.
+    fn container_ref(&self) -> Result<AsyncContainerRef, AsyncDIContainerError>
+    {
+        Err(AsyncDIContainerError::ContainerNotShared)
+    }
+}
@@ -1,7 +1,9 @@
 //! Interface for structs that can be injected into or be injected to.
+use std::any::{type_name, TypeId};
 use std::fmt::Debug;
 use std::future::ready;
 
+use crate::di_container::BindingOptions;
 use crate::errors::injectable::InjectableError;
 use crate::future::BoxFuture;
 use crate::private::cast::CastFromArc;
@@ -24,6 +26,71 @@ pub trait AsyncInjectable<DIContainerT>: CastFromArc
     where
         Self: Sized + 'fut,
         'di_container: 'fut;
+
+    /// Returns the bindings the injectable depends on.
+    ///
+    /// Used to determine the order in which lazy singletons are initialized by
+    /// [`AsyncDIContainer::warm_up`].
+    ///
+    /// [`AsyncDIContainer::warm_up`]: crate::AsyncDIContainer::warm_up
+    #[must_use]
+    fn dependencies() -> Vec<DependencyBinding>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+}
+
+/// A binding that a injectable depends on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DependencyBinding
+{
+    type_id: TypeId,
+    interface: &'static str,
+    binding_options: BindingOptions<'static>,
+}
+
+impl DependencyBinding
+{
+    /// Returns a new `DependencyBinding`.
+    #[must_use]
+    pub fn new<Interface>(binding_options: BindingOptions<'static>) -> Self
+    where
+        Interface: 'static + ?Sized,
+    {
+        Self {
+            type_id: TypeId::of::<Interface>(),
+            interface: type_name::<Interface>(),
+            binding_options,
+        }
+    }
+
+    /// Returns the [`TypeId`] of the interface of the binding.
+    #[must_use]
+    pub fn type_id(&self) -> TypeId
+    {
+        self.type_id
+    }
+
+    /// Returns the name of the interface of the binding.
+    #[must_use]
+    pub fn interface(&self) -> &'static str
+    {
+        self.interface
+    }
+
+    /// Returns the name of the binding.
+    #[must_use]
+    pub fn binding_name(&self) -> Option<&'static str>
+    {
+        self.binding_options.name
+    }
+
+    pub(crate) fn binding_options(&self) -> &BindingOptions<'static>
+    {
+        &self.binding_options
+    }
 }
 
 impl<DIContainerT> Debug for dyn AsyncInjectable<DIContainerT>
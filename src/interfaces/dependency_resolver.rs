@@ -0,0 +1,117 @@
+//! Interface for things that resolve the dependencies of injectables.
+use crate::di_container::blocking::container_ref::ContainerRef;
+use crate::di_container::BindingOptions;
+use crate::errors::di_container::DIContainerError;
+use crate::ptr::SomePtr;
+use crate::util::use_double;
+
+use_double!(crate::dependency_history::DependencyHistory);
+
+/// Interface for things that resolve the dependencies of injectables.
+///
+/// Injectables created with the [`injectable`] macro resolve their dependencies using
+/// this trait, which makes it possible to test a injectable without a real
+/// [`DIContainer`].
+///
+/// # Examples
+/// ```
+/// use std::any::{type_name, Any, TypeId};
+/// use std::collections::HashMap;
+///
+/// use syrette::dependency_history::DependencyHistory;
+/// use syrette::di_container::BindingOptions;
+/// use syrette::errors::di_container::DIContainerError;
+/// use syrette::injectable;
+/// use syrette::interfaces::dependency_resolver::DependencyResolver;
+/// use syrette::interfaces::injectable::Injectable;
+/// use syrette::ptr::{SingletonPtr, SomePtr};
+///
+/// struct Config
+/// {
+///     verbose: bool,
+/// }
+///
+/// struct Logger
+/// {
+///     config: SingletonPtr<Config>,
+/// }
+///
+/// #[injectable]
+/// impl Logger
+/// {
+///     fn new(config: SingletonPtr<Config>) -> Self
+///     {
+///         Self { config }
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct FakeResolver
+/// {
+///     singletons: HashMap<TypeId, Box<dyn Any>>,
+/// }
+///
+/// impl DependencyResolver for FakeResolver
+/// {
+///     fn get_bound<Interface>(
+///         &self,
+///         _dependency_history: DependencyHistory,
+///         _binding_options: BindingOptions<'static>,
+///     ) -> Result<SomePtr<Interface>, DIContainerError>
+///     where
+///         Interface: 'static + ?Sized,
+///     {
+///         self.singletons
+///             .get(&TypeId::of::<Interface>())
+///             .and_then(|singleton| singleton.downcast_ref::<SingletonPtr<Interface>>())
+///             .map(|singleton| SomePtr::Singleton(singleton.clone()))
+///             .ok_or_else(|| DIContainerError::BindingNotFound {
+///                 interface: type_name::<Interface>(),
+///                 name: None,
+///             })
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut fake_resolver = FakeResolver::default();
+///
+/// fake_resolver.singletons.insert(
+///     TypeId::of::<Config>(),
+///     Box::new(SingletonPtr::new(Config { verbose: true })),
+/// );
+///
+/// let logger = Logger::resolve(&fake_resolver, DependencyHistory::new())?;
+///
+/// assert!(logger.config.verbose);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`injectable`]: crate::injectable
+/// [`DIContainer`]: crate::di_container::blocking::DIContainer
+pub trait DependencyResolver
+{
+    /// Returns the type bound with `Interface` where the binding has the specified
+    /// options.
+    ///
+    /// # Errors
+    /// Will return `Err` if resolving the binding for `Interface` fails.
+    fn get_bound<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+        binding_options: BindingOptions<'static>,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized;
+
+    /// Returns a reference to the DI container that the dependencies are resolved from.
+    ///
+    /// # Errors
+    /// Will return `Err` if the resolver can't be referenced, which the default
+    /// implementation never can.
+    fn container_ref(&self) -> Result<ContainerRef, DIContainerError>
+    {
+        Err(DIContainerError::ContainerNotShared)
+    }
+}
@@ -1,5 +1,5 @@
 //! Interface for structs that can be injected into or be injected to.
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 use crate::errors::injectable::InjectableError;
 use crate::private::cast::CastFrom;
@@ -25,7 +25,7 @@ pub trait Injectable<DIContainerT>: CastFrom
 
 impl<DIContainerT> Debug for dyn Injectable<DIContainerT>
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
     {
         f.write_str("{}")
     }
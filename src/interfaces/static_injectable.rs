@@ -0,0 +1,68 @@
+//! Interface for structs that can be constructed by a static container.
+//!
+//! ---
+//!
+//! *This interface is implemented by the [`injectable`] macro and is not meant to be
+//! implemented manually.*
+//!
+//! [`injectable`]: crate::injectable
+
+/// Interface for structs that can be constructed by a static container created with
+/// the [`container`] macro.
+///
+/// Implemented by the [`injectable`] macro for blocking injectables with a infallible
+/// constructor, no named dependencies and no assisted constructor arguments.
+///
+/// The implementation requires `ContainerT` to provide every dependency of the
+/// injectable, which is what makes a missing binding or a dependency cycle a compile
+/// error.
+///
+/// # Examples
+/// ```compile_fail
+/// use syrette::ptr::TransientPtr;
+/// use syrette::{container, injectable};
+///
+/// struct Chicken
+/// {
+///     egg: TransientPtr<Egg>,
+/// }
+///
+/// #[injectable]
+/// impl Chicken
+/// {
+///     fn new(egg: TransientPtr<Egg>) -> Self
+///     {
+///         Self { egg }
+///     }
+/// }
+///
+/// struct Egg
+/// {
+///     chicken: TransientPtr<Chicken>,
+/// }
+///
+/// #[injectable]
+/// impl Egg
+/// {
+///     fn new(chicken: TransientPtr<Chicken>) -> Self
+///     {
+///         Self { chicken }
+///     }
+/// }
+///
+/// // Fails to compile because of the dependency cycle
+/// container! {
+///     struct FarmContainer;
+///
+///     Chicken => Chicken,
+///     Egg => Egg,
+/// }
+/// ```
+///
+/// [`container`]: crate::container
+/// [`injectable`]: crate::injectable
+pub trait StaticInjectable<ContainerT>: Sized
+{
+    /// Constructs the struct with it's dependencies resolved from `container`.
+    fn construct(container: &ContainerT) -> Self;
+}
@@ -0,0 +1,28 @@
+//! Interface for static containers that provide a interface.
+//!
+//! ---
+//!
+//! *This interface is implemented by the [`container`] macro and is not meant to be
+//! implemented manually.*
+//!
+//! [`container`]: crate::container
+
+/// Interface for static containers that provide `Interface`.
+///
+/// Implemented by the [`container`] macro for every binding of the generated
+/// container.
+///
+/// [`container`]: crate::container
+pub trait StaticResolver<Interface>
+where
+    Interface: ?Sized,
+{
+    /// The type bound to `Interface`.
+    type Implementation;
+
+    /// The pointer type that `Interface` is provided as.
+    type Ptr;
+
+    /// Returns `Interface` as it's bound pointer type.
+    fn resolve(&self) -> Self::Ptr;
+}
@@ -1,3 +1,4 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(feature = "factory", feature(unboxed_closures, tuple_trait))]
 #![cfg_attr(doc_cfg, feature(doc_cfg))]
 #![deny(clippy::all)]
@@ -88,6 +89,9 @@
 //! }
 //! ```
 
+extern crate alloc;
+
+pub mod casting;
 pub mod dependency_history;
 pub mod di_container;
 pub mod errors;
@@ -98,11 +102,22 @@ pub mod ptr;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
 pub mod future;
 
+#[cfg(feature = "testing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "testing")))]
+pub mod testing;
+
+#[cfg(feature = "config")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "config")))]
+pub mod config;
+
 #[cfg(feature = "async")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
 pub use di_container::asynchronous::AsyncDIContainer;
 pub use di_container::blocking::DIContainer;
-pub use syrette_macros::{declare_interface, injectable, named};
+#[cfg(feature = "factory")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
+pub use syrette_macros::assisted;
+pub use syrette_macros::{container, declare_interface, injectable, named};
 
 #[doc(hidden)]
 pub mod private;
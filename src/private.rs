@@ -2,5 +2,8 @@
 //! library user with the expansions of the macros in the syrette_macros crate.
 
 pub mod cast;
+pub mod registry;
 
+pub extern crate alloc;
 pub extern crate linkme;
+pub extern crate once_cell;
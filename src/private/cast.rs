@@ -9,20 +9,21 @@
 //! MIT license (LICENSE-MIT or <http://opensource.org/licenses/MIT>)
 //!
 //! at your option.
-use std::any::{Any, TypeId};
-use std::rc::Rc;
-use std::sync::Arc;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use core::any::{type_name, Any, TypeId};
 
-use ahash::AHashMap;
+use hashbrown::HashMap;
 use linkme::distributed_slice;
-use once_cell::sync::Lazy;
+use once_cell::race::OnceBox;
 
 pub mod arc;
 pub mod boxed;
 pub mod error;
 pub mod rc;
 
-pub type BoxedCaster = Box<dyn Any + Send + Sync>;
+pub type BoxedCaster = Box<dyn AnyCaster>;
 
 /// A distributed slice gathering constructor functions for [`Caster`]s.
 ///
@@ -32,16 +33,23 @@ pub type BoxedCaster = Box<dyn Any + Send + Sync>;
 pub static CASTERS: [fn() -> (TypeId, BoxedCaster)] = [..];
 
 /// A `HashMap` mapping `TypeId` of a [`Caster`] to an instance of it.
-static CASTER_MAP: Lazy<AHashMap<(TypeId, TypeId), BoxedCaster>> = Lazy::new(|| {
-    CASTERS
-        .iter()
-        .map(|caster_fn| {
-            let (type_id, caster) = caster_fn();
+static CASTER_MAP: OnceBox<HashMap<(TypeId, TypeId), BoxedCaster>> = OnceBox::new();
 
-            ((type_id, (*caster).type_id()), caster)
-        })
-        .collect()
-});
+fn caster_map() -> &'static HashMap<(TypeId, TypeId), BoxedCaster>
+{
+    CASTER_MAP.get_or_init(|| {
+        Box::new(
+            CASTERS
+                .iter()
+                .map(|caster_fn| {
+                    let (type_id, caster) = caster_fn();
+
+                    ((type_id, caster.as_any().type_id()), caster)
+                })
+                .collect(),
+        )
+    })
+}
 
 type CastBoxFn<Dest> = fn(from: Box<dyn Any>) -> Result<Box<Dest>, CasterError>;
 
@@ -94,6 +102,45 @@ impl<Dest: ?Sized + 'static> Caster<Dest>
     }
 }
 
+/// A [`Caster`] with the type or trait it casts to erased.
+pub trait AnyCaster: Any + Send + Sync
+{
+    /// Returns the caster as [`Any`].
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns the `TypeId` of the type or trait the caster casts to.
+    fn dest_type_id(&self) -> TypeId;
+
+    /// Returns the name of the type or trait the caster casts to.
+    fn dest_type_name(&self) -> &'static str;
+
+    /// Returns whether or not the caster can cast `Arc`s.
+    fn is_arc_castable(&self) -> bool;
+}
+
+impl<Dest: ?Sized + 'static> AnyCaster for Caster<Dest>
+{
+    fn as_any(&self) -> &dyn Any
+    {
+        self
+    }
+
+    fn dest_type_id(&self) -> TypeId
+    {
+        TypeId::of::<Dest>()
+    }
+
+    fn dest_type_name(&self) -> &'static str
+    {
+        type_name::<Dest>()
+    }
+
+    fn is_arc_castable(&self) -> bool
+    {
+        self.opt_cast_arc.is_some()
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum CasterError
 {
@@ -109,19 +156,31 @@ pub enum CasterError
 
 /// Returns a `Caster<Dest>` from a concrete type with the id `type_id` to a type or trait
 /// `Dest`.
-fn get_caster<Dest: ?Sized + 'static>(
+pub(crate) fn get_caster<Dest: ?Sized + 'static>(
     type_id: TypeId,
 ) -> Result<&'static Caster<Dest>, GetCasterError>
 {
-    let any_caster = CASTER_MAP
+    let any_caster = caster_map()
         .get(&(type_id, TypeId::of::<Caster<Dest>>()))
         .ok_or(GetCasterError::NotFound)?;
 
     any_caster
+        .as_any()
         .downcast_ref::<Caster<Dest>>()
         .ok_or(GetCasterError::DowncastFailed)
 }
 
+/// Returns all casters from the concrete type with the id `type_id`.
+pub(crate) fn get_casters_from(
+    type_id: TypeId,
+) -> impl Iterator<Item = &'static dyn AnyCaster>
+{
+    caster_map()
+        .iter()
+        .filter(move |((caster_type_id, _), _)| *caster_type_id == type_id)
+        .map(|(_, caster)| caster.as_ref())
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum GetCasterError
 {
@@ -230,8 +289,8 @@ impl CastFromArc for dyn Any + Sync + Send + 'static
 #[cfg(test)]
 mod tests
 {
-    use std::any::TypeId;
-    use std::fmt::Debug;
+    use core::any::TypeId;
+    use core::fmt::Debug;
 
     use linkme::distributed_slice;
 
@@ -270,4 +329,20 @@ mod tests
         });
         (type_id, caster)
     }
+
+    #[test]
+    fn get_casters_from_works()
+    {
+        let debug_caster = get_casters_from(TypeId::of::<subjects::Ninja>())
+            .find(|caster| caster.dest_type_id() == TypeId::of::<dyn Debug>())
+            .expect("Expected a caster to Debug");
+
+        assert_eq!(
+            debug_caster.dest_type_name(),
+            core::any::type_name::<dyn Debug>()
+        );
+        assert!(debug_caster.is_arc_castable());
+
+        assert!(get_casters_from(TypeId::of::<u128>()).next().is_none());
+    }
 }
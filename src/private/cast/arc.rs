@@ -9,8 +9,8 @@
 //! MIT license (LICENSE-MIT or <http://opensource.org/licenses/MIT>)
 //!
 //! at your option.
-use std::any::type_name;
-use std::sync::Arc;
+use alloc::sync::Arc;
+use core::any::type_name;
 
 use crate::private::cast::error::CastError;
 use crate::private::cast::{get_caster, CastFromArc};
@@ -45,9 +45,9 @@ impl<CastFromSelf: ?Sized + CastFromArc> CastArc for CastFromSelf
 #[cfg(test)]
 mod tests
 {
-    use std::any::Any;
-    use std::fmt::{Debug, Display};
-    use std::sync::Arc;
+    use alloc::sync::Arc;
+    use core::any::Any;
+    use core::fmt::{Debug, Display};
 
     use super::*;
     use crate::test_utils::subjects;
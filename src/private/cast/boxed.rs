@@ -10,7 +10,8 @@
 //!
 //! at your option.
 
-use std::any::type_name;
+use alloc::boxed::Box;
+use core::any::type_name;
 
 use crate::private::cast::error::CastError;
 use crate::private::cast::{get_caster, CastFrom};
@@ -40,8 +41,8 @@ impl<CastFromSelf: ?Sized + CastFrom> CastBox for CastFromSelf
 #[cfg(test)]
 mod tests
 {
-    use std::any::Any;
-    use std::fmt::{Debug, Display};
+    use core::any::Any;
+    use core::fmt::{Debug, Display};
 
     use super::*;
     use crate::test_utils::subjects;
@@ -9,8 +9,8 @@
 //! MIT license (LICENSE-MIT or <http://opensource.org/licenses/MIT>)
 //!
 //! at your option.
-use std::any::type_name;
-use std::rc::Rc;
+use alloc::rc::Rc;
+use core::any::type_name;
 
 use crate::private::cast::error::CastError;
 use crate::private::cast::{get_caster, CastFrom};
@@ -40,8 +40,8 @@ impl<CastFromSelf: ?Sized + CastFrom> CastRc for CastFromSelf
 #[cfg(test)]
 mod tests
 {
-    use std::any::Any;
-    use std::fmt::{Debug, Display};
+    use core::any::Any;
+    use core::fmt::{Debug, Display};
 
     use super::*;
     use crate::test_utils::subjects;
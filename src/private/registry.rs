@@ -0,0 +1,298 @@
+//! Global registry of implementations registered under a key using the `key` flag
+//! and of generic implementation instances listed in the `instances` flag of the
+//! `injectable` macro.
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::TypeId;
+
+use linkme::distributed_slice;
+
+use crate::di_container::BindingOptions;
+use crate::errors::di_container::BindingScopeConfiguratorError;
+use crate::interfaces::injectable::Injectable;
+use crate::provider::blocking::{IProvider, SingletonProvider, TransientTypeProvider};
+use crate::ptr::SingletonPtr;
+use crate::util::use_double;
+
+use_double!(crate::dependency_history::DependencyHistory);
+use_double!(crate::di_container::blocking::DIContainer);
+
+/// The scope to bind a registered implementation in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegisteredScope
+{
+    Transient,
+    Singleton,
+}
+
+/// Function binding a registered implementation to it's interface, optionally with a
+/// name.
+pub type BlockingBinder = fn(
+    &mut DIContainer,
+    fn() -> DependencyHistory,
+    RegisteredScope,
+    Option<&'static str>,
+) -> Result<(), BindingScopeConfiguratorError>;
+
+/// A implementation registered under a key.
+pub struct RegisteredImplementation
+{
+    /// Returns the `TypeId` of the interface the implementation is registered for.
+    pub interface: fn() -> TypeId,
+
+    /// The interface as written in the `injectable` macro.
+    pub interface_key: &'static str,
+
+    /// The key the implementation is registered under.
+    pub key: &'static str,
+
+    /// Returns the `TypeId` of the implementation.
+    pub implementation: fn() -> TypeId,
+
+    /// Returns the type name of the implementation.
+    pub implementation_name: fn() -> &'static str,
+
+    /// Binds the implementation to the interface.
+    pub bind: BlockingBinder,
+}
+
+/// A distributed slice gathering all registered implementations.
+#[distributed_slice]
+pub static REGISTERED_IMPLEMENTATIONS: [RegisteredImplementation] = [..];
+
+/// A instance of a generic implementation template.
+pub struct RegisteredGenericInstance
+{
+    /// Returns the `TypeId` of the interface with the type parameter replaced by
+    /// `GenericParam`.
+    pub template_interface: fn() -> TypeId,
+
+    /// Returns the `TypeId` of the implementation with the type parameter replaced by
+    /// `GenericParam`.
+    pub template_implementation: fn() -> TypeId,
+
+    /// Returns the type name of the interface instance.
+    pub interface_name: fn() -> &'static str,
+
+    /// Returns whether or not the interface instance is bound in a DI container.
+    pub is_bound: fn(&DIContainer) -> bool,
+
+    /// Binds the implementation instance to the interface instance.
+    pub bind: BlockingBinder,
+}
+
+/// A distributed slice gathering all instances of generic implementations.
+#[distributed_slice]
+pub static GENERIC_INSTANCES: [RegisteredGenericInstance] = [..];
+
+/// Binds `Implementation` to `Interface` in the given scope, optionally with a name.
+///
+/// # Errors
+/// Will return Err if resolving the implementation as a singleton fails.
+pub fn bind_blocking<Interface, Implementation>(
+    di_container: &mut DIContainer,
+    dependency_history_factory: fn() -> DependencyHistory,
+    scope: RegisteredScope,
+    opt_name: Option<&'static str>,
+) -> Result<(), BindingScopeConfiguratorError>
+where
+    Interface: 'static + ?Sized,
+    Implementation: Injectable<DIContainer>,
+{
+    let provider: Box<dyn IProvider<DIContainer>> = match scope {
+        RegisteredScope::Transient => {
+            Box::new(TransientTypeProvider::<Implementation, DIContainer>::new())
+        }
+        RegisteredScope::Singleton => {
+            let singleton: SingletonPtr<Implementation> = SingletonPtr::from(
+                Implementation::resolve(di_container, dependency_history_factory())?,
+            );
+
+            Box::new(SingletonProvider::new(singleton))
+        }
+    };
+
+    di_container.set_binding::<Interface>(
+        opt_name
+            .map_or_else(BindingOptions::new, |name| BindingOptions::new().name(name)),
+        provider,
+    );
+
+    Ok(())
+}
+
+/// Returns whether or not the DI container has a unnamed binding for `Interface`.
+#[must_use]
+pub fn is_bound<Interface>(di_container: &DIContainer) -> bool
+where
+    Interface: 'static + ?Sized,
+{
+    di_container.has_binding::<Interface>(BindingOptions::new())
+}
+
+/// Returns all registered implementations.
+#[cfg(feature = "config")]
+pub(crate) fn registered_implementations() -> &'static [RegisteredImplementation]
+{
+    &REGISTERED_IMPLEMENTATIONS
+}
+
+/// Returns the implementations registered for `Interface` under any key.
+pub(crate) fn find_registered<Interface>() -> Vec<&'static RegisteredImplementation>
+where
+    Interface: 'static + ?Sized,
+{
+    find_registered_in::<Interface>(&REGISTERED_IMPLEMENTATIONS)
+}
+
+/// Returns the instances of the generic implementation template `Implementation`
+/// for the interface template `Interface`.
+pub(crate) fn find_generic_instances<Interface, Implementation>(
+) -> Vec<&'static RegisteredGenericInstance>
+where
+    Interface: 'static + ?Sized,
+    Implementation: 'static + ?Sized,
+{
+    find_generic_instances_in::<Interface, Implementation>(&GENERIC_INSTANCES)
+}
+
+fn find_registered_in<Interface>(
+    registered_implementations: &'static [RegisteredImplementation],
+) -> Vec<&'static RegisteredImplementation>
+where
+    Interface: 'static + ?Sized,
+{
+    let interface_type_id = TypeId::of::<Interface>();
+
+    registered_implementations
+        .iter()
+        .filter(|registered| (registered.interface)() == interface_type_id)
+        .collect()
+}
+
+fn find_generic_instances_in<Interface, Implementation>(
+    generic_instances: &'static [RegisteredGenericInstance],
+) -> Vec<&'static RegisteredGenericInstance>
+where
+    Interface: 'static + ?Sized,
+    Implementation: 'static + ?Sized,
+{
+    let interface_type_id = TypeId::of::<Interface>();
+    let implementation_type_id = TypeId::of::<Implementation>();
+
+    generic_instances
+        .iter()
+        .filter(|instance| {
+            (instance.template_interface)() == interface_type_id
+                && (instance.template_implementation)() == implementation_type_id
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::dependency_history::MockDependencyHistory;
+    use crate::di_container::blocking::MockDIContainer;
+    use crate::test_utils::subjects;
+
+    static TEST_REGISTERED_IMPLEMENTATIONS: [RegisteredImplementation; 2] = [
+        RegisteredImplementation {
+            interface: TypeId::of::<dyn subjects::IUserManager>,
+            interface_key: "IUserManager",
+            key: "default",
+            implementation: TypeId::of::<subjects::UserManager>,
+            implementation_name: core::any::type_name::<subjects::UserManager>,
+            bind: bind_blocking::<dyn subjects::IUserManager, subjects::UserManager>,
+        },
+        RegisteredImplementation {
+            interface: TypeId::of::<dyn subjects::INumber>,
+            interface_key: "INumber",
+            key: "default",
+            implementation: TypeId::of::<subjects::Number>,
+            implementation_name: core::any::type_name::<subjects::Number>,
+            bind: bind_blocking::<dyn subjects::INumber, subjects::Number>,
+        },
+    ];
+
+    static TEST_GENERIC_INSTANCES: [RegisteredGenericInstance; 2] = [
+        RegisteredGenericInstance {
+            template_interface: TypeId::of::<dyn subjects::IUserManager>,
+            template_implementation: TypeId::of::<subjects::UserManager>,
+            interface_name: core::any::type_name::<dyn subjects::IUserManager>,
+            is_bound: is_bound::<dyn subjects::IUserManager>,
+            bind: bind_blocking::<dyn subjects::IUserManager, subjects::UserManager>,
+        },
+        RegisteredGenericInstance {
+            template_interface: TypeId::of::<dyn subjects::IUserManager>,
+            template_implementation: TypeId::of::<subjects::Number>,
+            interface_name: core::any::type_name::<dyn subjects::INumber>,
+            is_bound: is_bound::<dyn subjects::INumber>,
+            bind: bind_blocking::<dyn subjects::INumber, subjects::Number>,
+        },
+    ];
+
+    #[test]
+    fn find_registered_in_works()
+    {
+        let registered = find_registered_in::<dyn subjects::IUserManager>(
+            &TEST_REGISTERED_IMPLEMENTATIONS,
+        );
+
+        assert_eq!(registered.len(), 1);
+        assert_eq!(
+            (registered[0].implementation)(),
+            TypeId::of::<subjects::UserManager>()
+        );
+
+        assert!(
+            find_registered_in::<subjects::Ninja>(&TEST_REGISTERED_IMPLEMENTATIONS)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn find_generic_instances_in_works()
+    {
+        let instances = find_generic_instances_in::<
+            dyn subjects::IUserManager,
+            subjects::Number,
+        >(&TEST_GENERIC_INSTANCES);
+
+        assert_eq!(instances.len(), 1);
+        assert_eq!(
+            (instances[0].interface_name)(),
+            core::any::type_name::<dyn subjects::INumber>()
+        );
+
+        assert!(
+            find_generic_instances_in::<dyn subjects::INumber, subjects::Number>(
+                &TEST_GENERIC_INSTANCES
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn bind_blocking_in_transient_scope_works()
+    {
+        let mut di_container_mock = MockDIContainer::new();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects::IUserManager>()
+            .withf(|options, _provider| options.name.is_none())
+            .return_once(|_name, _provider| ())
+            .once();
+
+        assert!(
+            bind_blocking::<dyn subjects::IUserManager, subjects::UserManager>(
+                &mut di_container_mock,
+                MockDependencyHistory::new,
+                RegisteredScope::Transient,
+                None,
+            )
+            .is_ok()
+        );
+    }
+}
@@ -1,11 +1,19 @@
+use std::any::type_name;
 use std::marker::PhantomData;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
 
 use async_trait::async_trait;
+use hashbrown::HashMap;
+use once_cell::sync::Lazy;
+use tokio::sync::OnceCell;
 
 use crate::castable_function::threadsafe::AnyThreadsafeCastableFunction;
+use crate::di_container::asynchronous::task_scope::TaskScope;
+use crate::di_container::listener::ProviderKind;
+use crate::di_container::SingletonForkPolicy;
 use crate::errors::injectable::InjectableError;
-use crate::interfaces::async_injectable::AsyncInjectable;
+use crate::interfaces::async_injectable::{AsyncInjectable, DependencyBinding};
 use crate::ptr::{ThreadsafeSingletonPtr, TransientPtr};
 use crate::util::use_double;
 
@@ -20,6 +28,37 @@ pub enum AsyncProvidable<DIContainerT>
         Arc<dyn crate::castable_function::threadsafe::AnyThreadsafeCastableFunction>,
         ProvidableFunctionKind,
     ),
+    #[cfg(feature = "testing")]
+    Instance(Arc<dyn std::any::Any + Send + Sync>),
+    #[cfg(feature = "testing")]
+    TransientInstance(Arc<dyn std::any::Any + Send + Sync>),
+}
+
+impl<DIContainerT> AsyncProvidable<DIContainerT>
+{
+    pub fn kind(&self) -> ProviderKind
+    {
+        match self {
+            Self::Transient(_) => ProviderKind::Transient,
+            Self::Singleton(_) => ProviderKind::Singleton,
+            #[cfg(feature = "factory")]
+            Self::Function(
+                _,
+                ProvidableFunctionKind::UserCalled
+                | ProvidableFunctionKind::FallibleUserCalled,
+            ) => ProviderKind::Factory,
+            Self::Function(
+                _,
+                ProvidableFunctionKind::Instant
+                | ProvidableFunctionKind::AsyncInstant
+                | ProvidableFunctionKind::FallibleInstant,
+            ) => ProviderKind::DynamicValue,
+            #[cfg(feature = "testing")]
+            Self::Instance(_) => ProviderKind::Singleton,
+            #[cfg(feature = "testing")]
+            Self::TransientInstance(_) => ProviderKind::Transient,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -27,8 +66,11 @@ pub enum ProvidableFunctionKind
 {
     #[cfg(feature = "factory")]
     UserCalled,
+    #[cfg(feature = "factory")]
+    FallibleUserCalled,
     Instant,
     AsyncInstant,
+    FallibleInstant,
 }
 
 #[async_trait]
@@ -44,6 +86,25 @@ where
     ) -> Result<AsyncProvidable<DIContainerT>, InjectableError>;
 
     fn do_clone(&self) -> Box<dyn IAsyncProvider<DIContainerT>>;
+
+    /// Returns a copy of the provider for a forked DI container.
+    fn fork(
+        &self,
+        singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IAsyncProvider<DIContainerT>>;
+
+    /// Returns information about the lazy singleton provided by the provider, or `None`
+    /// if the provider doesn't provide a lazy singleton.
+    fn lazy_singleton_info(&self) -> Option<LazySingletonInfo>
+    {
+        None
+    }
+}
+
+pub struct LazySingletonInfo
+{
+    pub implementation: &'static str,
+    pub dependencies: Vec<DependencyBinding>,
 }
 
 impl<DIContainerT> Clone for Box<dyn IAsyncProvider<DIContainerT>>
@@ -99,6 +160,14 @@ where
     {
         Box::new(self.clone())
     }
+
+    fn fork(
+        &self,
+        _singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IAsyncProvider<DIContainerT>>
+    {
+        self.do_clone()
+    }
 }
 
 impl<InjectableT, DIContainerT> Clone
@@ -157,6 +226,19 @@ where
     {
         Box::new(self.clone())
     }
+
+    fn fork(
+        &self,
+        singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IAsyncProvider<DIContainerT>>
+    {
+        match singleton_policy {
+            SingletonForkPolicy::Share => self.do_clone(),
+            SingletonForkPolicy::Recreate => {
+                Box::new(AsyncLazySingletonProvider::<InjectableT, DIContainerT>::new())
+            }
+        }
+    }
 }
 
 impl<InjectableT, DIContainerT> Clone
@@ -173,6 +255,277 @@ where
     }
 }
 
+pub struct AsyncLazySingletonProvider<InjectableT, DIContainerT>
+where
+    InjectableT: AsyncInjectable<DIContainerT>,
+{
+    singleton_id: usize,
+    singleton: Arc<OnceCell<ThreadsafeSingletonPtr<InjectableT>>>,
+
+    di_container_phantom: PhantomData<DIContainerT>,
+}
+
+impl<InjectableT, DIContainerT> AsyncLazySingletonProvider<InjectableT, DIContainerT>
+where
+    InjectableT: AsyncInjectable<DIContainerT>,
+{
+    pub fn new() -> Self
+    {
+        static NEXT_SINGLETON_ID: AtomicUsize = AtomicUsize::new(0);
+
+        Self {
+            singleton_id: NEXT_SINGLETON_ID.fetch_add(1, Ordering::Relaxed),
+            singleton: Arc::new(OnceCell::new()),
+            di_container_phantom: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<InjectableT, DIContainerT> IAsyncProvider<DIContainerT>
+    for AsyncLazySingletonProvider<InjectableT, DIContainerT>
+where
+    InjectableT: AsyncInjectable<DIContainerT>,
+    DIContainerT: Send + Sync + 'static,
+{
+    async fn provide(
+        &self,
+        di_container: &DIContainerT,
+        dependency_history: DependencyHistory,
+    ) -> Result<AsyncProvidable<DIContainerT>, InjectableError>
+    {
+        // The singleton depends on itself. Waiting for the initialization would never
+        // finish since it's this resolution that is initializing it
+        if dependency_history.contains::<InjectableT>() {
+            return Err(detected_circular::<InjectableT>(dependency_history));
+        }
+
+        // Another resolution may be initializing the singleton while itself waiting for
+        // a singleton this resolution is initializing, in which case neither would ever
+        // finish
+        let _waiting_singleton = match dependency_history.last_initializing_singleton() {
+            Some(waiter_id) => Some(
+                WaitingSingleton::start(
+                    waiter_id,
+                    self.singleton_id,
+                    &dependency_history,
+                )
+                .ok_or_else(|| InjectableError::InDependencyCycle {
+                    affected: type_name::<InjectableT>(),
+                })?,
+            ),
+            None => None,
+        };
+
+        // Concurrent callers wait for the same initialization. If it fails, the cell is
+        // left empty so that the next caller retries it
+        let singleton = self
+            .singleton
+            .get_or_try_init(|| async {
+                let mut dependency_history = dependency_history;
+
+                dependency_history.push_initializing_singleton(self.singleton_id);
+
+                InjectableT::resolve(di_container, dependency_history)
+                    .await
+                    .map(ThreadsafeSingletonPtr::from)
+            })
+            .await?;
+
+        Ok(AsyncProvidable::Singleton(singleton.clone()))
+    }
+
+    fn do_clone(&self) -> Box<dyn IAsyncProvider<DIContainerT>>
+    {
+        Box::new(self.clone())
+    }
+
+    fn fork(
+        &self,
+        singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IAsyncProvider<DIContainerT>>
+    {
+        match singleton_policy {
+            SingletonForkPolicy::Share => self.do_clone(),
+            SingletonForkPolicy::Recreate => Box::new(Self::new()),
+        }
+    }
+
+    fn lazy_singleton_info(&self) -> Option<LazySingletonInfo>
+    {
+        Some(LazySingletonInfo {
+            implementation: type_name::<InjectableT>(),
+            dependencies: InjectableT::dependencies(),
+        })
+    }
+}
+
+impl<InjectableT, DIContainerT> Clone
+    for AsyncLazySingletonProvider<InjectableT, DIContainerT>
+where
+    InjectableT: AsyncInjectable<DIContainerT>,
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            singleton_id: self.singleton_id,
+            singleton: self.singleton.clone(),
+            di_container_phantom: PhantomData,
+        }
+    }
+}
+
+/// The id of the lazy singleton each lazy singleton being initialized is waiting for.
+static WAITING_SINGLETONS: Lazy<Mutex<HashMap<usize, usize>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// A lazy singleton being initialized that is waiting for another lazy singleton.
+///
+/// Stops waiting when dropped.
+struct WaitingSingleton
+{
+    waiter_id: usize,
+}
+
+impl WaitingSingleton
+{
+    /// Starts waiting for a lazy singleton.
+    ///
+    /// Returns `None` if the lazy singleton is, or is waiting for, a lazy singleton the
+    /// given resolution is initializing.
+    fn start(
+        waiter_id: usize,
+        singleton_id: usize,
+        dependency_history: &DependencyHistory,
+    ) -> Option<Self>
+    {
+        let mut waiting_singletons = WAITING_SINGLETONS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let mut opt_current_id = Some(singleton_id);
+
+        while let Some(current_id) = opt_current_id {
+            if dependency_history.is_initializing_singleton(current_id) {
+                return None;
+            }
+
+            opt_current_id = waiting_singletons.get(&current_id).copied();
+        }
+
+        waiting_singletons.insert(waiter_id, singleton_id);
+
+        Some(Self { waiter_id })
+    }
+}
+
+impl Drop for WaitingSingleton
+{
+    fn drop(&mut self)
+    {
+        WAITING_SINGLETONS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.waiter_id);
+    }
+}
+
+pub struct AsyncTaskScopedProvider<InjectableT, DIContainerT>
+where
+    InjectableT: AsyncInjectable<DIContainerT>,
+{
+    binding_id: usize,
+
+    injectable_phantom: PhantomData<InjectableT>,
+    di_container_phantom: PhantomData<DIContainerT>,
+}
+
+impl<InjectableT, DIContainerT> AsyncTaskScopedProvider<InjectableT, DIContainerT>
+where
+    InjectableT: AsyncInjectable<DIContainerT>,
+{
+    pub fn new() -> Self
+    {
+        static NEXT_BINDING_ID: AtomicUsize = AtomicUsize::new(0);
+
+        Self {
+            binding_id: NEXT_BINDING_ID.fetch_add(1, Ordering::Relaxed),
+            injectable_phantom: PhantomData,
+            di_container_phantom: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<InjectableT, DIContainerT> IAsyncProvider<DIContainerT>
+    for AsyncTaskScopedProvider<InjectableT, DIContainerT>
+where
+    InjectableT: AsyncInjectable<DIContainerT>,
+    DIContainerT: Send + Sync + 'static,
+{
+    async fn provide(
+        &self,
+        di_container: &DIContainerT,
+        dependency_history: DependencyHistory,
+    ) -> Result<AsyncProvidable<DIContainerT>, InjectableError>
+    {
+        let task_scope =
+            TaskScope::current().ok_or_else(|| InjectableError::OutsideOfTaskScope {
+                affected: type_name::<InjectableT>(),
+            })?;
+
+        // Same as with lazy singletons, the instance would wait for it's own
+        // initialization forever
+        if dependency_history.contains::<InjectableT>() {
+            return Err(detected_circular::<InjectableT>(dependency_history));
+        }
+
+        let instance_cell = task_scope
+            .instance_cell::<ThreadsafeSingletonPtr<InjectableT>>(self.binding_id);
+
+        let instance = instance_cell
+            .get_or_try_init(|| async {
+                InjectableT::resolve(di_container, dependency_history)
+                    .await
+                    .map(ThreadsafeSingletonPtr::from)
+            })
+            .await?;
+
+        Ok(AsyncProvidable::Singleton(instance.clone()))
+    }
+
+    fn do_clone(&self) -> Box<dyn IAsyncProvider<DIContainerT>>
+    {
+        Box::new(self.clone())
+    }
+
+    fn fork(
+        &self,
+        singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IAsyncProvider<DIContainerT>>
+    {
+        match singleton_policy {
+            SingletonForkPolicy::Share => self.do_clone(),
+            SingletonForkPolicy::Recreate => Box::new(Self::new()),
+        }
+    }
+}
+
+impl<InjectableT, DIContainerT> Clone
+    for AsyncTaskScopedProvider<InjectableT, DIContainerT>
+where
+    InjectableT: AsyncInjectable<DIContainerT>,
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            binding_id: self.binding_id,
+            injectable_phantom: PhantomData,
+            di_container_phantom: PhantomData,
+        }
+    }
+}
+
 pub struct AsyncFunctionProvider
 {
     function: Arc<dyn AnyThreadsafeCastableFunction>,
@@ -214,6 +567,14 @@ where
     {
         Box::new(self.clone())
     }
+
+    fn fork(
+        &self,
+        _singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IAsyncProvider<DIContainerT>>
+    {
+        self.do_clone()
+    }
 }
 
 impl Clone for AsyncFunctionProvider
@@ -227,6 +588,142 @@ impl Clone for AsyncFunctionProvider
     }
 }
 
+#[cfg(feature = "testing")]
+pub struct AsyncInstanceProvider
+{
+    instance: Arc<dyn std::any::Any + Send + Sync>,
+}
+
+#[cfg(feature = "testing")]
+impl AsyncInstanceProvider
+{
+    pub fn new<Interface>(instance: ThreadsafeSingletonPtr<Interface>) -> Self
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        Self {
+            instance: Arc::new(instance),
+        }
+    }
+}
+
+#[cfg(feature = "testing")]
+#[async_trait]
+impl<DIContainerT> IAsyncProvider<DIContainerT> for AsyncInstanceProvider
+where
+    DIContainerT: Send + Sync,
+{
+    async fn provide(
+        &self,
+        _di_container: &DIContainerT,
+        _dependency_history: DependencyHistory,
+    ) -> Result<AsyncProvidable<DIContainerT>, InjectableError>
+    {
+        Ok(AsyncProvidable::Instance(self.instance.clone()))
+    }
+
+    fn do_clone(&self) -> Box<dyn IAsyncProvider<DIContainerT>>
+    {
+        Box::new(self.clone())
+    }
+
+    fn fork(
+        &self,
+        _singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IAsyncProvider<DIContainerT>>
+    {
+        self.do_clone()
+    }
+}
+
+#[cfg(feature = "testing")]
+impl Clone for AsyncInstanceProvider
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            instance: self.instance.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "testing")]
+pub struct AsyncTransientInstanceProvider
+{
+    func: Arc<dyn std::any::Any + Send + Sync>,
+}
+
+#[cfg(feature = "testing")]
+impl AsyncTransientInstanceProvider
+{
+    pub fn new<Interface, Func>(func: Func) -> Self
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+        Func: Fn() -> TransientPtr<Interface> + Send + Sync + 'static,
+    {
+        let func: Box<dyn Fn() -> TransientPtr<Interface> + Send + Sync> = Box::new(func);
+
+        Self {
+            func: Arc::new(func),
+        }
+    }
+}
+
+#[cfg(feature = "testing")]
+#[async_trait]
+impl<DIContainerT> IAsyncProvider<DIContainerT> for AsyncTransientInstanceProvider
+where
+    DIContainerT: Send + Sync,
+{
+    async fn provide(
+        &self,
+        _di_container: &DIContainerT,
+        _dependency_history: DependencyHistory,
+    ) -> Result<AsyncProvidable<DIContainerT>, InjectableError>
+    {
+        Ok(AsyncProvidable::TransientInstance(self.func.clone()))
+    }
+
+    fn do_clone(&self) -> Box<dyn IAsyncProvider<DIContainerT>>
+    {
+        Box::new(self.clone())
+    }
+
+    fn fork(
+        &self,
+        _singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IAsyncProvider<DIContainerT>>
+    {
+        self.do_clone()
+    }
+}
+
+#[cfg(feature = "testing")]
+impl Clone for AsyncTransientInstanceProvider
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            func: self.func.clone(),
+        }
+    }
+}
+
+fn detected_circular<InjectableT>(
+    mut dependency_history: DependencyHistory,
+) -> InjectableError
+where
+    InjectableT: 'static + ?Sized,
+{
+    dependency_history.push::<InjectableT>();
+
+    // Errors can't hold the mock dependency history used by the tests
+    #[cfg(test)]
+    let dependency_history = crate::dependency_history::DependencyHistory::new();
+
+    InjectableError::DetectedCircular { dependency_history }
+}
+
 #[cfg(test)]
 mod tests
 {
@@ -281,6 +778,172 @@ mod tests
         );
     }
 
+    #[tokio::test]
+    async fn async_lazy_singleton_provider_works()
+    {
+        let lazy_singleton_provider = AsyncLazySingletonProvider::<
+            subjects_async::UserManager,
+            MockAsyncDIContainer,
+        >::new();
+
+        let di_container = MockAsyncDIContainer::new();
+
+        let new_dependency_history = || {
+            let mut dependency_history_mock = MockDependencyHistory::new();
+
+            dependency_history_mock
+                .expect_contains::<subjects_async::UserManager>()
+                .return_const(false);
+
+            dependency_history_mock
+                .expect_last_initializing_singleton()
+                .return_const(None);
+
+            dependency_history_mock
+                .expect_push_initializing_singleton()
+                .return_const(());
+
+            dependency_history_mock
+        };
+
+        let first_singleton = match lazy_singleton_provider
+            .do_clone()
+            .provide(&di_container, new_dependency_history())
+            .await
+            .unwrap()
+        {
+            AsyncProvidable::Singleton(singleton) => singleton,
+            _ => panic!("The provided type is not a singleton"),
+        };
+
+        let second_singleton = match lazy_singleton_provider
+            .provide(&di_container, new_dependency_history())
+            .await
+            .unwrap()
+        {
+            AsyncProvidable::Singleton(singleton) => singleton,
+            _ => panic!("The provided type is not a singleton"),
+        };
+
+        assert!(Arc::ptr_eq(&first_singleton, &second_singleton));
+    }
+
+    #[tokio::test]
+    async fn async_lazy_singleton_provider_detects_circular()
+    {
+        let lazy_singleton_provider = AsyncLazySingletonProvider::<
+            subjects_async::UserManager,
+            MockAsyncDIContainer,
+        >::new();
+
+        let di_container = MockAsyncDIContainer::new();
+
+        let mut dependency_history_mock = MockDependencyHistory::new();
+
+        dependency_history_mock
+            .expect_contains::<subjects_async::UserManager>()
+            .return_const(true);
+
+        dependency_history_mock
+            .expect_push::<subjects_async::UserManager>()
+            .return_const(())
+            .once();
+
+        assert!(matches!(
+            lazy_singleton_provider
+                .provide(&di_container, dependency_history_mock)
+                .await,
+            Err(InjectableError::DetectedCircular {
+                dependency_history: _
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn async_task_scoped_provider_works()
+    {
+        use crate::di_container::asynchronous::task_scope::TaskScoped;
+
+        let task_scoped_provider = AsyncTaskScopedProvider::<
+            subjects_async::UserManager,
+            MockAsyncDIContainer,
+        >::new();
+
+        let di_container = MockAsyncDIContainer::new();
+
+        assert!(matches!(
+            task_scoped_provider
+                .provide(&di_container, MockDependencyHistory::new())
+                .await,
+            Err(InjectableError::OutsideOfTaskScope { affected: _ })
+        ));
+
+        let provide_singleton = || async {
+            let mut dependency_history_mock = MockDependencyHistory::new();
+
+            dependency_history_mock
+                .expect_contains::<subjects_async::UserManager>()
+                .return_const(false);
+
+            match task_scoped_provider
+                .do_clone()
+                .provide(&di_container, dependency_history_mock)
+                .await
+                .unwrap()
+            {
+                AsyncProvidable::Singleton(singleton) => singleton,
+                _ => panic!("The provided type is not a singleton"),
+            }
+        };
+
+        let (first_singleton, second_singleton) = TaskScoped::new(|| async {
+            (provide_singleton().await, provide_singleton().await)
+        })
+        .await;
+
+        assert!(Arc::ptr_eq(&first_singleton, &second_singleton));
+
+        let other_scope_singleton = TaskScoped::new(provide_singleton).await;
+
+        assert!(!Arc::ptr_eq(&first_singleton, &other_scope_singleton));
+    }
+
+    #[tokio::test]
+    async fn async_task_scoped_provider_detects_circular()
+    {
+        use crate::di_container::asynchronous::task_scope::TaskScoped;
+
+        let task_scoped_provider = AsyncTaskScopedProvider::<
+            subjects_async::UserManager,
+            MockAsyncDIContainer,
+        >::new();
+
+        let di_container = MockAsyncDIContainer::new();
+
+        let mut dependency_history_mock = MockDependencyHistory::new();
+
+        dependency_history_mock
+            .expect_contains::<subjects_async::UserManager>()
+            .return_const(true);
+
+        dependency_history_mock
+            .expect_push::<subjects_async::UserManager>()
+            .return_const(())
+            .once();
+
+        let result = TaskScoped::new(|| {
+            task_scoped_provider.provide(&di_container, dependency_history_mock)
+        })
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(InjectableError::DetectedCircular {
+                dependency_history: _
+            })
+        ));
+    }
+
     #[tokio::test]
     async fn function_provider_works()
     {
@@ -1,9 +1,20 @@
-use std::marker::PhantomData;
-use std::rc::Rc;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+#[cfg(feature = "config")]
+use core::any::TypeId;
+use core::marker::PhantomData;
+
+use once_cell::unsync::OnceCell;
 
 use crate::castable_function::AnyCastableFunction;
+use crate::di_container::listener::ProviderKind;
+use crate::di_container::SingletonForkPolicy;
 use crate::errors::injectable::InjectableError;
+#[cfg(feature = "factory")]
+use crate::interfaces::assisted_injectable::AssistedInjectable;
 use crate::interfaces::injectable::Injectable;
+#[cfg(feature = "config")]
+use crate::private::registry::RegisteredScope;
 use crate::ptr::{SingletonPtr, TransientPtr};
 use crate::util::use_double;
 
@@ -15,6 +26,39 @@ pub enum Providable<DIContainerType>
     Transient(TransientPtr<dyn Injectable<DIContainerType>>),
     Singleton(SingletonPtr<dyn Injectable<DIContainerType>>),
     Function(Rc<dyn AnyCastableFunction>, ProvidableFunctionKind),
+    #[cfg(feature = "factory")]
+    AssistedFactory(TransientPtr<dyn core::any::Any>),
+    #[cfg(feature = "testing")]
+    Instance(Rc<dyn core::any::Any>),
+    #[cfg(feature = "testing")]
+    TransientInstance(Rc<dyn core::any::Any>),
+}
+
+impl<DIContainerType> Providable<DIContainerType>
+{
+    pub fn kind(&self) -> ProviderKind
+    {
+        match self {
+            Self::Transient(_) => ProviderKind::Transient,
+            Self::Singleton(_) => ProviderKind::Singleton,
+            #[cfg(feature = "factory")]
+            Self::Function(
+                _,
+                ProvidableFunctionKind::UserCalled
+                | ProvidableFunctionKind::FallibleUserCalled,
+            ) => ProviderKind::Factory,
+            Self::Function(
+                _,
+                ProvidableFunctionKind::Instant | ProvidableFunctionKind::FallibleInstant,
+            ) => ProviderKind::DynamicValue,
+            #[cfg(feature = "factory")]
+            Self::AssistedFactory(_) => ProviderKind::AssistedFactory,
+            #[cfg(feature = "testing")]
+            Self::Instance(_) => ProviderKind::Singleton,
+            #[cfg(feature = "testing")]
+            Self::TransientInstance(_) => ProviderKind::Transient,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -22,7 +66,10 @@ pub enum ProvidableFunctionKind
 {
     #[cfg(feature = "factory")]
     UserCalled,
+    #[cfg(feature = "factory")]
+    FallibleUserCalled,
     Instant,
+    FallibleInstant,
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -33,6 +80,20 @@ pub trait IProvider<DIContainerType>
         di_container: &DIContainerType,
         dependency_history: DependencyHistory,
     ) -> Result<Providable<DIContainerType>, InjectableError>;
+
+    /// Returns a copy of the provider for a forked DI container.
+    fn fork(
+        &self,
+        singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IProvider<DIContainerType>>;
+
+    /// Returns the type ID of the provided injectable type and the scope it's provided
+    /// in, if the provider provides a injectable type.
+    #[cfg(feature = "config")]
+    fn provided_type(&self) -> Option<(TypeId, RegisteredScope)>
+    {
+        None
+    }
 }
 
 pub struct TransientTypeProvider<InjectableType, DIContainerType>
@@ -61,6 +122,7 @@ impl<InjectableType, DIContainerType> IProvider<DIContainerType>
     for TransientTypeProvider<InjectableType, DIContainerType>
 where
     InjectableType: Injectable<DIContainerType>,
+    DIContainerType: 'static,
 {
     fn provide(
         &self,
@@ -73,6 +135,20 @@ where
             dependency_history,
         )?))
     }
+
+    fn fork(
+        &self,
+        _singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IProvider<DIContainerType>>
+    {
+        Box::new(Self::new())
+    }
+
+    #[cfg(feature = "config")]
+    fn provided_type(&self) -> Option<(TypeId, RegisteredScope)>
+    {
+        Some((TypeId::of::<InjectableType>(), RegisteredScope::Transient))
+    }
 }
 
 pub struct SingletonProvider<InjectableType, DIContainerType>
@@ -101,6 +177,7 @@ impl<InjectableType, DIContainerType> IProvider<DIContainerType>
     for SingletonProvider<InjectableType, DIContainerType>
 where
     InjectableType: Injectable<DIContainerType>,
+    DIContainerType: 'static,
 {
     fn provide(
         &self,
@@ -110,6 +187,90 @@ where
     {
         Ok(Providable::Singleton(self.singleton.clone()))
     }
+
+    fn fork(
+        &self,
+        singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IProvider<DIContainerType>>
+    {
+        match singleton_policy {
+            SingletonForkPolicy::Share => Box::new(Self::new(self.singleton.clone())),
+            SingletonForkPolicy::Recreate => {
+                Box::new(LazySingletonProvider::<InjectableType, DIContainerType>::new())
+            }
+        }
+    }
+
+    #[cfg(feature = "config")]
+    fn provided_type(&self) -> Option<(TypeId, RegisteredScope)>
+    {
+        Some((TypeId::of::<InjectableType>(), RegisteredScope::Singleton))
+    }
+}
+
+/// Provides a singleton that is created the first time it is provided.
+pub struct LazySingletonProvider<InjectableType, DIContainerType>
+where
+    InjectableType: Injectable<DIContainerType>,
+{
+    singleton: Rc<OnceCell<SingletonPtr<InjectableType>>>,
+
+    di_container_phantom: PhantomData<DIContainerType>,
+}
+
+impl<InjectableType, DIContainerType>
+    LazySingletonProvider<InjectableType, DIContainerType>
+where
+    InjectableType: Injectable<DIContainerType>,
+{
+    pub fn new() -> Self
+    {
+        Self {
+            singleton: Rc::new(OnceCell::new()),
+            di_container_phantom: PhantomData,
+        }
+    }
+}
+
+impl<InjectableType, DIContainerType> IProvider<DIContainerType>
+    for LazySingletonProvider<InjectableType, DIContainerType>
+where
+    InjectableType: Injectable<DIContainerType>,
+    DIContainerType: 'static,
+{
+    fn provide(
+        &self,
+        di_container: &DIContainerType,
+        dependency_history: DependencyHistory,
+    ) -> Result<Providable<DIContainerType>, InjectableError>
+    {
+        let singleton = self.singleton.get_or_try_init(|| {
+            InjectableType::resolve(di_container, dependency_history)
+                .map(SingletonPtr::from)
+        })?;
+
+        Ok(Providable::Singleton(singleton.clone()))
+    }
+
+    fn fork(
+        &self,
+        singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IProvider<DIContainerType>>
+    {
+        match singleton_policy {
+            SingletonForkPolicy::Share => Box::new(Self {
+                singleton: self.singleton.clone(),
+                di_container_phantom: PhantomData,
+            }),
+            SingletonForkPolicy::Recreate => Box::new(Self::new()),
+        }
+    }
+
+    #[cfg(feature = "config")]
+    fn provided_type(&self) -> Option<(TypeId, RegisteredScope)>
+    {
+        Some((TypeId::of::<InjectableType>(), RegisteredScope::Singleton))
+    }
 }
 
 pub struct FunctionProvider
@@ -145,6 +306,153 @@ impl<DIContainerType> IProvider<DIContainerType> for FunctionProvider
             self.providable_func_kind,
         ))
     }
+
+    fn fork(
+        &self,
+        _singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IProvider<DIContainerType>>
+    {
+        Box::new(Self::new(self.function.clone(), self.providable_func_kind))
+    }
+}
+
+#[cfg(feature = "testing")]
+pub struct InstanceProvider
+{
+    instance: Rc<dyn core::any::Any>,
+}
+
+#[cfg(feature = "testing")]
+impl InstanceProvider
+{
+    pub fn new<Interface>(instance: SingletonPtr<Interface>) -> Self
+    where
+        Interface: 'static + ?Sized,
+    {
+        Self {
+            instance: Rc::new(instance),
+        }
+    }
+}
+
+#[cfg(feature = "testing")]
+impl<DIContainerType> IProvider<DIContainerType> for InstanceProvider
+{
+    fn provide(
+        &self,
+        _di_container: &DIContainerType,
+        _dependency_history: DependencyHistory,
+    ) -> Result<Providable<DIContainerType>, InjectableError>
+    {
+        Ok(Providable::Instance(self.instance.clone()))
+    }
+
+    fn fork(
+        &self,
+        _singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IProvider<DIContainerType>>
+    {
+        Box::new(Self {
+            instance: self.instance.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "testing")]
+pub struct TransientInstanceProvider
+{
+    func: Rc<dyn core::any::Any>,
+}
+
+#[cfg(feature = "testing")]
+impl TransientInstanceProvider
+{
+    pub fn new<Interface, Func>(func: Func) -> Self
+    where
+        Interface: 'static + ?Sized,
+        Func: Fn() -> TransientPtr<Interface> + 'static,
+    {
+        let func: Box<dyn Fn() -> TransientPtr<Interface>> = Box::new(func);
+
+        Self {
+            func: Rc::new(func),
+        }
+    }
+}
+
+#[cfg(feature = "testing")]
+impl<DIContainerType> IProvider<DIContainerType> for TransientInstanceProvider
+{
+    fn provide(
+        &self,
+        _di_container: &DIContainerType,
+        _dependency_history: DependencyHistory,
+    ) -> Result<Providable<DIContainerType>, InjectableError>
+    {
+        Ok(Providable::TransientInstance(self.func.clone()))
+    }
+
+    fn fork(
+        &self,
+        _singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IProvider<DIContainerType>>
+    {
+        Box::new(Self {
+            func: self.func.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "factory")]
+pub struct AssistedFactoryProvider<AssistedInjectableType, DIContainerType>
+where
+    AssistedInjectableType: AssistedInjectable<DIContainerType>,
+{
+    assisted_injectable_phantom: PhantomData<AssistedInjectableType>,
+    di_container_phantom: PhantomData<DIContainerType>,
+}
+
+#[cfg(feature = "factory")]
+impl<AssistedInjectableType, DIContainerType>
+    AssistedFactoryProvider<AssistedInjectableType, DIContainerType>
+where
+    AssistedInjectableType: AssistedInjectable<DIContainerType>,
+{
+    pub fn new() -> Self
+    {
+        Self {
+            assisted_injectable_phantom: PhantomData,
+            di_container_phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "factory")]
+impl<AssistedInjectableType, DIContainerType> IProvider<DIContainerType>
+    for AssistedFactoryProvider<AssistedInjectableType, DIContainerType>
+where
+    AssistedInjectableType: AssistedInjectable<DIContainerType>,
+    DIContainerType: 'static,
+{
+    fn provide(
+        &self,
+        di_container: &DIContainerType,
+        dependency_history: DependencyHistory,
+    ) -> Result<Providable<DIContainerType>, InjectableError>
+    {
+        let factory =
+            AssistedInjectableType::resolve_factory(di_container, dependency_history)?;
+
+        Ok(Providable::AssistedFactory(TransientPtr::new(factory)))
+    }
+
+    fn fork(
+        &self,
+        _singleton_policy: SingletonForkPolicy,
+    ) -> Box<dyn IProvider<DIContainerType>>
+    {
+        Box::new(Self::new())
+    }
 }
 
 #[cfg(test)]
@@ -195,11 +503,53 @@ mod tests
         );
     }
 
+    #[test]
+    fn singleton_provider_fork_works()
+    {
+        let singleton = SingletonPtr::new(subjects::UserManager {});
+
+        let singleton_provider = SingletonProvider::<
+            subjects::UserManager,
+            MockDIContainer,
+        >::new(singleton.clone());
+
+        let di_container = MockDIContainer::new();
+
+        let provide_singleton = |provider: &dyn IProvider<MockDIContainer>| match provider
+            .provide(&di_container, MockDependencyHistory::new())
+            .unwrap()
+        {
+            Providable::Singleton(singleton) => singleton,
+            _ => panic!("The provided type is not a singleton"),
+        };
+
+        let shared_fork = singleton_provider.fork(SingletonForkPolicy::Share);
+
+        assert!(Rc::ptr_eq(
+            &provide_singleton(shared_fork.as_ref()),
+            &(singleton.clone() as SingletonPtr<dyn Injectable<MockDIContainer>>)
+        ));
+
+        let recreated_fork = singleton_provider.fork(SingletonForkPolicy::Recreate);
+
+        let recreated_singleton = provide_singleton(recreated_fork.as_ref());
+
+        assert!(!Rc::ptr_eq(
+            &recreated_singleton,
+            &(singleton as SingletonPtr<dyn Injectable<MockDIContainer>>)
+        ));
+
+        assert!(Rc::ptr_eq(
+            &recreated_singleton,
+            &provide_singleton(recreated_fork.as_ref())
+        ));
+    }
+
     #[test]
     fn function_provider_works()
     {
-        use std::any::Any;
-        use std::rc::Rc;
+        use alloc::rc::Rc;
+        use core::any::Any;
 
         use crate::castable_function::AnyCastableFunction;
 
@@ -231,4 +581,52 @@ mod tests
             )
         );
     }
+
+    #[test]
+    #[cfg(feature = "factory")]
+    fn assisted_factory_provider_works()
+    {
+        use crate::errors::injectable::InjectableError;
+        use crate::interfaces::assisted_injectable::AssistedInjectable;
+
+        type IUserManagerFactory =
+            dyn Fn(i128) -> TransientPtr<dyn subjects::IUserManager>;
+
+        struct UserManagerFactoryMarker;
+
+        impl AssistedInjectable<MockDIContainer> for UserManagerFactoryMarker
+        {
+            type Factory = IUserManagerFactory;
+
+            fn resolve_factory(
+                _di_container: &MockDIContainer,
+                _dependency_history: DependencyHistory,
+            ) -> Result<TransientPtr<Self::Factory>, InjectableError>
+            {
+                Ok(TransientPtr::new(|_user_id| {
+                    TransientPtr::new(subjects::UserManager::new())
+                        as TransientPtr<dyn subjects::IUserManager>
+                }))
+            }
+        }
+
+        let assisted_factory_provider =
+            AssistedFactoryProvider::<UserManagerFactoryMarker, MockDIContainer>::new();
+
+        let di_container = MockDIContainer::new();
+
+        let providable = assisted_factory_provider
+            .provide(&di_container, MockDependencyHistory::new())
+            .unwrap();
+
+        let factory = match providable {
+            Providable::AssistedFactory(factory) => factory,
+            _ => panic!("The provided type is not a Providable::AssistedFactory"),
+        };
+
+        assert!(
+            factory.is::<TransientPtr<IUserManagerFactory>>(),
+            "The provided factory has the wrong type"
+        );
+    }
 }
@@ -1,6 +1,8 @@
 //! Smart pointer type aliases.
-use std::rc::Rc;
-use std::sync::Arc;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use core::ops::Deref;
 
 use paste::paste;
 
@@ -15,6 +17,11 @@ pub type SingletonPtr<Interface> = Rc<Interface>;
 /// A threadsafe smart pointer to a interface in the singleton scope.
 pub type ThreadsafeSingletonPtr<Interface> = Arc<Interface>;
 
+/// A smart pointer to a interface in any scope.
+///
+/// Constructor arguments of this type accept bindings regardless of their scope.
+pub type InjectedPtr<Interface> = SomePtr<Interface>;
+
 /// A smart pointer to a factory.
 #[cfg(feature = "factory")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
@@ -105,4 +112,143 @@ where
         cfg(feature = "factory"),
         cfg_attr(doc_cfg, doc(cfg(feature = "factory")))
     );
+
+    /// Converts into a [`Rc`] regardless of the scope.
+    ///
+    /// # Errors
+    /// Will return Err if it's a threadsafe smart pointer.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::ptr::SomePtr;
+    /// #
+    /// let ptr = SomePtr::Transient(Box::new(42));
+    ///
+    /// assert_eq!(*ptr.into_rc().unwrap(), 42);
+    /// ```
+    pub fn into_rc(self) -> Result<Rc<Interface>, SomePtrError>
+    {
+        match self {
+            Self::Transient(transient_ptr) => Ok(Rc::from(transient_ptr)),
+            Self::Singleton(singleton_ptr) => Ok(singleton_ptr),
+            #[cfg(feature = "factory")]
+            Self::Factory(factory_ptr) => Ok(factory_ptr),
+            threadsafe_ptr => Err(SomePtrError::InconvertiblePtrType {
+                target: "Rc",
+                found: threadsafe_ptr.into(),
+            }),
+        }
+    }
+
+    /// Converts into a [`Arc`] regardless of the scope.
+    ///
+    /// # Errors
+    /// Will return Err if it's a non-threadsafe shared smart pointer.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::ptr::SomePtr;
+    /// #
+    /// let ptr = SomePtr::Transient(Box::new(42));
+    ///
+    /// assert_eq!(*ptr.into_arc().unwrap(), 42);
+    /// ```
+    pub fn into_arc(self) -> Result<Arc<Interface>, SomePtrError>
+    {
+        match self {
+            Self::Transient(transient_ptr) => Ok(Arc::from(transient_ptr)),
+            Self::ThreadsafeSingleton(threadsafe_singleton_ptr) => {
+                Ok(threadsafe_singleton_ptr)
+            }
+            #[cfg(feature = "factory")]
+            Self::ThreadsafeFactory(threadsafe_factory_ptr) => Ok(threadsafe_factory_ptr),
+            shared_ptr => Err(SomePtrError::InconvertiblePtrType {
+                target: "Arc",
+                found: shared_ptr.into(),
+            }),
+        }
+    }
+}
+
+impl<Interface> AsRef<Interface> for SomePtr<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    /// Returns a reference to the interface regardless of the scope.
+    fn as_ref(&self) -> &Interface
+    {
+        match self {
+            Self::Transient(transient_ptr) => transient_ptr,
+            Self::Singleton(singleton_ptr) => singleton_ptr,
+            #[cfg(feature = "factory")]
+            Self::Factory(factory_ptr) => factory_ptr,
+            Self::ThreadsafeSingleton(threadsafe_singleton_ptr) => {
+                threadsafe_singleton_ptr
+            }
+            #[cfg(feature = "factory")]
+            Self::ThreadsafeFactory(threadsafe_factory_ptr) => threadsafe_factory_ptr,
+        }
+    }
+}
+
+impl<Interface> Deref for SomePtr<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    type Target = Interface;
+
+    fn deref(&self) -> &Self::Target
+    {
+        self.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn as_ref_works_for_every_scope()
+    {
+        assert_eq!(*SomePtr::Transient(Box::new(1)).as_ref(), 1);
+        assert_eq!(*SomePtr::Singleton(Rc::new(2)).as_ref(), 2);
+        assert_eq!(*SomePtr::ThreadsafeSingleton(Arc::new(3)).as_ref(), 3);
+    }
+
+    #[test]
+    fn can_deref()
+    {
+        let ptr: SomePtr<str> = SomePtr::Singleton(Rc::from("hello"));
+
+        assert_eq!(ptr.len(), 5);
+    }
+
+    #[test]
+    fn into_rc_works()
+    {
+        assert!(SomePtr::Singleton(Rc::new(1)).into_rc().is_ok());
+
+        assert!(matches!(
+            SomePtr::ThreadsafeSingleton(Arc::new(1)).into_rc(),
+            Err(SomePtrError::InconvertiblePtrType {
+                target: "Rc",
+                found: "ThreadsafeSingleton"
+            })
+        ));
+    }
+
+    #[test]
+    fn into_arc_works()
+    {
+        assert!(SomePtr::ThreadsafeSingleton(Arc::new(1)).into_arc().is_ok());
+
+        assert!(matches!(
+            SomePtr::Singleton(Rc::new(1)).into_arc(),
+            Err(SomePtrError::InconvertiblePtrType {
+                target: "Arc",
+                found: "Singleton"
+            })
+        ));
+    }
 }
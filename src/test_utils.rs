@@ -14,8 +14,10 @@ pub mod subjects
 
     pub trait IUserManager
     {
+        #[allow(dead_code)] // Not used by every test
         fn add_user(&self, user_id: i128);
 
+        #[allow(dead_code)] // Not used by every test
         fn remove_user(&self, user_id: i128);
     }
 
@@ -64,6 +66,7 @@ pub mod subjects
     {
         fn get(&self) -> i32;
 
+        #[allow(dead_code)] // Not used by every test
         fn set(&mut self, number: i32);
     }
 
@@ -149,8 +152,10 @@ pub mod subjects_async
 
     pub trait IUserManager: Send + Sync
     {
+        #[allow(dead_code)] // Not used by every test
         fn add_user(&self, user_id: i128);
 
+        #[allow(dead_code)] // Not used by every test
         fn remove_user(&self, user_id: i128);
     }
 
@@ -200,6 +205,7 @@ pub mod subjects_async
     {
         fn get(&self) -> i32;
 
+        #[allow(dead_code)] // Not used by every test
         fn set(&mut self, number: i32);
     }
 
@@ -273,6 +279,7 @@ pub mod mocks
         use async_trait::async_trait;
         use mockall::mock;
 
+        use crate::di_container::SingletonForkPolicy;
         use crate::errors::injectable::InjectableError;
         use crate::provider::r#async::{AsyncProvidable, IAsyncProvider};
         use crate::util::use_double;
@@ -296,12 +303,17 @@ pub mod mocks
 
                 fn do_clone(&self) ->
                     Box<dyn IAsyncProvider<DIContainerT>>;
+
+                fn fork(
+                    &self,
+                    singleton_policy: SingletonForkPolicy
+                ) -> Box<dyn IAsyncProvider<DIContainerT>>;
             }
         }
     }
 }
 
-#[cfg(all(feature = "async"))]
+#[cfg(feature = "async")]
 macro_rules! async_closure {
     (|$($args: ident),*| { $($inner: stmt);* }) => {
         Box::new(|$($args),*| {
@@ -315,5 +327,5 @@ macro_rules! async_closure {
     };
 }
 
-#[cfg(all(feature = "async"))]
+#[cfg(feature = "async")]
 pub(crate) use async_closure;
@@ -0,0 +1,373 @@
+//! Utilities for tests of code that uses DI containers.
+//!
+//! # Examples
+//! ```
+//! use std::error::Error;
+//!
+//! use syrette::ptr::TransientPtr;
+//! use syrette::{injectable, DIContainer};
+//!
+//! trait IMailer
+//! {
+//!     fn send(&self, to: &str) -> bool;
+//! }
+//!
+//! struct SmtpMailer {}
+//!
+//! #[injectable(IMailer)]
+//! impl SmtpMailer
+//! {
+//!     fn new() -> Self
+//!     {
+//!         Self {}
+//!     }
+//! }
+//!
+//! impl IMailer for SmtpMailer
+//! {
+//!     fn send(&self, _to: &str) -> bool
+//!     {
+//!         // Talk to a SMTP server here
+//!         true
+//!     }
+//! }
+//!
+//! struct FailingMailer {}
+//!
+//! impl IMailer for FailingMailer
+//! {
+//!     fn send(&self, _to: &str) -> bool
+//!     {
+//!         false
+//!     }
+//! }
+//!
+//! fn main() -> Result<(), Box<dyn Error>>
+//! {
+//!     let mut di_container = DIContainer::new();
+//!
+//!     di_container
+//!         .bind::<dyn IMailer>()
+//!         .to::<SmtpMailer>()?
+//!         .in_transient_scope()
+//!         .when_named("support")?;
+//!
+//!     let di_container = di_container
+//!         .override_binding::<dyn IMailer>()
+//!         .when_named("support")
+//!         .to_transient(|| TransientPtr::new(FailingMailer {}));
+//!
+//!     let mailer = di_container.get_named::<dyn IMailer>("support")?;
+//!
+//!     assert!(!mailer.transient()?.send("someone@example.com"));
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # Pointer types
+//! The pointer type of a overridden binding is decided by the override and not by the
+//! original binding. Use `to_instance` when the binding is resolved as a singleton and
+//! `to_transient` when it is resolved as a transient, for example by a injectable
+//! taking a [`TransientPtr`] of the interface.
+//!
+//! # Shared DI containers
+//! Overriding a binding requires mutable access to the DI container. A DI container
+//! shared using `new_shared` or `into_shared` can therefore not have it's bindings
+//! overridden.
+use alloc::boxed::Box;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+#[cfg(feature = "async")]
+use crate::di_container::asynchronous::AsyncDIContainer;
+use crate::di_container::blocking::DIContainer;
+use crate::di_container::BindingOptions;
+use crate::provider::blocking::{IProvider, InstanceProvider, TransientInstanceProvider};
+#[cfg(feature = "async")]
+use crate::provider::r#async::{
+    AsyncInstanceProvider,
+    AsyncTransientInstanceProvider,
+    IAsyncProvider,
+};
+#[cfg(feature = "async")]
+use crate::ptr::ThreadsafeSingletonPtr;
+use crate::ptr::{SingletonPtr, TransientPtr};
+
+/// Builder for overriding a binding of a [`DIContainer`].
+///
+/// Returned by [`DIContainer::override_binding`].
+#[must_use = "the binding is not overridden until a instance is given"]
+pub struct BindingOverrideBuilder<'di_container, Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    di_container: &'di_container mut DIContainer,
+    binding_options: BindingOptions<'static>,
+    interface_phantom: PhantomData<Interface>,
+}
+
+impl<'di_container, Interface> BindingOverrideBuilder<'di_container, Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    pub(crate) fn new(di_container: &'di_container mut DIContainer) -> Self
+    {
+        Self {
+            di_container,
+            binding_options: BindingOptions::new(),
+            interface_phantom: PhantomData,
+        }
+    }
+
+    /// Overrides the binding with the given name instead of the unnamed binding.
+    pub fn when_named(mut self, name: &'static str) -> Self
+    {
+        self.binding_options = self.binding_options.name(name);
+
+        self
+    }
+
+    /// Overrides the binding so that every resolution of it returns `instance` as a
+    /// singleton.
+    pub fn to_instance(
+        self,
+        instance: SingletonPtr<Interface>,
+    ) -> BindingOverrideGuard<'di_container, Interface>
+    {
+        self.override_with(Box::new(InstanceProvider::new(instance)))
+    }
+
+    /// Overrides the binding so that every resolution of it returns a new transient
+    /// created by `func`.
+    pub fn to_transient<Func>(
+        self,
+        func: Func,
+    ) -> BindingOverrideGuard<'di_container, Interface>
+    where
+        Func: Fn() -> TransientPtr<Interface> + 'static,
+    {
+        self.override_with(Box::new(TransientInstanceProvider::new(func)))
+    }
+
+    fn override_with(
+        self,
+        provider: Box<dyn IProvider<DIContainer>>,
+    ) -> BindingOverrideGuard<'di_container, Interface>
+    {
+        let original_provider = self
+            .di_container
+            .remove_binding::<Interface>(self.binding_options.clone());
+
+        self.di_container
+            .set_binding::<Interface>(self.binding_options.clone(), provider);
+
+        BindingOverrideGuard {
+            di_container: self.di_container,
+            binding_options: self.binding_options,
+            original_provider,
+            interface_phantom: PhantomData,
+        }
+    }
+}
+
+/// Guard of a overridden binding of a [`DIContainer`].
+///
+/// The original binding is restored when the guard is dropped. The DI container can be
+/// used through the guard while the binding is overridden.
+#[must_use = "the original binding is restored when the guard is dropped"]
+pub struct BindingOverrideGuard<'di_container, Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    di_container: &'di_container mut DIContainer,
+    binding_options: BindingOptions<'static>,
+    original_provider: Option<Box<dyn IProvider<DIContainer>>>,
+    interface_phantom: PhantomData<Interface>,
+}
+
+impl<Interface> Deref for BindingOverrideGuard<'_, Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    type Target = DIContainer;
+
+    fn deref(&self) -> &Self::Target
+    {
+        self.di_container
+    }
+}
+
+impl<Interface> DerefMut for BindingOverrideGuard<'_, Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target
+    {
+        self.di_container
+    }
+}
+
+impl<Interface> Drop for BindingOverrideGuard<'_, Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    fn drop(&mut self)
+    {
+        let binding_options = self.binding_options.clone();
+
+        match self.original_provider.take() {
+            Some(original_provider) => {
+                self.di_container
+                    .set_binding::<Interface>(binding_options, original_provider);
+            }
+            None => {
+                self.di_container
+                    .remove_binding::<Interface>(binding_options);
+            }
+        }
+    }
+}
+
+/// Builder for overriding a binding of a [`AsyncDIContainer`].
+///
+/// Returned by [`AsyncDIContainer::override_binding`].
+#[cfg(feature = "async")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
+#[must_use = "the binding is not overridden until a instance is given"]
+pub struct AsyncBindingOverrideBuilder<'di_container, Interface>
+where
+    Interface: 'static + ?Sized + Send + Sync,
+{
+    di_container: &'di_container mut AsyncDIContainer,
+    binding_options: BindingOptions<'static>,
+    interface_phantom: PhantomData<Interface>,
+}
+
+#[cfg(feature = "async")]
+impl<'di_container, Interface> AsyncBindingOverrideBuilder<'di_container, Interface>
+where
+    Interface: 'static + ?Sized + Send + Sync,
+{
+    pub(crate) fn new(di_container: &'di_container mut AsyncDIContainer) -> Self
+    {
+        Self {
+            di_container,
+            binding_options: BindingOptions::new(),
+            interface_phantom: PhantomData,
+        }
+    }
+
+    /// Overrides the binding with the given name instead of the unnamed binding.
+    pub fn when_named(mut self, name: &'static str) -> Self
+    {
+        self.binding_options = self.binding_options.name(name);
+
+        self
+    }
+
+    /// Overrides the binding so that every resolution of it returns `instance` as a
+    /// threadsafe singleton.
+    pub fn to_instance(
+        self,
+        instance: ThreadsafeSingletonPtr<Interface>,
+    ) -> AsyncBindingOverrideGuard<'di_container, Interface>
+    {
+        self.override_with(Box::new(AsyncInstanceProvider::new(instance)))
+    }
+
+    /// Overrides the binding so that every resolution of it returns a new transient
+    /// created by `func`.
+    pub fn to_transient<Func>(
+        self,
+        func: Func,
+    ) -> AsyncBindingOverrideGuard<'di_container, Interface>
+    where
+        Func: Fn() -> TransientPtr<Interface> + Send + Sync + 'static,
+    {
+        self.override_with(Box::new(AsyncTransientInstanceProvider::new(func)))
+    }
+
+    fn override_with(
+        self,
+        provider: Box<dyn IAsyncProvider<AsyncDIContainer>>,
+    ) -> AsyncBindingOverrideGuard<'di_container, Interface>
+    {
+        let original_provider = self
+            .di_container
+            .remove_binding::<Interface>(self.binding_options.clone());
+
+        self.di_container
+            .set_binding::<Interface>(self.binding_options.clone(), provider);
+
+        AsyncBindingOverrideGuard {
+            di_container: self.di_container,
+            binding_options: self.binding_options,
+            original_provider,
+            interface_phantom: PhantomData,
+        }
+    }
+}
+
+/// Guard of a overridden binding of a [`AsyncDIContainer`].
+///
+/// The original binding is restored when the guard is dropped. The DI container can be
+/// used through the guard while the binding is overridden.
+#[cfg(feature = "async")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
+#[must_use = "the original binding is restored when the guard is dropped"]
+pub struct AsyncBindingOverrideGuard<'di_container, Interface>
+where
+    Interface: 'static + ?Sized + Send + Sync,
+{
+    di_container: &'di_container mut AsyncDIContainer,
+    binding_options: BindingOptions<'static>,
+    original_provider: Option<Box<dyn IAsyncProvider<AsyncDIContainer>>>,
+    interface_phantom: PhantomData<Interface>,
+}
+
+#[cfg(feature = "async")]
+impl<Interface> Deref for AsyncBindingOverrideGuard<'_, Interface>
+where
+    Interface: 'static + ?Sized + Send + Sync,
+{
+    type Target = AsyncDIContainer;
+
+    fn deref(&self) -> &Self::Target
+    {
+        self.di_container
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Interface> DerefMut for AsyncBindingOverrideGuard<'_, Interface>
+where
+    Interface: 'static + ?Sized + Send + Sync,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target
+    {
+        self.di_container
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Interface> Drop for AsyncBindingOverrideGuard<'_, Interface>
+where
+    Interface: 'static + ?Sized + Send + Sync,
+{
+    fn drop(&mut self)
+    {
+        let binding_options = self.binding_options.clone();
+
+        match self.original_provider.take() {
+            Some(original_provider) => {
+                self.di_container
+                    .set_binding::<Interface>(binding_options, original_provider);
+            }
+            None => {
+                self.di_container
+                    .remove_binding::<Interface>(binding_options);
+            }
+        }
+    }
+}
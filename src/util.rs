@@ -1,4 +1,41 @@
 //! Internal utilities.
+// Requires Rust >= 1.81, which is why building without the std feature does
+#[cfg(not(feature = "std"))]
+pub use core::error::Error;
+use core::time::Duration;
+#[cfg(feature = "std")]
+pub use std::error::Error;
+
+/// Measures the time elapsed since it was started.
+///
+/// The elapsed time is always zero when the `std` feature is disabled, as there is no
+/// clock to measure it with.
+pub struct Stopwatch
+{
+    #[cfg(feature = "std")]
+    start: std::time::Instant,
+}
+
+impl Stopwatch
+{
+    pub fn start() -> Self
+    {
+        Self {
+            #[cfg(feature = "std")]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    #[cfg_attr(not(feature = "std"), allow(clippy::unused_self))]
+    pub fn elapsed(&self) -> Duration
+    {
+        #[cfg(feature = "std")]
+        return self.start.elapsed();
+
+        #[cfg(not(feature = "std"))]
+        Duration::ZERO
+    }
+}
 
 /// Imports the specified item, prepending 'Mock' to the item identifier if the `test`
 /// configuration option is set.
@@ -0,0 +1,358 @@
+#![deny(clippy::all, clippy::pedantic)]
+#![cfg(feature = "testing")]
+
+use std::rc::Rc;
+
+use syrette::ptr::TransientPtr;
+use syrette::{injectable, DIContainer};
+
+trait IGreeter
+{
+    fn greet(&self) -> String;
+}
+
+struct EnglishGreeter {}
+
+#[injectable(IGreeter)]
+impl EnglishGreeter
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IGreeter for EnglishGreeter
+{
+    fn greet(&self) -> String
+    {
+        "Hello".to_string()
+    }
+}
+
+struct FakeGreeter {}
+
+impl IGreeter for FakeGreeter
+{
+    fn greet(&self) -> String
+    {
+        "Fake".to_string()
+    }
+}
+
+struct Reception
+{
+    greeter: TransientPtr<dyn IGreeter>,
+}
+
+#[injectable]
+impl Reception
+{
+    fn new(greeter: TransientPtr<dyn IGreeter>) -> Self
+    {
+        Self { greeter }
+    }
+}
+
+fn greet(di_container: &DIContainer, name: Option<&'static str>) -> String
+{
+    match name {
+        Some(name) => di_container.get_named::<dyn IGreeter>(name),
+        None => di_container.get::<dyn IGreeter>(),
+    }
+    .expect("Expected Ok")
+    .transient()
+    .expect("Expected Ok")
+    .greet()
+}
+
+#[test]
+fn override_is_restored_when_guard_is_dropped()
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IGreeter>()
+        .to::<EnglishGreeter>()
+        .expect("Expected Ok");
+
+    {
+        let di_container = di_container
+            .override_binding::<dyn IGreeter>()
+            .to_transient(|| TransientPtr::new(FakeGreeter {}));
+
+        assert_eq!(greet(&di_container, None), "Fake");
+    }
+
+    assert_eq!(greet(&di_container, None), "Hello");
+}
+
+#[test]
+fn can_override_with_instance()
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IGreeter>()
+        .to::<EnglishGreeter>()
+        .expect("Expected Ok")
+        .in_singleton_scope()
+        .expect("Expected Ok");
+
+    let fake_greeter: Rc<dyn IGreeter> = Rc::new(FakeGreeter {});
+
+    {
+        let di_container = di_container
+            .override_binding::<dyn IGreeter>()
+            .to_instance(fake_greeter.clone());
+
+        let greeter = di_container
+            .get::<dyn IGreeter>()
+            .expect("Expected Ok")
+            .singleton()
+            .expect("Expected Ok");
+
+        assert!(Rc::ptr_eq(&greeter, &fake_greeter));
+    }
+
+    let greeter = di_container
+        .get::<dyn IGreeter>()
+        .expect("Expected Ok")
+        .singleton()
+        .expect("Expected Ok");
+
+    assert_eq!(greeter.greet(), "Hello");
+}
+
+#[test]
+fn injectable_with_transient_dependency_gets_override()
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IGreeter>()
+        .to::<EnglishGreeter>()
+        .expect("Expected Ok");
+
+    di_container
+        .bind::<Reception>()
+        .to::<Reception>()
+        .expect("Expected Ok");
+
+    {
+        let di_container = di_container
+            .override_binding::<dyn IGreeter>()
+            .to_transient(|| TransientPtr::new(FakeGreeter {}));
+
+        let reception = di_container
+            .get::<Reception>()
+            .expect("Expected Ok")
+            .transient()
+            .expect("Expected Ok");
+
+        assert_eq!(reception.greeter.greet(), "Fake");
+    }
+
+    let reception = di_container
+        .get::<Reception>()
+        .expect("Expected Ok")
+        .transient()
+        .expect("Expected Ok");
+
+    assert_eq!(reception.greeter.greet(), "Hello");
+}
+
+#[test]
+fn can_override_named_binding()
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IGreeter>()
+        .to::<EnglishGreeter>()
+        .expect("Expected Ok")
+        .in_transient_scope()
+        .when_named("english")
+        .expect("Expected Ok");
+
+    di_container
+        .bind::<dyn IGreeter>()
+        .to::<EnglishGreeter>()
+        .expect("Expected Ok");
+
+    {
+        let di_container = di_container
+            .override_binding::<dyn IGreeter>()
+            .when_named("english")
+            .to_transient(|| TransientPtr::new(FakeGreeter {}));
+
+        assert_eq!(greet(&di_container, Some("english")), "Fake");
+        assert_eq!(greet(&di_container, None), "Hello");
+    }
+
+    assert_eq!(greet(&di_container, Some("english")), "Hello");
+}
+
+#[test]
+fn override_of_missing_binding_is_removed()
+{
+    let mut di_container = DIContainer::new();
+
+    {
+        let di_container = di_container
+            .override_binding::<dyn IGreeter>()
+            .to_transient(|| TransientPtr::new(FakeGreeter {}));
+
+        assert_eq!(greet(&di_container, None), "Fake");
+    }
+
+    assert!(di_container.get::<dyn IGreeter>().is_err());
+}
+
+#[test]
+fn can_nest_overrides()
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IGreeter>()
+        .to::<EnglishGreeter>()
+        .expect("Expected Ok");
+
+    {
+        let mut outer = di_container
+            .override_binding::<dyn IGreeter>()
+            .to_transient(|| TransientPtr::new(FakeGreeter {}));
+
+        {
+            let inner = outer
+                .override_binding::<dyn IGreeter>()
+                .to_transient(|| TransientPtr::new(EnglishGreeter {}));
+
+            assert_eq!(greet(&inner, None), "Hello");
+        }
+
+        assert_eq!(greet(&outer, None), "Fake");
+    }
+
+    assert_eq!(greet(&di_container, None), "Hello");
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use std::sync::Arc;
+
+    use syrette::ptr::TransientPtr;
+    use syrette::{injectable, AsyncDIContainer};
+
+    trait IClock: Send + Sync
+    {
+        fn now(&self) -> u64;
+    }
+
+    struct SystemClock {}
+
+    #[injectable(IClock, async = true)]
+    impl SystemClock
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    impl IClock for SystemClock
+    {
+        fn now(&self) -> u64
+        {
+            1_700_000_000
+        }
+    }
+
+    struct FakeClock {}
+
+    impl IClock for FakeClock
+    {
+        fn now(&self) -> u64
+        {
+            0
+        }
+    }
+
+    #[tokio::test]
+    async fn async_override_is_restored_when_guard_is_dropped()
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<dyn IClock>()
+            .to::<SystemClock>()
+            .expect("Expected Ok")
+            .in_singleton_scope()
+            .await
+            .expect("Expected Ok")
+            .when_named("system")
+            .expect("Expected Ok");
+
+        {
+            let di_container = di_container
+                .override_binding::<dyn IClock>()
+                .when_named("system")
+                .to_instance(Arc::new(FakeClock {}));
+
+            let clock = di_container
+                .get_named::<dyn IClock>("system")
+                .await
+                .expect("Expected Ok")
+                .threadsafe_singleton()
+                .expect("Expected Ok");
+
+            assert_eq!(clock.now(), 0);
+        }
+
+        let clock = di_container
+            .get_named::<dyn IClock>("system")
+            .await
+            .expect("Expected Ok")
+            .threadsafe_singleton()
+            .expect("Expected Ok");
+
+        assert_eq!(clock.now(), 1_700_000_000);
+    }
+
+    #[tokio::test]
+    async fn async_can_override_with_transient()
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<dyn IClock>()
+            .to::<SystemClock>()
+            .expect("Expected Ok");
+
+        {
+            let di_container = di_container
+                .override_binding::<dyn IClock>()
+                .to_transient(|| TransientPtr::new(FakeClock {}));
+
+            let clock = di_container
+                .get::<dyn IClock>()
+                .await
+                .expect("Expected Ok")
+                .transient()
+                .expect("Expected Ok");
+
+            assert_eq!(clock.now(), 0);
+        }
+
+        let clock = di_container
+            .get::<dyn IClock>()
+            .await
+            .expect("Expected Ok")
+            .transient()
+            .expect("Expected Ok");
+
+        assert_eq!(clock.now(), 1_700_000_000);
+    }
+}
@@ -0,0 +1,97 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use syrette::casting::{self, CastFrom};
+use syrette::errors::casting::CastingError;
+use syrette::{declare_interface, injectable, DIContainer};
+
+trait IAnimal: CastFrom
+{
+    fn name(&self) -> &'static str;
+}
+
+trait IPet: CastFrom
+{
+    fn owner(&self) -> &'static str;
+}
+
+trait IWildAnimal: CastFrom {}
+
+struct Dog {}
+
+#[injectable(IAnimal)]
+impl Dog
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IAnimal for Dog
+{
+    fn name(&self) -> &'static str
+    {
+        "dog"
+    }
+}
+
+impl IPet for Dog
+{
+    fn owner(&self) -> &'static str
+    {
+        "Alice"
+    }
+}
+
+declare_interface!(Dog -> IPet);
+
+#[test]
+fn can_list_declared_interfaces()
+{
+    let interface_names = casting::declared_interfaces::<Dog>()
+        .iter()
+        .map(casting::DeclaredInterface::name)
+        .collect::<Vec<_>>();
+
+    assert_eq!(interface_names.len(), 2);
+
+    assert!(interface_names
+        .iter()
+        .any(|interface_name| interface_name.ends_with("IAnimal")));
+
+    assert!(interface_names
+        .iter()
+        .any(|interface_name| interface_name.ends_with("IPet")));
+}
+
+#[test]
+fn can_cast_resolved_ptr() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IAnimal>()
+        .to::<Dog>()?
+        .in_singleton_scope()?;
+
+    assert_eq!(
+        di_container.get::<dyn IAnimal>()?.singleton()?.name(),
+        "dog"
+    );
+
+    let animal = di_container.get::<dyn IAnimal>()?;
+
+    assert!(casting::can_cast::<dyn IPet, _>(&animal));
+    assert!(!casting::can_cast::<dyn IWildAnimal, _>(&animal));
+
+    let pet = casting::cast::<dyn IPet, _>(animal)?.singleton()?;
+
+    assert_eq!(pet.owner(), "Alice");
+
+    assert!(matches!(
+        casting::cast::<dyn IWildAnimal, _>(di_container.get::<dyn IAnimal>()?),
+        Err(CastingError::NotDeclared { .. })
+    ));
+
+    Ok(())
+}
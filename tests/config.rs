@@ -0,0 +1,282 @@
+#![deny(clippy::all, clippy::pedantic)]
+#![cfg(feature = "config")]
+
+use std::rc::Rc;
+
+use syrette::config::ConfigFormat;
+use syrette::errors::config::ConfigError;
+use syrette::{injectable, DIContainer};
+
+trait IMailer
+{
+    fn provider(&self) -> &'static str;
+}
+
+struct SmtpMailer {}
+
+#[injectable(IMailer, key = "smtp")]
+impl SmtpMailer
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IMailer for SmtpMailer
+{
+    fn provider(&self) -> &'static str
+    {
+        "smtp"
+    }
+}
+
+struct ApiMailer {}
+
+#[injectable(IMailer, key = "api")]
+impl ApiMailer
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IMailer for ApiMailer
+{
+    fn provider(&self) -> &'static str
+    {
+        "api"
+    }
+}
+
+trait ISpamFilter {}
+
+#[derive(Debug, thiserror::Error)]
+#[error("The spam filter service is unreachable")]
+struct UnreachableError;
+
+struct RemoteSpamFilter {}
+
+#[injectable(ISpamFilter, key = "remote")]
+impl RemoteSpamFilter
+{
+    fn new() -> Result<Self, UnreachableError>
+    {
+        Err(UnreachableError)
+    }
+}
+
+impl ISpamFilter for RemoteSpamFilter {}
+
+#[test]
+fn can_load_toml_config() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.load_config_str(
+        concat!(
+            "[[bindings]]\n",
+            "interface = \"IMailer\"\n",
+            "implementation = \"smtp\"\n",
+            "scope = \"singleton\"\n",
+            "\n",
+            "[[bindings]]\n",
+            "interface = \"IMailer\"\n",
+            "implementation = \"api\"\n",
+            "name = \"fallback\"\n",
+        ),
+        ConfigFormat::Toml,
+    )?;
+
+    let mailer = di_container.get::<dyn IMailer>()?.singleton()?;
+
+    assert_eq!(mailer.provider(), "smtp");
+    assert!(Rc::ptr_eq(
+        &mailer,
+        &di_container.get::<dyn IMailer>()?.singleton()?
+    ));
+
+    let fallback_mailer = di_container
+        .get_named::<dyn IMailer>("fallback")?
+        .transient()?;
+
+    assert_eq!(fallback_mailer.provider(), "api");
+
+    Ok(())
+}
+
+#[test]
+fn can_load_json_config_file() -> Result<(), Box<dyn std::error::Error>>
+{
+    let path = std::env::temp_dir().join("syrette_can_load_json_config_file.json");
+
+    std::fs::write(
+        &path,
+        r#"{ "bindings": [{ "interface": "IMailer", "implementation": "api" }] }"#,
+    )?;
+
+    let mut di_container = DIContainer::new();
+
+    let result = di_container.load_config(&path);
+
+    std::fs::remove_file(&path)?;
+
+    result?;
+
+    assert_eq!(
+        di_container.get::<dyn IMailer>()?.transient()?.provider(),
+        "api"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn load_config_with_unregistered_key_names_line_and_key()
+{
+    let mut di_container = DIContainer::new();
+
+    let err = di_container
+        .load_config_str(
+            concat!(
+                "[[bindings]]\n",
+                "interface = \"IMailer\"\n",
+                "implementation = \"smtp\"\n",
+                "name = \"primary\"\n",
+                "\n",
+                "[[bindings]]\n",
+                "interface = \"IMailer\"\n",
+                "implementation = \"carrier_pigeon\"\n",
+            ),
+            ConfigFormat::Toml,
+        )
+        .expect_err("Expected Err");
+
+    assert!(matches!(
+        &err,
+        ConfigError::UnregisteredKey { key, line: Some(8), .. } if key == "carrier_pigeon"
+    ));
+
+    assert_eq!(
+        err.to_string(),
+        concat!(
+            "Line 8: No implementation of interface 'IMailer' is registered with key ",
+            "'carrier_pigeon'. Available keys: [api, smtp]"
+        )
+    );
+
+    // No bindings are created when the configuration is invalid
+    assert!(di_container.get_named::<dyn IMailer>("primary").is_err());
+}
+
+#[test]
+fn load_config_with_unknown_interface_fails()
+{
+    let mut di_container = DIContainer::new();
+
+    assert!(matches!(
+        di_container.load_config_str(
+            r#"{"bindings": [{"interface": "ISms", "implementation": "smtp"}]}"#,
+            ConfigFormat::Json,
+        ),
+        Err(ConfigError::UnknownInterface { interface, line: Some(1) }) if interface == "ISms"
+    ));
+}
+
+#[test]
+fn load_config_with_existing_binding_fails() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<dyn IMailer>().to::<SmtpMailer>()?;
+
+    assert!(matches!(
+        di_container.load_config_str(
+            concat!(
+                "[[bindings]]\n",
+                "interface = \"IMailer\"\n",
+                "implementation = \"api\"\n",
+            ),
+            ConfigFormat::Toml,
+        ),
+        Err(ConfigError::BindingAlreadyExists {
+            name: None,
+            line: Some(2),
+            ..
+        })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn load_config_with_failing_singleton_creates_no_bindings()
+{
+    let mut di_container = DIContainer::new();
+
+    assert!(matches!(
+        di_container.load_config_str(
+            concat!(
+                "[[bindings]]\n",
+                "interface = \"IMailer\"\n",
+                "implementation = \"smtp\"\n",
+                "name = \"primary\"\n",
+                "\n",
+                "[[bindings]]\n",
+                "interface = \"ISpamFilter\"\n",
+                "implementation = \"remote\"\n",
+                "scope = \"singleton\"\n",
+            ),
+            ConfigFormat::Toml,
+        ),
+        Err(ConfigError::BindingFailed { line: Some(7), .. })
+    ));
+
+    assert!(di_container.get_named::<dyn IMailer>("primary").is_err());
+    assert!(di_container.get::<dyn ISpamFilter>().is_err());
+}
+
+#[test]
+fn can_export_config() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IMailer>()
+        .to_registered("api")?
+        .in_transient_scope()
+        .when_named("fallback")?;
+
+    di_container
+        .bind::<dyn IMailer>()
+        .to_registered("smtp")?
+        .in_singleton_scope()?;
+
+    // Bindings to implementations that aren't registered under a key are left out
+    di_container.bind::<SmtpMailer>().to::<SmtpMailer>()?;
+
+    let exported = di_container.export_config_str(ConfigFormat::Json)?;
+
+    let mut other_di_container = DIContainer::new();
+
+    other_di_container.load_config_str(&exported, ConfigFormat::Json)?;
+
+    assert_eq!(
+        other_di_container.export_config_str(ConfigFormat::Toml)?,
+        concat!(
+            "[[bindings]]\n",
+            "interface = \"IMailer\"\n",
+            "implementation = \"smtp\"\n",
+            "scope = \"singleton\"\n",
+            "\n",
+            "[[bindings]]\n",
+            "interface = \"IMailer\"\n",
+            "implementation = \"api\"\n",
+            "scope = \"transient\"\n",
+            "name = \"fallback\"\n",
+        )
+    );
+
+    Ok(())
+}
@@ -0,0 +1,263 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use syrette::di_container::blocking::container_ref::ContainerRef;
+use syrette::di_container::SingletonForkPolicy;
+use syrette::errors::di_container::DIContainerError;
+use syrette::errors::injectable::InjectableError;
+use syrette::{injectable, DIContainer};
+
+trait IPlugin
+{
+    fn name(&self) -> &'static str;
+}
+
+struct AuditPlugin {}
+
+#[injectable(IPlugin)]
+impl AuditPlugin
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IPlugin for AuditPlugin
+{
+    fn name(&self) -> &'static str
+    {
+        "audit"
+    }
+}
+
+struct PluginHost
+{
+    container_ref: ContainerRef,
+}
+
+#[injectable]
+impl PluginHost
+{
+    fn new(container_ref: ContainerRef) -> Self
+    {
+        Self { container_ref }
+    }
+}
+
+fn create_di_container() -> Result<DIContainer, Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to::<AuditPlugin>()?
+        .in_transient_scope()
+        .when_named("audit")?;
+
+    di_container.bind::<PluginHost>().to::<PluginHost>()?;
+
+    Ok(di_container)
+}
+
+#[test]
+fn can_resolve_through_container_ref() -> Result<(), Box<dyn std::error::Error>>
+{
+    let di_container = create_di_container()?.into_shared();
+
+    let plugin_host = di_container.get::<PluginHost>()?.transient()?;
+
+    let plugin = plugin_host
+        .container_ref
+        .get_named::<dyn IPlugin>("audit")?
+        .transient()?;
+
+    assert_eq!(plugin.name(), "audit");
+
+    assert!(matches!(
+        plugin_host
+            .container_ref
+            .get_named::<dyn IPlugin>("billing"),
+        Err(DIContainerError::BindingNotFound { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn can_bind_singleton_with_container_ref() -> Result<(), Box<dyn std::error::Error>>
+{
+    let di_container = DIContainer::new_shared(|di_container| {
+        di_container
+            .bind::<dyn IPlugin>()
+            .to::<AuditPlugin>()?
+            .in_transient_scope()
+            .when_named("audit")?;
+
+        di_container
+            .bind::<PluginHost>()
+            .to::<PluginHost>()?
+            .in_singleton_scope()?;
+
+        Ok::<_, Box<dyn std::error::Error>>(())
+    })?;
+
+    let plugin_host = di_container.get::<PluginHost>()?.singleton()?;
+
+    let plugin = plugin_host
+        .container_ref
+        .get_named::<dyn IPlugin>("audit")?
+        .transient()?;
+
+    assert_eq!(plugin.name(), "audit");
+
+    Ok(())
+}
+
+#[test]
+fn resolve_with_container_ref_from_unshared_container_fails(
+) -> Result<(), Box<dyn std::error::Error>>
+{
+    let di_container = create_di_container()?;
+
+    assert!(matches!(
+        di_container.get::<PluginHost>(),
+        Err(DIContainerError::BindingResolveFailed {
+            reason: InjectableError::ResolveFailed { reason, .. },
+            ..
+        }) if matches!(*reason, DIContainerError::ContainerNotShared)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn resolve_with_container_ref_from_fork_of_shared_container_fails(
+) -> Result<(), Box<dyn std::error::Error>>
+{
+    let di_container = create_di_container()?.into_shared();
+
+    let forked_di_container = di_container.fork(SingletonForkPolicy::Share);
+
+    assert!(matches!(
+        forked_di_container.get::<PluginHost>(),
+        Err(DIContainerError::BindingResolveFailed {
+            reason: InjectableError::ResolveFailed { reason, .. },
+            ..
+        }) if matches!(*reason, DIContainerError::ContainerNotShared)
+    ));
+
+    let forked_di_container = forked_di_container.into_shared();
+
+    assert!(forked_di_container.get::<PluginHost>().is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn get_through_container_ref_of_dropped_container_fails(
+) -> Result<(), Box<dyn std::error::Error>>
+{
+    let di_container = create_di_container()?.into_shared();
+
+    let plugin_host = di_container.get::<PluginHost>()?.transient()?;
+
+    drop(di_container);
+
+    assert!(matches!(
+        plugin_host.container_ref.get_named::<dyn IPlugin>("audit"),
+        Err(DIContainerError::ContainerDropped)
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use syrette::di_container::asynchronous::container_ref::AsyncContainerRef;
+    use syrette::errors::async_di_container::AsyncDIContainerError;
+    use syrette::{injectable, AsyncDIContainer};
+
+    trait IPlugin: Send + Sync
+    {
+        fn name(&self) -> &'static str;
+    }
+
+    struct AuditPlugin {}
+
+    #[injectable(IPlugin, async = true)]
+    impl AuditPlugin
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    impl IPlugin for AuditPlugin
+    {
+        fn name(&self) -> &'static str
+        {
+            "audit"
+        }
+    }
+
+    struct PluginHost
+    {
+        container_ref: AsyncContainerRef,
+    }
+
+    #[injectable(async = true)]
+    impl PluginHost
+    {
+        fn new(container_ref: AsyncContainerRef) -> Self
+        {
+            Self { container_ref }
+        }
+    }
+
+    #[tokio::test]
+    async fn can_resolve_through_async_container_ref(
+    ) -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<dyn IPlugin>()
+            .to::<AuditPlugin>()?
+            .in_transient_scope()
+            .when_named("audit")?;
+
+        di_container
+            .bind::<PluginHost>()
+            .to::<PluginHost>()?
+            .in_lazy_singleton_scope();
+
+        let di_container = di_container.into_shared();
+
+        let plugin_host = di_container
+            .get::<PluginHost>()
+            .await?
+            .threadsafe_singleton()?;
+
+        let plugin = plugin_host
+            .container_ref
+            .get_named::<dyn IPlugin>("audit")
+            .await?
+            .transient()?;
+
+        assert_eq!(plugin.name(), "audit");
+
+        drop(di_container);
+
+        assert!(matches!(
+            plugin_host
+                .container_ref
+                .get_named::<dyn IPlugin>("audit")
+                .await,
+            Err(AsyncDIContainerError::ContainerDropped)
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::fmt::Debug;
+
+use syrette::{declare_interface, injectable, DIContainer};
+
+trait ICounter<Value>
+{
+    fn current(&self) -> String;
+}
+
+trait IResettable
+{
+    fn is_reset(&self) -> bool;
+}
+
+struct Counter<Value>
+{
+    value: Value,
+}
+
+#[injectable(no_declare_concrete_interface = true)]
+impl<Value: Default + 'static> Counter<Value>
+{
+    fn new() -> Self
+    {
+        Self {
+            value: Value::default(),
+        }
+    }
+}
+
+impl<Value: Debug> ICounter<Value> for Counter<Value>
+{
+    fn current(&self) -> String
+    {
+        format!("{:?}", self.value)
+    }
+}
+
+impl<Value: Default + PartialEq> IResettable for Counter<Value>
+{
+    fn is_reset(&self) -> bool
+    {
+        self.value == Value::default()
+    }
+}
+
+declare_interface!(
+    Counter<Value> -> ICounter<Value>, IResettable
+    where Value: Debug + Default + PartialEq;
+    for Value in [u8, String]
+);
+
+#[test]
+fn can_resolve_all_declared_interfaces() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn ICounter<u8>>()
+        .to::<Counter<u8>>()?;
+
+    di_container
+        .bind::<dyn ICounter<String>>()
+        .to::<Counter<String>>()?;
+
+    di_container
+        .bind::<dyn IResettable>()
+        .to::<Counter<String>>()?
+        .in_transient_scope()
+        .when_named("text")?;
+
+    di_container.bind::<dyn IResettable>().to::<Counter<u8>>()?;
+
+    assert_eq!(
+        di_container
+            .get::<dyn ICounter<u8>>()?
+            .transient()?
+            .current(),
+        "0"
+    );
+
+    assert_eq!(
+        di_container
+            .get::<dyn ICounter<String>>()?
+            .transient()?
+            .current(),
+        "\"\""
+    );
+
+    assert!(di_container
+        .get::<dyn IResettable>()?
+        .transient()?
+        .is_reset());
+
+    assert!(di_container
+        .get_named::<dyn IResettable>("text")?
+        .transient()?
+        .is_reset());
+
+    Ok(())
+}
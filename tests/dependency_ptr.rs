@@ -0,0 +1,152 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::rc::Rc;
+
+use syrette::ptr::SingletonPtr;
+use syrette::{injectable, DIContainer};
+
+type Shared<Interface> = SingletonPtr<Interface>;
+
+type SharedLogger = SingletonPtr<dyn ILogger>;
+
+trait ILogger
+{
+    fn prefix(&self) -> &'static str;
+}
+
+struct Logger {}
+
+#[injectable(ILogger)]
+impl Logger
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl ILogger for Logger
+{
+    fn prefix(&self) -> &'static str
+    {
+        "[app]"
+    }
+}
+
+struct Clock {}
+
+#[injectable]
+impl Clock
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+struct Scheduler
+{
+    logger: Rc<dyn ILogger>,
+    clock: Box<Clock>,
+}
+
+#[injectable]
+impl Scheduler
+{
+    fn new(logger: Rc<dyn ILogger>, clock: Box<Clock>) -> Self
+    {
+        Self { logger, clock }
+    }
+}
+
+struct Worker
+{
+    logger: Shared<dyn ILogger>,
+}
+
+#[injectable]
+impl Worker
+{
+    fn new(#[ptr = singleton] logger: Shared<dyn ILogger>) -> Self
+    {
+        Self { logger }
+    }
+}
+
+struct Auditor
+{
+    logger: SharedLogger,
+}
+
+#[injectable]
+impl Auditor
+{
+    fn new(#[ptr(singleton, interface = dyn ILogger)] logger: SharedLogger) -> Self
+    {
+        Self { logger }
+    }
+}
+
+fn create_di_container() -> Result<DIContainer, Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn ILogger>()
+        .to::<Logger>()?
+        .in_singleton_scope()?;
+
+    di_container.bind::<Clock>().to::<Clock>()?;
+
+    Ok(di_container)
+}
+
+#[test]
+fn can_inject_std_ptrs() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = create_di_container()?;
+
+    di_container.bind::<Scheduler>().to::<Scheduler>()?;
+
+    let scheduler = di_container.get::<Scheduler>()?.transient()?;
+
+    assert_eq!(scheduler.logger.prefix(), "[app]");
+
+    let _clock: &Clock = &scheduler.clock;
+
+    Ok(())
+}
+
+#[test]
+fn can_inject_ptr_type_alias() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = create_di_container()?;
+
+    di_container.bind::<Worker>().to::<Worker>()?;
+
+    let worker = di_container.get::<Worker>()?.transient()?;
+
+    assert!(Rc::ptr_eq(
+        &worker.logger,
+        &di_container.get::<dyn ILogger>()?.singleton()?
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn can_inject_non_generic_ptr_type_alias() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = create_di_container()?;
+
+    di_container.bind::<Auditor>().to::<Auditor>()?;
+
+    let auditor = di_container.get::<Auditor>()?.transient()?;
+
+    assert!(Rc::ptr_eq(
+        &auditor.logger,
+        &di_container.get::<dyn ILogger>()?.singleton()?
+    ));
+
+    Ok(())
+}
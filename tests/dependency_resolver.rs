@@ -0,0 +1,115 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::any::{type_name, Any};
+use std::cell::RefCell;
+
+use syrette::dependency_history::DependencyHistory;
+use syrette::di_container::BindingOptions;
+use syrette::errors::di_container::DIContainerError;
+use syrette::errors::injectable::InjectableError;
+use syrette::injectable;
+use syrette::interfaces::dependency_resolver::DependencyResolver;
+use syrette::interfaces::injectable::Injectable;
+use syrette::ptr::{SomePtr, TransientPtr};
+
+trait IEngine
+{
+    fn horsepower(&self) -> u32;
+}
+
+struct FakeEngine;
+
+impl IEngine for FakeEngine
+{
+    fn horsepower(&self) -> u32
+    {
+        300
+    }
+}
+
+struct Car
+{
+    engine: TransientPtr<dyn IEngine>,
+}
+
+#[injectable]
+impl Car
+{
+    fn new(#[syrette::named("v8")] engine: TransientPtr<dyn IEngine>) -> Self
+    {
+        Self { engine }
+    }
+}
+
+#[derive(Default)]
+struct FakeResolver
+{
+    has_engine: bool,
+    requested: RefCell<Vec<&'static str>>,
+}
+
+impl DependencyResolver for FakeResolver
+{
+    fn get_bound<Interface>(
+        &self,
+        _dependency_history: DependencyHistory,
+        _binding_options: BindingOptions<'static>,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.requested.borrow_mut().push(type_name::<Interface>());
+
+        if !self.has_engine {
+            return Err(DIContainerError::BindingNotFound {
+                interface: type_name::<Interface>(),
+                name: Some("v8".to_string()),
+            });
+        }
+
+        let engine: Box<dyn Any> =
+            Box::new(TransientPtr::new(FakeEngine) as TransientPtr<dyn IEngine>);
+
+        engine
+            .downcast::<TransientPtr<Interface>>()
+            .map(|engine| SomePtr::Transient(*engine))
+            .map_err(|_| DIContainerError::BindingNotFound {
+                interface: type_name::<Interface>(),
+                name: None,
+            })
+    }
+}
+
+#[test]
+fn can_resolve_with_fake_resolver()
+{
+    let fake_resolver = FakeResolver {
+        has_engine: true,
+        ..FakeResolver::default()
+    };
+
+    let car =
+        Car::resolve(&fake_resolver, DependencyHistory::new()).expect("Expected Ok");
+
+    assert_eq!(car.engine.horsepower(), 300);
+
+    assert_eq!(
+        *fake_resolver.requested.borrow(),
+        vec![type_name::<dyn IEngine>()]
+    );
+}
+
+#[test]
+fn resolve_fails_when_fake_resolver_fails()
+{
+    let fake_resolver = FakeResolver::default();
+
+    assert!(matches!(
+        Car::resolve(&fake_resolver, DependencyHistory::new()),
+        Err(InjectableError::ResolveFailed {
+            reason: _,
+            affected: _,
+            dependency_history: _
+        })
+    ));
+}
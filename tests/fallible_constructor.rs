@@ -0,0 +1,153 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::error::Error;
+use std::num::ParseIntError;
+
+use syrette::errors::di_container::DIContainerError;
+use syrette::errors::injectable::InjectableError;
+use syrette::{injectable, DIContainer};
+
+#[derive(Debug, thiserror::Error)]
+#[error("The config file is missing")]
+struct MissingConfigError;
+
+#[derive(Debug)]
+struct Config
+{
+    _content: String,
+}
+
+#[injectable]
+impl Config
+{
+    fn new() -> Result<Self, MissingConfigError>
+    {
+        Err(MissingConfigError)
+    }
+}
+
+#[derive(Debug)]
+struct Logger
+{
+    _level: u8,
+}
+
+#[injectable]
+impl Logger
+{
+    fn new() -> Result<Self, ParseIntError>
+    {
+        let level = "3".parse()?;
+
+        Ok(Self { _level: level })
+    }
+}
+
+#[test]
+fn can_resolve_with_fallible_constructor()
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Logger>()
+        .to::<Logger>()
+        .expect("Expected Ok");
+
+    di_container
+        .get::<Logger>()
+        .expect("Expected Ok")
+        .transient()
+        .expect("Expected Ok");
+}
+
+#[test]
+fn fallible_constructor_error_is_returned()
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Config>()
+        .to::<Config>()
+        .expect("Expected Ok");
+
+    let err = di_container.get::<Config>().expect_err("Expected Err");
+
+    assert!(matches!(
+        &err,
+        DIContainerError::BindingResolveFailed {
+            reason: InjectableError::ConstructorFailed {
+                source: _,
+                affected: _
+            },
+            interface: _
+        }
+    ));
+
+    assert!(err
+        .source()
+        .and_then(Error::source)
+        .map_or_else(|| false, <dyn Error>::is::<MissingConfigError>));
+}
+
+#[cfg(feature = "async")]
+mod asynchronous
+{
+    use std::error::Error;
+
+    use syrette::errors::async_di_container::AsyncDIContainerError;
+    use syrette::errors::injectable::InjectableError;
+    use syrette::{injectable, AsyncDIContainer};
+
+    use super::MissingConfigError;
+
+    #[derive(Debug)]
+    struct AsyncConfig
+    {
+        _content: String,
+    }
+
+    #[injectable(async = true)]
+    impl AsyncConfig
+    {
+        async fn new() -> Result<Self, MissingConfigError>
+        {
+            let content = std::future::ready(None).await;
+
+            content
+                .map(|content| Self { _content: content })
+                .ok_or(MissingConfigError)
+        }
+    }
+
+    #[tokio::test]
+    async fn fallible_async_constructor_error_is_returned()
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<AsyncConfig>()
+            .to::<AsyncConfig>()
+            .expect("Expected Ok");
+
+        let err = di_container
+            .get::<AsyncConfig>()
+            .await
+            .expect_err("Expected Err");
+
+        assert!(matches!(
+            &err,
+            AsyncDIContainerError::BindingResolveFailed {
+                reason: InjectableError::ConstructorFailed {
+                    source: _,
+                    affected: _
+                },
+                interface: _
+            }
+        ));
+
+        assert!(err
+            .source()
+            .and_then(Error::source)
+            .map_or_else(|| false, <dyn Error>::is::<MissingConfigError>));
+    }
+}
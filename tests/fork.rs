@@ -0,0 +1,172 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::rc::Rc;
+
+use syrette::di_container::SingletonForkPolicy;
+use syrette::{injectable, DIContainer};
+
+struct Database {}
+
+#[injectable]
+impl Database
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+struct Mailer {}
+
+#[injectable]
+impl Mailer
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+fn create_base_di_container() -> DIContainer
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Database>()
+        .to::<Database>()
+        .expect("Expected Ok")
+        .in_singleton_scope()
+        .expect("Expected Ok");
+
+    di_container
+}
+
+#[test]
+fn fork_has_independent_bindings()
+{
+    let base_di_container = create_base_di_container();
+
+    let mut forked_di_container = base_di_container.fork(SingletonForkPolicy::Share);
+
+    forked_di_container
+        .bind::<Mailer>()
+        .to::<Mailer>()
+        .expect("Expected Ok");
+
+    assert!(forked_di_container.get::<Database>().is_ok());
+    assert!(forked_di_container.get::<Mailer>().is_ok());
+
+    assert!(base_di_container.get::<Mailer>().is_err());
+}
+
+#[test]
+fn fork_can_share_singletons()
+{
+    let base_di_container = create_base_di_container();
+
+    let forked_di_container = base_di_container.fork(SingletonForkPolicy::Share);
+
+    let base_database = base_di_container
+        .get::<Database>()
+        .expect("Expected Ok")
+        .singleton()
+        .expect("Expected Ok");
+
+    let forked_database = forked_di_container
+        .get::<Database>()
+        .expect("Expected Ok")
+        .singleton()
+        .expect("Expected Ok");
+
+    assert!(Rc::ptr_eq(&base_database, &forked_database));
+}
+
+#[test]
+fn fork_can_recreate_singletons()
+{
+    let base_di_container = create_base_di_container();
+
+    let forked_di_container = base_di_container.fork(SingletonForkPolicy::Recreate);
+
+    let base_database = base_di_container
+        .get::<Database>()
+        .expect("Expected Ok")
+        .singleton()
+        .expect("Expected Ok");
+
+    let forked_database_a = forked_di_container
+        .get::<Database>()
+        .expect("Expected Ok")
+        .singleton()
+        .expect("Expected Ok");
+
+    let forked_database_b = forked_di_container
+        .get::<Database>()
+        .expect("Expected Ok")
+        .singleton()
+        .expect("Expected Ok");
+
+    assert!(!Rc::ptr_eq(&base_database, &forked_database_a));
+    assert!(Rc::ptr_eq(&forked_database_a, &forked_database_b));
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use std::sync::Arc;
+
+    use syrette::di_container::SingletonForkPolicy;
+    use syrette::{injectable, AsyncDIContainer};
+
+    struct Cache {}
+
+    #[injectable(async = true)]
+    impl Cache
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    #[tokio::test]
+    async fn async_fork_can_share_and_recreate_singletons()
+    {
+        let mut base_di_container = AsyncDIContainer::new();
+
+        base_di_container
+            .bind::<Cache>()
+            .to::<Cache>()
+            .expect("Expected Ok")
+            .in_singleton_scope()
+            .await
+            .expect("Expected Ok");
+
+        let shared_fork = base_di_container.fork(SingletonForkPolicy::Share);
+        let recreated_fork = base_di_container.fork(SingletonForkPolicy::Recreate);
+
+        let base_cache = base_di_container
+            .get::<Cache>()
+            .await
+            .expect("Expected Ok")
+            .threadsafe_singleton()
+            .expect("Expected Ok");
+
+        let shared_cache = shared_fork
+            .get::<Cache>()
+            .await
+            .expect("Expected Ok")
+            .threadsafe_singleton()
+            .expect("Expected Ok");
+
+        let recreated_cache = recreated_fork
+            .get::<Cache>()
+            .await
+            .expect("Expected Ok")
+            .threadsafe_singleton()
+            .expect("Expected Ok");
+
+        assert!(Arc::ptr_eq(&base_cache, &shared_cache));
+        assert!(!Arc::ptr_eq(&base_cache, &recreated_cache));
+    }
+}
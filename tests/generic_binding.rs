@@ -0,0 +1,153 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+use syrette::di_container::GenericParam;
+use syrette::errors::di_container::BindingBuilderError;
+use syrette::{injectable, DIContainer};
+
+trait IFormatter<Value: Display>
+{
+    fn format(&self, value: Value) -> String;
+}
+
+struct BracketFormatter<Value>
+{
+    _value: PhantomData<Value>,
+}
+
+#[injectable(IFormatter<Value>, instances = [String, i32, char])]
+impl<Value: Display + 'static> BracketFormatter<Value>
+{
+    fn new() -> Self
+    {
+        Self {
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<Value: Display> IFormatter<Value> for BracketFormatter<Value>
+{
+    fn format(&self, value: Value) -> String
+    {
+        format!("[{value}]")
+    }
+}
+
+struct UnregisteredFormatter<Value>
+{
+    _value: PhantomData<Value>,
+}
+
+impl<Value: Display> IFormatter<Value> for UnregisteredFormatter<Value>
+{
+    fn format(&self, value: Value) -> String
+    {
+        value.to_string()
+    }
+}
+
+struct Cache<Value>
+{
+    _value: PhantomData<Value>,
+}
+
+#[injectable(instances = [u8, u16])]
+impl<Value: 'static> Cache<Value>
+{
+    fn new() -> Self
+    {
+        Self {
+            _value: PhantomData,
+        }
+    }
+}
+
+#[test]
+fn can_bind_generic() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind_generic::<dyn IFormatter<GenericParam>>()
+        .to::<BracketFormatter<GenericParam>>()?;
+
+    assert_eq!(
+        di_container
+            .get::<dyn IFormatter<String>>()?
+            .transient()?
+            .format("hello".to_string()),
+        "[hello]"
+    );
+
+    assert_eq!(
+        di_container
+            .get::<dyn IFormatter<i32>>()?
+            .transient()?
+            .format(42),
+        "[42]"
+    );
+
+    assert!(di_container.get::<dyn IFormatter<u64>>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn can_bind_generic_concrete_type_in_singleton_scope(
+) -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind_generic::<Cache<GenericParam>>()
+        .to::<Cache<GenericParam>>()?
+        .in_singleton_scope()?;
+
+    let first = di_container.get::<Cache<u16>>()?.singleton()?;
+    let second = di_container.get::<Cache<u16>>()?.singleton()?;
+
+    assert!(std::rc::Rc::ptr_eq(&first, &second));
+
+    di_container.get::<Cache<u8>>()?.singleton()?;
+
+    Ok(())
+}
+
+#[test]
+fn bind_generic_without_instances_fails()
+{
+    let mut di_container = DIContainer::new();
+
+    assert!(matches!(
+        di_container
+            .bind_generic::<dyn IFormatter<GenericParam>>()
+            .to::<UnregisteredFormatter<GenericParam>>(),
+        Err(BindingBuilderError::NoGenericInstances { .. })
+    ));
+}
+
+#[test]
+fn bind_generic_with_existing_binding_fails() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IFormatter<i32>>()
+        .to::<BracketFormatter<i32>>()?;
+
+    assert!(matches!(
+        di_container
+            .bind_generic::<dyn IFormatter<GenericParam>>()
+            .to::<BracketFormatter<GenericParam>>(),
+        Err(BindingBuilderError::BindingAlreadyExists(interface))
+            if interface.contains("IFormatter<i32>")
+    ));
+
+    // No bindings are created when a instance of the interface is already bound
+    assert!(di_container.get::<dyn IFormatter<String>>().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use syrette::ptr::{InjectedPtr, SomePtr};
+use syrette::{injectable, DIContainer};
+
+trait IDatabase
+{
+    fn url(&self) -> &'static str;
+}
+
+struct Database {}
+
+#[injectable(IDatabase)]
+impl Database
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IDatabase for Database
+{
+    fn url(&self) -> &'static str
+    {
+        "postgres://localhost"
+    }
+}
+
+struct UserRepository
+{
+    database: InjectedPtr<dyn IDatabase>,
+}
+
+#[injectable]
+impl UserRepository
+{
+    fn new(database: InjectedPtr<dyn IDatabase>) -> Self
+    {
+        Self { database }
+    }
+}
+
+struct OrderRepository
+{
+    database: SomePtr<dyn IDatabase>,
+}
+
+#[injectable]
+impl OrderRepository
+{
+    fn new(database: SomePtr<dyn IDatabase>) -> Self
+    {
+        Self { database }
+    }
+}
+
+#[test]
+fn can_inject_transient_binding() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<dyn IDatabase>().to::<Database>()?;
+    di_container
+        .bind::<UserRepository>()
+        .to::<UserRepository>()?;
+
+    let user_repository = di_container.get::<UserRepository>()?.transient()?;
+
+    assert!(matches!(user_repository.database, SomePtr::Transient(_)));
+    assert_eq!(user_repository.database.url(), "postgres://localhost");
+
+    Ok(())
+}
+
+#[test]
+fn can_inject_singleton_binding() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IDatabase>()
+        .to::<Database>()?
+        .in_singleton_scope()?;
+
+    di_container
+        .bind::<UserRepository>()
+        .to::<UserRepository>()?;
+
+    di_container
+        .bind::<OrderRepository>()
+        .to::<OrderRepository>()?;
+
+    let user_repository = di_container.get::<UserRepository>()?.transient()?;
+    let order_repository = di_container.get::<OrderRepository>()?.transient()?;
+
+    assert!(matches!(user_repository.database, SomePtr::Singleton(_)));
+    assert_eq!(order_repository.database.url(), "postgres://localhost");
+
+    assert!(std::rc::Rc::ptr_eq(
+        &user_repository.database.into_rc()?,
+        &order_repository.database.into_rc()?
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,270 @@
+#![deny(clippy::all, clippy::pedantic)]
+#![cfg(feature = "async")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use syrette::ptr::{ThreadsafeSingletonPtr, TransientPtr};
+use syrette::{injectable, AsyncDIContainer};
+
+static CONNECTION_POOL_CONSTRUCT_CNT: AtomicUsize = AtomicUsize::new(0);
+
+struct ConnectionPool {}
+
+#[injectable(async = true)]
+impl ConnectionPool
+{
+    async fn new() -> Self
+    {
+        tokio::task::yield_now().await;
+
+        CONNECTION_POOL_CONSTRUCT_CNT.fetch_add(1, Ordering::SeqCst);
+
+        Self {}
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("The cache server is unavailable")]
+struct CacheUnavailableError;
+
+static CACHE_CONSTRUCT_ATTEMPT_CNT: AtomicUsize = AtomicUsize::new(0);
+
+struct Cache {}
+
+#[injectable(async = true)]
+impl Cache
+{
+    async fn new() -> Result<Self, CacheUnavailableError>
+    {
+        tokio::task::yield_now().await;
+
+        if CACHE_CONSTRUCT_ATTEMPT_CNT.fetch_add(1, Ordering::SeqCst) == 0 {
+            return Err(CacheUnavailableError);
+        }
+
+        Ok(Self {})
+    }
+}
+
+struct EventBus
+{
+    _audit_log: ThreadsafeSingletonPtr<AuditLog>,
+}
+
+#[injectable(async = true)]
+impl EventBus
+{
+    fn new(audit_log: ThreadsafeSingletonPtr<AuditLog>) -> Self
+    {
+        Self {
+            _audit_log: audit_log,
+        }
+    }
+}
+
+struct AuditLog
+{
+    _event_bus: ThreadsafeSingletonPtr<EventBus>,
+}
+
+#[injectable(async = true)]
+impl AuditLog
+{
+    fn new(event_bus: ThreadsafeSingletonPtr<EventBus>) -> Self
+    {
+        Self {
+            _event_bus: event_bus,
+        }
+    }
+}
+
+struct Clock {}
+
+#[injectable(async = true)]
+impl Clock
+{
+    async fn new() -> Self
+    {
+        tokio::task::yield_now().await;
+
+        Self {}
+    }
+}
+
+struct Scheduler
+{
+    _clock: TransientPtr<Clock>,
+    _job_queue: ThreadsafeSingletonPtr<JobQueue>,
+}
+
+#[injectable(async = true)]
+impl Scheduler
+{
+    fn new(
+        clock: TransientPtr<Clock>,
+        job_queue: ThreadsafeSingletonPtr<JobQueue>,
+    ) -> Self
+    {
+        Self {
+            _clock: clock,
+            _job_queue: job_queue,
+        }
+    }
+}
+
+struct JobQueue
+{
+    _clock: TransientPtr<Clock>,
+    _scheduler: ThreadsafeSingletonPtr<Scheduler>,
+}
+
+#[injectable(async = true)]
+impl JobQueue
+{
+    fn new(
+        clock: TransientPtr<Clock>,
+        scheduler: ThreadsafeSingletonPtr<Scheduler>,
+    ) -> Self
+    {
+        Self {
+            _clock: clock,
+            _scheduler: scheduler,
+        }
+    }
+}
+
+#[tokio::test]
+async fn lazy_singleton_is_constructed_once()
+{
+    let mut di_container = AsyncDIContainer::new();
+
+    di_container
+        .bind::<ConnectionPool>()
+        .to::<ConnectionPool>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    assert_eq!(CONNECTION_POOL_CONSTRUCT_CNT.load(Ordering::SeqCst), 0);
+
+    let (pool_a, pool_b, pool_c) = tokio::join!(
+        di_container.get::<ConnectionPool>(),
+        di_container.get::<ConnectionPool>(),
+        di_container.get::<ConnectionPool>()
+    );
+
+    let pool_a = pool_a
+        .expect("Expected Ok")
+        .threadsafe_singleton()
+        .expect("Expected Ok");
+
+    let pool_b = pool_b
+        .expect("Expected Ok")
+        .threadsafe_singleton()
+        .expect("Expected Ok");
+
+    let pool_c = pool_c
+        .expect("Expected Ok")
+        .threadsafe_singleton()
+        .expect("Expected Ok");
+
+    assert_eq!(CONNECTION_POOL_CONSTRUCT_CNT.load(Ordering::SeqCst), 1);
+
+    assert!(Arc::ptr_eq(&pool_a, &pool_b));
+    assert!(Arc::ptr_eq(&pool_a, &pool_c));
+}
+
+#[tokio::test]
+async fn failed_lazy_singleton_is_retried()
+{
+    let mut di_container = AsyncDIContainer::new();
+
+    di_container
+        .bind::<Cache>()
+        .to::<Cache>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    assert!(di_container.get::<Cache>().await.is_err());
+
+    let cache_a = di_container
+        .get::<Cache>()
+        .await
+        .expect("Expected Ok")
+        .threadsafe_singleton()
+        .expect("Expected Ok");
+
+    let cache_b = di_container
+        .get::<Cache>()
+        .await
+        .expect("Expected Ok")
+        .threadsafe_singleton()
+        .expect("Expected Ok");
+
+    assert_eq!(CACHE_CONSTRUCT_ATTEMPT_CNT.load(Ordering::SeqCst), 2);
+
+    assert!(Arc::ptr_eq(&cache_a, &cache_b));
+}
+
+#[tokio::test]
+async fn mutually_dependent_lazy_singletons_fail()
+{
+    let mut di_container = AsyncDIContainer::new();
+
+    di_container
+        .bind::<EventBus>()
+        .to::<EventBus>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    di_container
+        .bind::<AuditLog>()
+        .to::<AuditLog>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    let result =
+        tokio::time::timeout(Duration::from_secs(5), di_container.get::<EventBus>())
+            .await
+            .expect("Resolving the mutually dependent lazy singletons timed out");
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn concurrently_resolved_mutually_dependent_lazy_singletons_fail()
+{
+    let mut di_container = AsyncDIContainer::new();
+
+    di_container
+        .bind::<Clock>()
+        .to::<Clock>()
+        .expect("Expected Ok");
+
+    di_container
+        .bind::<Scheduler>()
+        .to::<Scheduler>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    di_container
+        .bind::<JobQueue>()
+        .to::<JobQueue>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    // Resolving the clock yields, so both resolutions start initializing their
+    // singleton before requesting the other one
+    let (scheduler, job_queue) = tokio::time::timeout(Duration::from_secs(5), async {
+        tokio::join!(
+            di_container.get::<Scheduler>(),
+            di_container.get::<JobQueue>()
+        )
+    })
+    .await
+    .expect("Resolving the mutually dependent lazy singletons timed out");
+
+    assert!(scheduler.is_err());
+    assert!(job_queue.is_err());
+}
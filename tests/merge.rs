@@ -0,0 +1,314 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::rc::Rc;
+
+use syrette::di_container::ConflictPolicy;
+use syrette::errors::di_container::DIContainerError;
+use syrette::{injectable, DIContainer};
+
+trait IMailer
+{
+    fn provider(&self) -> &'static str;
+}
+
+struct SmtpMailer {}
+
+#[injectable(IMailer)]
+impl SmtpMailer
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IMailer for SmtpMailer
+{
+    fn provider(&self) -> &'static str
+    {
+        "smtp"
+    }
+}
+
+struct ApiMailer {}
+
+#[injectable(IMailer)]
+impl ApiMailer
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IMailer for ApiMailer
+{
+    fn provider(&self) -> &'static str
+    {
+        "api"
+    }
+}
+
+struct Database {}
+
+#[injectable]
+impl Database
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+fn create_di_containers() -> (DIContainer, DIContainer)
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IMailer>()
+        .to::<SmtpMailer>()
+        .expect("Expected Ok");
+
+    let mut other_di_container = DIContainer::new();
+
+    other_di_container
+        .bind::<dyn IMailer>()
+        .to::<ApiMailer>()
+        .expect("Expected Ok");
+
+    other_di_container
+        .bind::<Database>()
+        .to::<Database>()
+        .expect("Expected Ok");
+
+    (di_container, other_di_container)
+}
+
+fn get_mailer_provider(di_container: &DIContainer) -> &'static str
+{
+    di_container
+        .get::<dyn IMailer>()
+        .expect("Expected Ok")
+        .transient()
+        .expect("Expected Ok")
+        .provider()
+}
+
+#[test]
+fn merge_errors_on_conflict()
+{
+    let (mut di_container, other_di_container) = create_di_containers();
+
+    assert!(matches!(
+        di_container.merge(other_di_container, ConflictPolicy::Error),
+        Err(DIContainerError::BindingConflict { name: None, .. })
+    ));
+
+    assert!(di_container.get::<Database>().is_err());
+    assert_eq!(get_mailer_provider(&di_container), "smtp");
+}
+
+#[test]
+fn merge_can_keep_existing()
+{
+    let (mut di_container, other_di_container) = create_di_containers();
+
+    di_container
+        .merge(other_di_container, ConflictPolicy::KeepExisting)
+        .expect("Expected Ok");
+
+    assert!(di_container.get::<Database>().is_ok());
+    assert_eq!(get_mailer_provider(&di_container), "smtp");
+}
+
+#[test]
+fn merge_can_overwrite()
+{
+    let (mut di_container, other_di_container) = create_di_containers();
+
+    di_container
+        .merge(other_di_container, ConflictPolicy::Overwrite)
+        .expect("Expected Ok");
+
+    assert!(di_container.get::<Database>().is_ok());
+    assert_eq!(get_mailer_provider(&di_container), "api");
+}
+
+#[test]
+fn import_shares_singletons()
+{
+    let mut other_di_container = DIContainer::new();
+
+    other_di_container
+        .bind::<Database>()
+        .to::<Database>()
+        .expect("Expected Ok")
+        .in_singleton_scope()
+        .expect("Expected Ok");
+
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .import::<Database>(&other_di_container)
+        .expect("Expected Ok");
+
+    let database = di_container
+        .get::<Database>()
+        .expect("Expected Ok")
+        .singleton()
+        .expect("Expected Ok");
+
+    let other_database = other_di_container
+        .get::<Database>()
+        .expect("Expected Ok")
+        .singleton()
+        .expect("Expected Ok");
+
+    assert!(Rc::ptr_eq(&database, &other_database));
+}
+
+#[test]
+fn import_named_works()
+{
+    let mut other_di_container = DIContainer::new();
+
+    other_di_container
+        .bind::<dyn IMailer>()
+        .to::<ApiMailer>()
+        .expect("Expected Ok")
+        .in_transient_scope()
+        .when_named("api")
+        .expect("Expected Ok");
+
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .import_named::<dyn IMailer>(&other_di_container, "api")
+        .expect("Expected Ok");
+
+    assert!(di_container.get_named::<dyn IMailer>("api").is_ok());
+    assert!(di_container.get::<dyn IMailer>().is_err());
+}
+
+#[test]
+fn import_errors_on_missing_or_conflicting_binding()
+{
+    let (mut di_container, other_di_container) = create_di_containers();
+
+    assert!(matches!(
+        di_container.import_named::<Database>(&other_di_container, "primary"),
+        Err(DIContainerError::BindingNotFound { .. })
+    ));
+
+    assert!(matches!(
+        di_container.import::<dyn IMailer>(&other_di_container),
+        Err(DIContainerError::BindingConflict { .. })
+    ));
+
+    assert_eq!(get_mailer_provider(&di_container), "smtp");
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use std::sync::Arc;
+
+    use syrette::di_container::{ConflictPolicy, SingletonForkPolicy};
+    use syrette::errors::async_di_container::AsyncDIContainerError;
+    use syrette::{injectable, AsyncDIContainer};
+
+    struct Cache {}
+
+    #[injectable(async = true)]
+    impl Cache
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    struct Queue {}
+
+    #[injectable(async = true)]
+    impl Queue
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    #[tokio::test]
+    async fn merge_works()
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<Cache>()
+            .to::<Cache>()
+            .expect("Expected Ok");
+
+        let mut other_di_container = AsyncDIContainer::new();
+
+        other_di_container
+            .bind::<Cache>()
+            .to::<Cache>()
+            .expect("Expected Ok");
+
+        other_di_container
+            .bind::<Queue>()
+            .to::<Queue>()
+            .expect("Expected Ok");
+
+        assert!(matches!(
+            di_container.merge(
+                other_di_container.fork(SingletonForkPolicy::Share),
+                ConflictPolicy::Error
+            ),
+            Err(AsyncDIContainerError::BindingConflict { .. })
+        ));
+
+        di_container
+            .merge(other_di_container, ConflictPolicy::KeepExisting)
+            .expect("Expected Ok");
+
+        assert!(di_container.get::<Queue>().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn import_shares_singletons()
+    {
+        let mut other_di_container = AsyncDIContainer::new();
+
+        other_di_container
+            .bind::<Cache>()
+            .to::<Cache>()
+            .expect("Expected Ok")
+            .in_singleton_scope()
+            .await
+            .expect("Expected Ok");
+
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .import::<Cache>(&other_di_container)
+            .expect("Expected Ok");
+
+        let cache = di_container
+            .get::<Cache>()
+            .await
+            .expect("Expected Ok")
+            .threadsafe_singleton()
+            .expect("Expected Ok");
+
+        let other_cache = other_di_container
+            .get::<Cache>()
+            .await
+            .expect("Expected Ok")
+            .threadsafe_singleton()
+            .expect("Expected Ok");
+
+        assert!(Arc::ptr_eq(&cache, &other_cache));
+    }
+}
@@ -1,6 +1,10 @@
 #![deny(clippy::all, clippy::pedantic)]
 #![allow(clippy::disallowed_names)]
+#![cfg(feature = "prevent-circular")]
 
+use std::any::TypeId;
+
+use syrette::dependency_history::{DependencyFrame, DependencyFrameKind};
 use syrette::errors::di_container::DIContainerError;
 use syrette::errors::injectable::InjectableError;
 use syrette::ptr::TransientPtr;
@@ -68,7 +72,11 @@ fn prevent_circular_works()
     let container_err_a = assert_match!(
         err,
         DIContainerError::BindingResolveFailed {
-            reason: InjectableError::ResolveFailed { reason, affected: _ },
+            reason: InjectableError::ResolveFailed {
+                reason,
+                affected: _,
+                dependency_history: _
+            },
             interface: _
         } => *reason
     );
@@ -76,18 +84,36 @@ fn prevent_circular_works()
     let container_err_b = assert_match!(
         container_err_a,
         DIContainerError::BindingResolveFailed {
-            reason: InjectableError::ResolveFailed { reason, affected: _ },
+            reason: InjectableError::ResolveFailed {
+                reason,
+                affected: _,
+                dependency_history: _
+            },
             interface: _
         } => *reason
     );
 
-    assert!(matches!(
+    let dependency_history = assert_match!(
         container_err_b,
         DIContainerError::BindingResolveFailed {
             reason: InjectableError::DetectedCircular {
-                dependency_history: _
+                dependency_history
             },
             interface: _
-        }
-    ));
+        } => dependency_history
+    );
+
+    assert_eq!(
+        dependency_history
+            .cycle()
+            .iter()
+            .filter(|frame| frame.kind() == DependencyFrameKind::Implementation)
+            .map(DependencyFrame::type_id)
+            .collect::<Vec<_>>(),
+        vec![
+            TypeId::of::<Foo>(),
+            TypeId::of::<Bar>(),
+            TypeId::of::<Foo>()
+        ]
+    );
 }
@@ -0,0 +1,191 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use syrette::errors::di_container::BindingBuilderError;
+use syrette::ptr::TransientPtr;
+use syrette::{injectable, DIContainer};
+
+trait IStorage
+{
+    fn backend(&self) -> &'static str;
+}
+
+struct S3Storage {}
+
+#[injectable(IStorage, key = "s3")]
+impl S3Storage
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IStorage for S3Storage
+{
+    fn backend(&self) -> &'static str
+    {
+        "s3"
+    }
+}
+
+struct LocalStorage
+{
+    root: TransientPtr<StorageRoot>,
+}
+
+#[injectable(IStorage, key = "local")]
+impl LocalStorage
+{
+    fn new(root: TransientPtr<StorageRoot>) -> Self
+    {
+        Self { root }
+    }
+}
+
+impl IStorage for LocalStorage
+{
+    fn backend(&self) -> &'static str
+    {
+        self.root.path
+    }
+}
+
+struct StorageRoot
+{
+    path: &'static str,
+}
+
+#[injectable(key = "default")]
+impl StorageRoot
+{
+    fn new() -> Self
+    {
+        Self {
+            path: "/var/lib/storage",
+        }
+    }
+}
+
+trait ICache {}
+
+struct MemoryCache {}
+
+#[injectable(ICache, key = "memory")]
+impl MemoryCache
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl ICache for MemoryCache {}
+
+struct OtherMemoryCache {}
+
+#[injectable(ICache, key = "memory")]
+impl OtherMemoryCache
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl ICache for OtherMemoryCache {}
+
+#[test]
+fn can_bind_to_registered() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<dyn IStorage>().to_registered("s3")?;
+
+    let storage = di_container.get::<dyn IStorage>()?.transient()?;
+
+    assert_eq!(storage.backend(), "s3");
+
+    Ok(())
+}
+
+#[test]
+fn can_bind_concrete_type_to_registered() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<StorageRoot>()
+        .to_registered("default")?;
+    di_container.bind::<dyn IStorage>().to_registered("local")?;
+
+    let storage = di_container.get::<dyn IStorage>()?.transient()?;
+
+    assert_eq!(storage.backend(), "/var/lib/storage");
+
+    Ok(())
+}
+
+#[test]
+fn can_bind_to_registered_in_singleton_scope() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IStorage>()
+        .to_registered("s3")?
+        .in_singleton_scope()?;
+
+    let first = di_container.get::<dyn IStorage>()?.singleton()?;
+    let second = di_container.get::<dyn IStorage>()?.singleton()?;
+
+    assert!(std::rc::Rc::ptr_eq(&first, &second));
+
+    Ok(())
+}
+
+#[test]
+fn bind_to_unregistered_key_lists_available_keys()
+{
+    let mut di_container = DIContainer::new();
+
+    let err = di_container
+        .bind::<dyn IStorage>()
+        .to_registered("gcs")
+        .err()
+        .expect("Expected Err");
+
+    assert!(matches!(
+        &err,
+        BindingBuilderError::UnregisteredKey { key, available_keys, .. }
+            if key == "gcs" && available_keys == &["local", "s3"]
+    ));
+
+    assert!(err.to_string().ends_with("Available keys: [local, s3]"));
+}
+
+#[test]
+fn bind_to_ambiguous_key_fails()
+{
+    let mut di_container = DIContainer::new();
+
+    assert!(matches!(
+        di_container.bind::<dyn ICache>().to_registered("memory"),
+        Err(BindingBuilderError::AmbiguousRegisteredKey { implementations, .. })
+            if implementations.len() == 2
+    ));
+}
+
+#[test]
+fn bind_to_registered_twice_fails() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<dyn IStorage>().to_registered("s3")?;
+
+    assert!(matches!(
+        di_container.bind::<dyn IStorage>().to_registered("local"),
+        Err(BindingBuilderError::BindingAlreadyExists(_))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::any::type_name;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use syrette::di_container::listener::{
+    ProviderKind,
+    ResolutionEvent,
+    ResolutionListener,
+};
+use syrette::ptr::SingletonPtr;
+use syrette::{injectable, DIContainer};
+
+trait IPool {}
+
+struct Pool;
+
+#[injectable(IPool)]
+impl Pool
+{
+    fn new() -> Self
+    {
+        Self
+    }
+}
+
+impl IPool for Pool {}
+
+struct Repository
+{
+    _pool: SingletonPtr<dyn IPool>,
+}
+
+#[injectable]
+impl Repository
+{
+    fn new(pool: SingletonPtr<dyn IPool>) -> Self
+    {
+        Self { _pool: pool }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct RecordedEvent
+{
+    callback: &'static str,
+    interface: &'static str,
+    provider_kind: Option<ProviderKind>,
+    depth: usize,
+}
+
+#[derive(Clone, Default)]
+struct EventRecorder
+{
+    events: Rc<RefCell<Vec<RecordedEvent>>>,
+}
+
+impl EventRecorder
+{
+    fn record(&self, callback: &'static str, event: &ResolutionEvent<'_>)
+    {
+        self.events.borrow_mut().push(RecordedEvent {
+            callback,
+            interface: event.interface(),
+            provider_kind: event.provider_kind(),
+            depth: event.depth(),
+        });
+    }
+}
+
+impl ResolutionListener for EventRecorder
+{
+    fn before_resolve(&self, event: &ResolutionEvent<'_>)
+    {
+        self.record("before", event);
+    }
+
+    fn after_resolve(&self, event: &ResolutionEvent<'_>)
+    {
+        self.record("after", event);
+    }
+
+    fn on_failure(&self, event: &ResolutionEvent<'_>, _err: &dyn std::error::Error)
+    {
+        self.record("failure", event);
+    }
+}
+
+#[test]
+fn listeners_are_notified_about_dependencies()
+{
+    let recorder = EventRecorder::default();
+
+    let mut di_container = DIContainer::new();
+
+    di_container.add_listener(recorder.clone());
+
+    di_container
+        .bind::<Repository>()
+        .to::<Repository>()
+        .expect("Expected Ok");
+
+    di_container
+        .bind::<dyn IPool>()
+        .to::<Pool>()
+        .expect("Expected Ok")
+        .in_singleton_scope()
+        .expect("Expected Ok");
+
+    assert!(di_container.get::<Repository>().is_ok());
+
+    assert_eq!(
+        *recorder.events.borrow(),
+        vec![
+            RecordedEvent {
+                callback: "before",
+                interface: type_name::<Repository>(),
+                provider_kind: None,
+                depth: 0
+            },
+            RecordedEvent {
+                callback: "before",
+                interface: type_name::<dyn IPool>(),
+                provider_kind: None,
+                depth: 1
+            },
+            RecordedEvent {
+                callback: "after",
+                interface: type_name::<dyn IPool>(),
+                provider_kind: Some(ProviderKind::Singleton),
+                depth: 1
+            },
+            RecordedEvent {
+                callback: "after",
+                interface: type_name::<Repository>(),
+                provider_kind: Some(ProviderKind::Transient),
+                depth: 0
+            },
+        ]
+    );
+}
+
+#[test]
+fn listeners_are_notified_about_failures()
+{
+    let recorder = EventRecorder::default();
+
+    let mut di_container = DIContainer::new();
+
+    di_container.add_listener(recorder.clone());
+
+    di_container
+        .bind::<Repository>()
+        .to::<Repository>()
+        .expect("Expected Ok");
+
+    assert!(di_container.get::<Repository>().is_err());
+
+    let events = recorder.events.borrow();
+
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| (event.callback, event.interface))
+            .collect::<Vec<_>>(),
+        vec![
+            ("before", type_name::<Repository>()),
+            ("before", type_name::<dyn IPool>()),
+            ("failure", type_name::<dyn IPool>()),
+            ("failure", type_name::<Repository>()),
+        ]
+    );
+}
@@ -0,0 +1,89 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::any::type_name;
+
+use syrette::ptr::SingletonPtr;
+use syrette::{declare_interface, injectable, DIContainer};
+
+trait IPool {}
+
+trait IRepo {}
+
+struct PgRepo
+{
+    _pool: SingletonPtr<dyn IPool>,
+}
+
+#[injectable(IRepo)]
+impl PgRepo
+{
+    fn new(pool: SingletonPtr<dyn IPool>) -> Self
+    {
+        Self { _pool: pool }
+    }
+}
+
+impl IRepo for PgRepo {}
+
+struct App
+{
+    _repo: SingletonPtr<dyn IRepo>,
+}
+
+#[injectable]
+impl App
+{
+    fn new(#[syrette::named("primary")] repo: SingletonPtr<dyn IRepo>) -> Self
+    {
+        Self { _repo: repo }
+    }
+}
+
+declare_interface!(PgRepo -> IRepo);
+
+#[test]
+fn error_report_contains_resolution_path()
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<App>().to::<App>().expect("Expected Ok");
+
+    di_container
+        .bind::<dyn IRepo>()
+        .to::<PgRepo>()
+        .expect("Expected Ok")
+        .in_transient_scope()
+        .when_named("primary")
+        .expect("Expected Ok");
+
+    let err = di_container.get::<App>().err().expect("Expected Err");
+
+    assert_eq!(
+        err.report(),
+        format!(
+            "{} -> {} (\"primary\") -> {} -> {}: binding not found",
+            type_name::<App>(),
+            type_name::<dyn IRepo>(),
+            type_name::<PgRepo>(),
+            type_name::<dyn IPool>()
+        )
+    );
+}
+
+#[test]
+fn error_report_of_root_binding_works()
+{
+    let di_container = DIContainer::new();
+
+    let err = di_container
+        .get_named::<dyn IPool>("main")
+        .err()
+        .expect("Expected Err");
+
+    assert!(err.resolution_path().is_none());
+
+    assert_eq!(
+        err.report(),
+        format!("{} (\"main\"): binding not found", type_name::<dyn IPool>())
+    );
+}
@@ -0,0 +1,109 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::rc::Rc;
+
+use syrette::ptr::{SingletonPtr, TransientPtr};
+use syrette::{container, injectable};
+
+trait IDatabase
+{
+    fn url(&self) -> &'static str;
+}
+
+struct Database {}
+
+#[injectable]
+impl Database
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IDatabase for Database
+{
+    fn url(&self) -> &'static str
+    {
+        "postgres://localhost"
+    }
+}
+
+struct UserRepository
+{
+    database: SingletonPtr<dyn IDatabase>,
+}
+
+#[injectable]
+impl UserRepository
+{
+    fn new(database: SingletonPtr<dyn IDatabase>) -> Self
+    {
+        Self { database }
+    }
+}
+
+struct UserService
+{
+    user_repository: TransientPtr<UserRepository>,
+    database: SingletonPtr<dyn IDatabase>,
+}
+
+#[injectable]
+impl UserService
+{
+    fn new(
+        user_repository: TransientPtr<UserRepository>,
+        database: SingletonPtr<dyn IDatabase>,
+    ) -> Self
+    {
+        Self {
+            user_repository,
+            database,
+        }
+    }
+}
+
+container! {
+    struct AppContainer;
+
+    dyn IDatabase => Database in singleton,
+    UserRepository => UserRepository in transient,
+    UserService => UserService,
+}
+
+#[test]
+fn can_resolve_from_static_container()
+{
+    let app_container = AppContainer::new();
+
+    let user_service = app_container.get::<UserService>();
+
+    assert_eq!(
+        user_service.user_repository.database.url(),
+        "postgres://localhost"
+    );
+}
+
+#[test]
+fn static_container_singletons_are_shared()
+{
+    let app_container = AppContainer::default();
+
+    let user_service = app_container.get::<UserService>();
+
+    let database = app_container.get::<dyn IDatabase>();
+
+    assert!(Rc::ptr_eq(&user_service.database, &database));
+    assert!(Rc::ptr_eq(
+        &user_service.user_repository.database,
+        &database
+    ));
+
+    let other_app_container = AppContainer::new();
+
+    assert!(!Rc::ptr_eq(
+        &other_app_container.get::<dyn IDatabase>(),
+        &database
+    ));
+}
@@ -0,0 +1,165 @@
+#![deny(clippy::all, clippy::pedantic)]
+#![cfg(feature = "async")]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use syrette::errors::async_di_container::AsyncDIContainerError;
+use syrette::errors::injectable::InjectableError;
+use syrette::ptr::ThreadsafeSingletonPtr;
+use syrette::{injectable, AsyncDIContainer};
+
+struct RequestContext {}
+
+#[injectable(async = true)]
+impl RequestContext
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+struct UserService
+{
+    request_context: ThreadsafeSingletonPtr<RequestContext>,
+}
+
+#[injectable(async = true)]
+impl UserService
+{
+    fn new(request_context: ThreadsafeSingletonPtr<RequestContext>) -> Self
+    {
+        Self { request_context }
+    }
+}
+
+struct Session
+{
+    _transaction: ThreadsafeSingletonPtr<Transaction>,
+}
+
+#[injectable(async = true)]
+impl Session
+{
+    fn new(transaction: ThreadsafeSingletonPtr<Transaction>) -> Self
+    {
+        Self {
+            _transaction: transaction,
+        }
+    }
+}
+
+struct Transaction
+{
+    _session: ThreadsafeSingletonPtr<Session>,
+}
+
+#[injectable(async = true)]
+impl Transaction
+{
+    fn new(session: ThreadsafeSingletonPtr<Session>) -> Self
+    {
+        Self { _session: session }
+    }
+}
+
+fn create_di_container() -> AsyncDIContainer
+{
+    let mut di_container = AsyncDIContainer::new();
+
+    di_container
+        .bind::<RequestContext>()
+        .to::<RequestContext>()
+        .expect("Expected Ok")
+        .in_task_scope();
+
+    di_container
+        .bind::<UserService>()
+        .to::<UserService>()
+        .expect("Expected Ok");
+
+    di_container
+}
+
+async fn get_request_context(
+    di_container: &AsyncDIContainer,
+) -> ThreadsafeSingletonPtr<RequestContext>
+{
+    tokio::task::yield_now().await;
+
+    di_container
+        .get::<RequestContext>()
+        .await
+        .expect("Expected Ok")
+        .threadsafe_singleton()
+        .expect("Expected Ok")
+}
+
+#[tokio::test]
+async fn task_scoped_instances_are_shared_inside_of_scope()
+{
+    let di_container = create_di_container();
+
+    let (request_context, user_service) = AsyncDIContainer::scope(|| async {
+        let request_context = get_request_context(&di_container).await;
+
+        let user_service = di_container
+            .get::<UserService>()
+            .await
+            .expect("Expected Ok")
+            .transient()
+            .expect("Expected Ok");
+
+        (request_context, user_service)
+    })
+    .await;
+
+    assert!(Arc::ptr_eq(&request_context, &user_service.request_context));
+
+    let other_request_context =
+        AsyncDIContainer::scope(|| get_request_context(&di_container)).await;
+
+    assert!(!Arc::ptr_eq(&request_context, &other_request_context));
+}
+
+#[tokio::test]
+async fn resolving_outside_of_scope_fails()
+{
+    let di_container = create_di_container();
+
+    assert!(matches!(
+        di_container.get::<RequestContext>().await,
+        Err(AsyncDIContainerError::BindingResolveFailed {
+            reason: InjectableError::OutsideOfTaskScope { affected: _ },
+            interface: _
+        })
+    ));
+}
+
+#[tokio::test]
+async fn mutually_dependent_task_scoped_bindings_fail()
+{
+    let mut di_container = AsyncDIContainer::new();
+
+    di_container
+        .bind::<Session>()
+        .to::<Session>()
+        .expect("Expected Ok")
+        .in_task_scope();
+
+    di_container
+        .bind::<Transaction>()
+        .to::<Transaction>()
+        .expect("Expected Ok")
+        .in_task_scope();
+
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        AsyncDIContainer::scope(|| di_container.get::<Session>()),
+    )
+    .await
+    .expect("Resolving the mutually dependent task scoped bindings timed out");
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,283 @@
+#![deny(clippy::all, clippy::pedantic)]
+#![cfg(feature = "async")]
+
+use std::any::type_name;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use syrette::di_container::asynchronous::warm_up::WarmUpEntry;
+use syrette::errors::injectable::InjectableError;
+use syrette::ptr::ThreadsafeSingletonPtr;
+use syrette::{injectable, AsyncDIContainer};
+
+static INDEPENDENT_SINGLETONS_STARTED_CNT: AtomicUsize = AtomicUsize::new(0);
+
+// Waits for both of the independent lazy singletons to have started being initialized,
+// which is only possible if they are initialized concurrently
+async fn wait_for_independent_singletons()
+{
+    INDEPENDENT_SINGLETONS_STARTED_CNT.fetch_add(1, Ordering::SeqCst);
+
+    while INDEPENDENT_SINGLETONS_STARTED_CNT.load(Ordering::SeqCst) < 2 {
+        tokio::task::yield_now().await;
+    }
+}
+
+struct ConnectionPool {}
+
+#[injectable(async = true)]
+impl ConnectionPool
+{
+    async fn new() -> Self
+    {
+        wait_for_independent_singletons().await;
+
+        Self {}
+    }
+}
+
+struct RemoteConfig {}
+
+#[injectable(async = true)]
+impl RemoteConfig
+{
+    async fn new() -> Self
+    {
+        wait_for_independent_singletons().await;
+
+        Self {}
+    }
+}
+
+struct UserRepository
+{
+    connection_pool: ThreadsafeSingletonPtr<ConnectionPool>,
+}
+
+#[injectable(async = true)]
+impl UserRepository
+{
+    fn new(connection_pool: ThreadsafeSingletonPtr<ConnectionPool>) -> Self
+    {
+        Self { connection_pool }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("The message queue is unreachable")]
+struct UnreachableError;
+
+struct MessageQueue {}
+
+#[injectable(async = true)]
+impl MessageQueue
+{
+    async fn new() -> Result<Self, UnreachableError>
+    {
+        tokio::task::yield_now().await;
+
+        Err(UnreachableError)
+    }
+}
+
+struct EventBus
+{
+    _audit_log: ThreadsafeSingletonPtr<AuditLog>,
+}
+
+#[injectable(async = true)]
+impl EventBus
+{
+    fn new(audit_log: ThreadsafeSingletonPtr<AuditLog>) -> Self
+    {
+        Self {
+            _audit_log: audit_log,
+        }
+    }
+}
+
+struct AuditLog
+{
+    _event_bus: ThreadsafeSingletonPtr<EventBus>,
+}
+
+#[injectable(async = true)]
+impl AuditLog
+{
+    fn new(event_bus: ThreadsafeSingletonPtr<EventBus>) -> Self
+    {
+        Self {
+            _event_bus: event_bus,
+        }
+    }
+}
+
+struct Notifier
+{
+    _event_bus: ThreadsafeSingletonPtr<EventBus>,
+}
+
+#[injectable(async = true)]
+impl Notifier
+{
+    fn new(event_bus: ThreadsafeSingletonPtr<EventBus>) -> Self
+    {
+        Self {
+            _event_bus: event_bus,
+        }
+    }
+}
+
+struct MetricsRegistry {}
+
+#[injectable(async = true)]
+impl MetricsRegistry
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+#[tokio::test]
+async fn warm_up_works()
+{
+    let mut di_container = AsyncDIContainer::new();
+
+    di_container
+        .bind::<UserRepository>()
+        .to::<UserRepository>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    di_container
+        .bind::<ConnectionPool>()
+        .to::<ConnectionPool>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    di_container
+        .bind::<RemoteConfig>()
+        .to::<RemoteConfig>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    di_container
+        .bind::<MessageQueue>()
+        .to::<MessageQueue>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    let report = tokio::time::timeout(Duration::from_secs(10), di_container.warm_up())
+        .await
+        .expect(
+            "Expected the independent lazy singletons to be initialized concurrently",
+        );
+
+    assert_eq!(report.entries().len(), 4);
+
+    let stage_of = |implementation: &str| {
+        report
+            .entries()
+            .iter()
+            .find(|entry| entry.implementation() == implementation)
+            .map(WarmUpEntry::stage)
+            .expect("Expected a entry")
+    };
+
+    assert_eq!(stage_of(type_name::<ConnectionPool>()), 0);
+    assert_eq!(stage_of(type_name::<RemoteConfig>()), 0);
+    assert_eq!(stage_of(type_name::<UserRepository>()), 1);
+
+    assert!(!report.is_success());
+
+    assert_eq!(
+        report
+            .failures()
+            .map(WarmUpEntry::implementation)
+            .collect::<Vec<_>>(),
+        vec![type_name::<MessageQueue>()]
+    );
+
+    let connection_pool = di_container
+        .get::<ConnectionPool>()
+        .await
+        .expect("Expected Ok")
+        .threadsafe_singleton()
+        .expect("Expected Ok");
+
+    let user_repository = di_container
+        .get::<UserRepository>()
+        .await
+        .expect("Expected Ok")
+        .threadsafe_singleton()
+        .expect("Expected Ok");
+
+    assert!(ThreadsafeSingletonPtr::ptr_eq(
+        &connection_pool,
+        &user_repository.connection_pool
+    ));
+}
+
+#[tokio::test]
+async fn warm_up_does_not_initialize_cycles()
+{
+    let mut di_container = AsyncDIContainer::new();
+
+    di_container
+        .bind::<EventBus>()
+        .to::<EventBus>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    di_container
+        .bind::<AuditLog>()
+        .to::<AuditLog>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    di_container
+        .bind::<Notifier>()
+        .to::<Notifier>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    di_container
+        .bind::<MetricsRegistry>()
+        .to::<MetricsRegistry>()
+        .expect("Expected Ok")
+        .in_lazy_singleton_scope();
+
+    let report = tokio::time::timeout(Duration::from_secs(10), di_container.warm_up())
+        .await
+        .expect("Warming up the lazy singletons timed out");
+
+    assert_eq!(report.entries().len(), 4);
+
+    let mut failures = report
+        .failures()
+        .map(|entry| {
+            assert_eq!(entry.stage(), 1);
+
+            assert!(matches!(
+                entry.error(),
+                Some(InjectableError::InDependencyCycle { affected })
+                    if *affected == entry.implementation()
+            ));
+
+            entry.implementation()
+        })
+        .collect::<Vec<_>>();
+
+    failures.sort_unstable();
+
+    let mut expected_failures = vec![
+        type_name::<EventBus>(),
+        type_name::<AuditLog>(),
+        type_name::<Notifier>(),
+    ];
+
+    expected_failures.sort_unstable();
+
+    assert_eq!(failures, expected_failures);
+}